@@ -65,7 +65,7 @@ fn main() {
 
         // Update skill when batch is full
         if batch_full {
-            player.update_skill(hole, p_max);
+            player.update_skill(hole);
             let new_sigma = player.get_current_sigma(hole);
             let new_confidence = player.get_skill_confidence(hole);
             println!("   📊 Skill updated! σ={:.2}ft, Confidence={:.1}%",
@@ -75,7 +75,7 @@ fn main() {
 
     // Process any remaining shots in batch
     if player.get_batch_size(hole) > 0 {
-        player.update_skill(hole, p_max);
+        player.update_skill(hole);
     }
 
     println!("{}", "-".repeat(60));
@@ -55,6 +55,7 @@ fn demo_player_session() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     println!("Player: {} (Handicap: {})", player.id, player.handicap);
@@ -95,6 +96,7 @@ fn demo_venue_simulation() {
             std_dev: 5.0,
         },
         wager_range: (5.0, 20.0),
+        ..Default::default()
     };
 
     println!("Venue: {} bays, {:.1} hours operation", config.num_bays, config.hours);
@@ -151,6 +153,7 @@ fn demo_tournament() {
             third: 0.15,
         },
         attempts_per_player: 5,
+        ..Default::default()
     };
 
     println!("Tournament: Closest to Pin (Hole 4 - 150 yds)");
@@ -1,4 +1,4 @@
-/// Phase 4 Demo: Analytics & Validation
+//! Phase 4 Demo: Analytics & Validation
 
 use continuum_golf_simulator::models::{player::Player, hole::get_hole_by_id};
 use continuum_golf_simulator::simulators::player_session::{SessionConfig, run_session, HoleSelection};
@@ -28,10 +28,11 @@ fn main() {
     let hole4 = get_hole_by_id(4).unwrap();
     let player_hcp15 = Player::new("player_15".to_string(), 15);
     
-    let ev = calculate_expected_value(&player_hcp15, &hole4, 10.0, 10000);
+    let ev = calculate_expected_value(&player_hcp15, hole4, 10.0, 10000);
     let theoretical_ev = 10.0 * (hole4.rtp - 1.0);
     
-    println!("Monte Carlo EV (10,000 trials): ${:.2}", ev);
+    println!("Monte Carlo EV (10,000 trials): ${:.2} ± {:.2}", ev.monte_carlo_ev, ev.std_error);
+    println!("Analytic EV (payout integral):  ${:.2}", ev.analytic_ev);
     println!("Theoretical EV (RTP formula):   ${:.2}", theoretical_ev);
     println!("House Edge:                     {:.2}%\n", (1.0 - hole4.rtp) * 100.0);
 
@@ -41,7 +42,7 @@ fn main() {
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
 
     let hole1 = get_hole_by_id(1).unwrap();
-    let rtp_results = validate_rtp_across_skills(&hole1, vec![0, 10, 20, 30], 5000);
+    let rtp_results = validate_rtp_across_skills(hole1, vec![0, 10, 20, 30], 5000);
     
     println!("┌──────────┬─────────────┬─────────────┬───────────────┐");
     println!("│ Handicap │ Actual RTP  │ Target RTP  │ Deviation     │");
@@ -60,7 +61,7 @@ fn main() {
     println!("━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━━\n");
 
     let hole6 = get_hole_by_id(6).unwrap();
-    let fairness_report = calculate_fairness_metric(&hole6, vec![0, 10, 20, 30], 5000);
+    let fairness_report = calculate_fairness_metric(hole6, vec![0, 10, 20, 30], 5000);
     
     println!("┌──────────┬─────────────┬─────────────┬─────────────┐");
     println!("│ Handicap │     EV      │   P_max     │   Sigma     │");
@@ -89,12 +90,13 @@ fn main() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
     
     let session_result = run_session(&mut player, config);
     let convergence_reports = analyze_kalman_convergence(&session_result);
     
-    for (_category, report) in &convergence_reports {
+    for report in convergence_reports.values() {
         println!("Category: {}", report.club_category);
         println!("  Final Confidence: {:.2}%", report.final_confidence);
         println!("  Converged:        {}\n", if report.converged { "✓ YES" } else { "✗ NO" });
@@ -117,6 +119,7 @@ fn main() {
         shots_per_hour: 100,
         player_archetype: PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 },
         wager_range: (5.0, 15.0),
+        ..Default::default()
     };
     let venue_result = run_venue_simulation(venue_config);
     
@@ -1,18 +1,55 @@
-/// Data export module
-///
-/// Provides functions for exporting simulation results to various formats:
-/// - CSV for spreadsheet analysis
-/// - JSON for web visualization tools
-/// - Specialized formats for heatmaps and time-series data
+//! Data export module
+//!
+//! Provides functions for exporting simulation results to various formats:
+//! - CSV for spreadsheet analysis
+//! - JSON for web visualization tools (session exports carry a schema version)
+//! - NDJSON for live dashboards, one shot per line as it is played
+//! - Markdown session reports for sharing
+//! - Parquet for per-shot venue data in columnar analytics tools
+//! - Specialized formats for heatmaps and time-series data
 
-use crate::models::player::Player;
+use crate::models::hole::ClubCategory;
+use crate::models::player::{LoggedShot, Player};
+use crate::models::shot::ShotOutcome;
 use crate::simulators::player_session::SessionResult;
 use crate::simulators::venue::VenueResult;
 use crate::simulators::venue::HeatmapData;
 use csv::Writer;
+use parquet::data_type::{BoolType, DataType, DoubleType, Int32Type};
+use parquet::file::properties::WriterProperties;
+use parquet::file::writer::{SerializedFileWriter, SerializedRowGroupWriter};
+use parquet::schema::parser::parse_message_type;
+use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 use std::error::Error;
 use std::fs::File;
-use std::io::Write;
+use std::io::{BufWriter, Write};
+use std::sync::Arc;
+
+/// Schema version written into JSON export envelopes
+///
+/// Bump when an exported structure changes in a way older readers cannot parse.
+pub const EXPORT_SCHEMA_VERSION: u32 = 1;
+
+/// Versioned wrapper around an exported JSON payload
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ExportEnvelope<T> {
+    /// Schema version the payload was written with
+    pub schema_version: u32,
+    /// Payload type, e.g. "session"
+    pub kind: String,
+    /// The exported data
+    pub data: T,
+}
+
+/// Options for the per-shot session CSV
+#[derive(Debug, Clone, Copy, Default)]
+pub struct SessionCsvOptions {
+    /// Append `sigma_after`, `confidence_after` and `kalman_updated` columns
+    ///
+    /// Off by default so existing consumers see the original column set.
+    pub include_skill_columns: bool,
+}
 
 /// Export session results to CSV format
 ///
@@ -32,21 +69,43 @@ use std::io::Write;
 /// use continuum_golf_simulator::simulators::player_session::{SessionConfig, run_session, HoleSelection};
 /// use continuum_golf_simulator::analytics::export::export_session_csv;
 ///
-/// let mut player = Player::new(15);
+/// let mut player = Player::new("player_1".to_string(), 15);
 /// let config = SessionConfig {
 ///     num_shots: 100,
-///     wager_range: (5.0, 10.0),
+///     wager_min: 5.0,
+///     wager_max: 10.0,
 ///     hole_selection: HoleSelection::Random,
 ///     developer_mode: None,
+///     ..Default::default()
 /// };
 /// let result = run_session(&mut player, config);
 /// export_session_csv(&result, "my_session.csv").unwrap();
 /// ```
 pub fn export_session_csv(result: &SessionResult, path: &str) -> Result<(), Box<dyn Error>> {
+    export_session_csv_with_options(result, path, SessionCsvOptions::default())
+}
+
+/// Export session results to CSV with optional extra columns
+///
+/// With `include_skill_columns`, each row also carries the skill estimate for
+/// the shot's club category after that shot was processed, taken from the
+/// session's convergence trajectory:
+/// - `sigma_after`, `confidence_after`: blank until the category's first update
+/// - `kalman_updated`: true if the shot triggered a Kalman update
+///
+/// # Arguments
+/// * `result` - The session result to export
+/// * `path` - Output file path
+/// * `options` - Column options
+pub fn export_session_csv_with_options(
+    result: &SessionResult,
+    path: &str,
+    options: SessionCsvOptions,
+) -> Result<(), Box<dyn Error>> {
     let mut wtr = Writer::from_path(path)?;
     
     // Write header
-    wtr.write_record(&[
+    let mut header = vec![
         "shot_num",
         "hole_id",
         "hole_distance_yds",
@@ -57,9 +116,15 @@ pub fn export_session_csv(result: &SessionResult, path: &str) -> Result<(), Box<
         "net_gain_loss",
         "cumulative_net",
         "is_fat_tail",
-    ])?;
+    ];
+    if options.include_skill_columns {
+        header.extend(["sigma_after", "confidence_after", "kalman_updated"]);
+    }
+    wtr.write_record(&header)?;
     
     let mut cumulative_net = 0.0;
+    let mut trajectory = result.convergence_trajectory.iter().peekable();
+    let mut latest_skill: HashMap<ClubCategory, (f64, f64)> = HashMap::new();
     
     for (i, shot) in result.shots.iter().enumerate() {
         let net = shot.payout - shot.wager;
@@ -67,7 +132,7 @@ pub fn export_session_csv(result: &SessionResult, path: &str) -> Result<(), Box<
         
         let hole = crate::models::hole::get_hole_by_id(shot.hole_id).unwrap();
         
-        wtr.write_record(&[
+        let mut record = vec![
             (i + 1).to_string(),
             shot.hole_id.to_string(),
             hole.distance_yds.to_string(),
@@ -78,13 +143,285 @@ pub fn export_session_csv(result: &SessionResult, path: &str) -> Result<(), Box<
             format!("{:.2}", net),
             format!("{:.2}", cumulative_net),
             shot.is_fat_tail.to_string(),
-        ])?;
+        ];
+        
+        if options.include_skill_columns {
+            let mut kalman_updated = false;
+            while let Some((_, category, confidence, sigma)) =
+                trajectory.next_if(|(shot_index, _, _, _)| *shot_index == i)
+            {
+                latest_skill.insert(*category, (*sigma, *confidence));
+                kalman_updated |= *category == hole.category;
+            }
+            
+            match latest_skill.get(&hole.category) {
+                Some((sigma, confidence)) => {
+                    record.push(format!("{:.4}", sigma));
+                    record.push(format!("{:.2}", confidence));
+                }
+                None => {
+                    record.push(String::new());
+                    record.push(String::new());
+                }
+            }
+            record.push(kalman_updated.to_string());
+        }
+        
+        wtr.write_record(&record)?;
     }
     
     wtr.flush()?;
     Ok(())
 }
 
+/// Export a full session result to JSON
+///
+/// Writes the whole `SessionResult` (totals, every shot, skill trajectory and
+/// anti-cheat reports) inside an `ExportEnvelope` with `kind = "session"`.
+/// Floats are written so they read back bit-for-bit.
+///
+/// # Arguments
+/// * `result` - The session result to export
+/// * `path` - Output file path (e.g., "session_results.json")
+///
+/// # Returns
+/// Result indicating success or error
+pub fn export_session_json(result: &SessionResult, path: &str) -> Result<(), Box<dyn Error>> {
+    let envelope = ExportEnvelope {
+        schema_version: EXPORT_SCHEMA_VERSION,
+        kind: "session".to_string(),
+        data: result,
+    };
+    let json = serde_json::to_string_pretty(&envelope)?;
+    let mut file = File::create(path)?;
+    file.write_all(json.as_bytes())?;
+    Ok(())
+}
+
+/// Read a session exported by `export_session_json`
+///
+/// # Errors
+/// If the file is not a session export or was written by a newer schema version
+pub fn read_session_json(path: &str) -> Result<SessionResult, Box<dyn Error>> {
+    let contents = std::fs::read_to_string(path)?;
+    let envelope: ExportEnvelope<SessionResult> = serde_json::from_str(&contents)?;
+    if envelope.kind != "session" {
+        return Err(format!("Expected a session export, found '{}'", envelope.kind).into());
+    }
+    if envelope.schema_version > EXPORT_SCHEMA_VERSION {
+        return Err(format!(
+            "Session export uses schema version {}, newest supported is {}",
+            envelope.schema_version, EXPORT_SCHEMA_VERSION
+        )
+        .into());
+    }
+    Ok(envelope.data)
+}
+
+/// One NDJSON line: the shot's fields plus the running net
+#[derive(Serialize)]
+struct NdjsonShot<'a> {
+    #[serde(flatten)]
+    shot: &'a ShotOutcome,
+    cumulative_net: f64,
+}
+
+/// Append-only NDJSON shot log, one JSON object per line
+///
+/// Each line is a `ShotOutcome` plus `cumulative_net` (payouts minus wagers
+/// so far). Every line is flushed as soon as it is written, so the file can
+/// be tailed while a session is still running.
+///
+/// # Example
+/// ```no_run
+/// use continuum_golf_simulator::models::player::Player;
+/// use continuum_golf_simulator::simulators::player_session::{SessionConfig, SessionState};
+/// use continuum_golf_simulator::analytics::export::NdjsonShotWriter;
+///
+/// let mut player = Player::new("live".to_string(), 15);
+/// let mut session = SessionState::new(SessionConfig::default());
+/// let mut log = NdjsonShotWriter::create("live_shots.ndjson").unwrap();
+/// for wager in [5.0, 10.0, 5.0] {
+///     let outcome = session.play_shot(&mut player, wager);
+///     log.write_shot(&outcome).unwrap();
+/// }
+/// ```
+pub struct NdjsonShotWriter {
+    writer: BufWriter<File>,
+    cumulative_net: f64,
+}
+
+impl NdjsonShotWriter {
+    /// Create (or truncate) the log at `path`
+    pub fn create(path: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(Self { writer: BufWriter::new(File::create(path)?), cumulative_net: 0.0 })
+    }
+
+    /// Append one shot and flush it to disk
+    pub fn write_shot(&mut self, shot: &ShotOutcome) -> Result<(), Box<dyn Error>> {
+        self.cumulative_net += shot.payout - shot.wager;
+        let mut line = serde_json::to_vec(&NdjsonShot { shot, cumulative_net: self.cumulative_net })?;
+        line.push(b'\n');
+        self.writer.write_all(&line)?;
+        self.writer.flush()?;
+        Ok(())
+    }
+}
+
+/// Export session shots to NDJSON (newline-delimited JSON)
+///
+/// Writes every shot of a finished session with [`NdjsonShotWriter`]; use the
+/// writer directly with `SessionState` to stream shots as they are played.
+///
+/// # Arguments
+/// * `result` - The session result to export
+/// * `path` - Output file path (e.g., "session_shots.ndjson")
+///
+/// # Returns
+/// Result indicating success or error
+pub fn export_session_ndjson(result: &SessionResult, path: &str) -> Result<(), Box<dyn Error>> {
+    let mut writer = NdjsonShotWriter::create(path)?;
+    for shot in &result.shots {
+        writer.write_shot(shot)?;
+    }
+    Ok(())
+}
+
+/// Longest cumulative-net sparkline in a Markdown report, in characters
+const SPARKLINE_WIDTH: usize = 60;
+
+/// Export a session report to Markdown
+///
+/// The GitHub-flavoured report has the same financial summary and final skill
+/// profiles as the CLI's session output, plus win rate, fat-tail count,
+/// Kalman update count and a sparkline of cumulative net over the session.
+///
+/// # Arguments
+/// * `result` - The session result to report
+/// * `path` - Output file path (e.g., "session_report.md")
+///
+/// # Returns
+/// Result indicating success or error
+///
+/// # Example
+/// ```no_run
+/// use continuum_golf_simulator::models::player::Player;
+/// use continuum_golf_simulator::simulators::player_session::{SessionConfig, run_session};
+/// use continuum_golf_simulator::analytics::export::export_session_markdown;
+///
+/// let mut player = Player::new("player_1".to_string(), 15);
+/// let result = run_session(&mut player, SessionConfig::default());
+/// export_session_markdown(&result, "session_report.md").unwrap();
+/// ```
+pub fn export_session_markdown(result: &SessionResult, path: &str) -> Result<(), Box<dyn Error>> {
+    let signed_dollars = |amount: f64| {
+        if amount >= 0.0 {
+            format!("+${:.2}", amount)
+        } else {
+            format!("-${:.2}", -amount)
+        }
+    };
+    let fat_tails = result.shots.iter().filter(|shot| shot.is_fat_tail).count();
+
+    let mut md = String::from("# Session Report\n\n## Financial Summary\n\n");
+    md.push_str("| Metric | Value |\n|---|---:|\n");
+    md.push_str(&format!("| Shots | {} |\n", result.shots.len()));
+    md.push_str(&format!("| Total Wagered | ${:.2} |\n", result.total_wagered));
+    md.push_str(&format!("| Total Won | ${:.2} |\n", result.total_won));
+    md.push_str(&format!("| Net Gain/Loss | {} |\n", signed_dollars(result.net_gain_loss)));
+    md.push_str(&format!("| Session House Edge | {:.2}% |\n", result.house_edge_percent()));
+    md.push_str(&format!("| Win Rate | {:.1}% |\n", result.win_rate()));
+    md.push_str(&format!("| Fat-Tail Shots | {} |\n", fat_tails));
+    md.push_str(&format!("| Kalman Updates | {} |\n", result.num_kalman_updates));
+
+    md.push_str("\n## Final Skill Profiles\n\n| Category | Dispersion (σ) |\n|---|---:|\n");
+    let mut profiles: Vec<(&String, &f64)> = result.final_skill_profiles.iter().collect();
+    profiles.sort_by(|a, b| a.0.cmp(b.0));
+    for (category, sigma) in profiles {
+        md.push_str(&format!("| {} | {:.1} ft |\n", category, sigma));
+    }
+
+    let cumulative_net: Vec<f64> = result
+        .shots
+        .iter()
+        .scan(0.0, |net, shot| {
+            *net += shot.payout - shot.wager;
+            Some(*net)
+        })
+        .collect();
+    md.push_str("\n## Cumulative Net\n\n");
+    md.push_str(&format!("```\n{}\n```\n", sparkline(&cumulative_net, SPARKLINE_WIDTH)));
+    if let (Some(low), Some(high)) = (
+        cumulative_net.iter().cloned().reduce(f64::min),
+        cumulative_net.iter().cloned().reduce(f64::max),
+    ) {
+        md.push_str(&format!("\nLow {}, high {}\n", signed_dollars(low), signed_dollars(high)));
+    }
+
+    let mut file = File::create(path)?;
+    file.write_all(md.as_bytes())?;
+    Ok(())
+}
+
+/// Render `values` as a block-character sparkline at most `width` wide
+///
+/// Longer series are downsampled to the last value of each of `width` buckets.
+fn sparkline(values: &[f64], width: usize) -> String {
+    const BARS: [char; 8] = ['▁', '▂', '▃', '▄', '▅', '▆', '▇', '█'];
+    if values.is_empty() || width == 0 {
+        return String::new();
+    }
+
+    let points: Vec<f64> = if values.len() > width {
+        (1..=width).map(|bucket| values[bucket * values.len() / width - 1]).collect()
+    } else {
+        values.to_vec()
+    };
+    let low = points.iter().cloned().fold(f64::INFINITY, f64::min);
+    let high = points.iter().cloned().fold(f64::NEG_INFINITY, f64::max);
+    let span = high - low;
+
+    points
+        .iter()
+        .map(|value| {
+            if span > 0.0 {
+                BARS[(((value - low) / span) * (BARS.len() - 1) as f64).round() as usize]
+            } else {
+                BARS[0]
+            }
+        })
+        .collect()
+}
+
+/// One row of a shot log; other columns in the file are ignored
+#[derive(Debug, Deserialize)]
+struct ShotLogRow {
+    miss_distance_ft: f64,
+    wager: f64,
+    hole_id: u8,
+}
+
+/// Load a shot log CSV for `Player::from_shot_log`
+///
+/// The file needs a header with `miss_distance_ft`, `wager` and `hole_id`
+/// columns; any others (such as the rest of an `export_session_csv` file)
+/// are ignored.
+///
+/// # Returns
+/// (miss_distance_ft, wager, hole_id) per row, in file order
+///
+/// # Errors
+/// If the file cannot be read, a column is missing, or a value does not parse
+pub fn load_shot_log_csv(path: &str) -> Result<Vec<LoggedShot>, Box<dyn Error>> {
+    let mut reader = csv::Reader::from_path(path)?;
+    let mut shots = Vec::new();
+    for row in reader.deserialize() {
+        let row: ShotLogRow = row?;
+        shots.push((row.miss_distance_ft, row.wager, row.hole_id));
+    }
+    Ok(shots)
+}
+
 /// Export venue results to JSON format
 ///
 /// Creates a comprehensive JSON file with all venue simulation data including:
@@ -110,6 +447,7 @@ pub fn export_session_csv(result: &SessionResult, path: &str) -> Result<(), Box<
 ///     hours: 8.0,
 ///     shots_per_hour: 100,
 ///     player_archetype: PlayerArchetype::Uniform,
+///     ..Default::default()
 /// };
 /// let result = run_venue_simulation(config);
 /// export_venue_json(&result, "venue_results.json").unwrap();
@@ -121,6 +459,87 @@ pub fn export_venue_json(result: &VenueResult, path: &str) -> Result<(), Box<dyn
     Ok(())
 }
 
+/// Parquet schema of `export_venue_parquet`, one row per shot
+const VENUE_SHOTS_SCHEMA: &str = "
+    message venue_shots {
+        REQUIRED INT32 hole_id (INTEGER(8, false));
+        REQUIRED DOUBLE wager;
+        REQUIRED DOUBLE miss_distance;
+        REQUIRED DOUBLE multiplier;
+        REQUIRED DOUBLE payout;
+        REQUIRED BOOLEAN is_fat_tail;
+    }
+";
+
+/// Shots per Parquet row group
+const PARQUET_ROW_GROUP_SIZE: usize = 1 << 20;
+
+/// Export a venue's per-shot data to Parquet
+///
+/// Writes one row per shot in `result.shots` with columns hole_id, wager,
+/// miss_distance, multiplier, payout and is_fat_tail. Far quicker to load
+/// into dataframe tools than `export_venue_json` for large runs.
+///
+/// # Arguments
+/// * `result` - Venue result run with `retain_shots` set
+/// * `path` - Output file path (e.g., "venue_shots.parquet")
+///
+/// # Returns
+/// Result indicating success or error; fails if the venue played shots
+/// but did not retain them
+///
+/// # Example
+/// ```no_run
+/// use continuum_golf_simulator::simulators::venue::{VenueConfig, run_venue_simulation};
+/// use continuum_golf_simulator::analytics::export::export_venue_parquet;
+///
+/// let config = VenueConfig {
+///     num_bays: 50,
+///     retain_shots: true,
+///     ..Default::default()
+/// };
+/// let result = run_venue_simulation(config);
+/// export_venue_parquet(&result, "venue_shots.parquet").unwrap();
+/// ```
+pub fn export_venue_parquet(result: &VenueResult, path: &str) -> Result<(), Box<dyn Error>> {
+    if result.shots.is_empty() && result.total_shots > 0 {
+        return Err("Venue shots were not retained; run with VenueConfig::retain_shots".into());
+    }
+
+    let schema = Arc::new(parse_message_type(VENUE_SHOTS_SCHEMA)?);
+    let properties = Arc::new(WriterProperties::builder().build());
+    let mut writer = SerializedFileWriter::new(File::create(path)?, schema, properties)?;
+
+    for shots in result.shots.chunks(PARQUET_ROW_GROUP_SIZE) {
+        let doubles = |value: fn(&ShotOutcome) -> f64| shots.iter().map(value).collect::<Vec<f64>>();
+        let mut row_group = writer.next_row_group()?;
+        // Columns in schema order
+        let hole_ids: Vec<i32> = shots.iter().map(|shot| shot.hole_id as i32).collect();
+        write_parquet_column::<Int32Type>(&mut row_group, &hole_ids)?;
+        write_parquet_column::<DoubleType>(&mut row_group, &doubles(|shot| shot.wager))?;
+        write_parquet_column::<DoubleType>(&mut row_group, &doubles(|shot| shot.miss_distance_ft))?;
+        write_parquet_column::<DoubleType>(&mut row_group, &doubles(|shot| shot.multiplier))?;
+        write_parquet_column::<DoubleType>(&mut row_group, &doubles(|shot| shot.payout))?;
+        let fat_tails: Vec<bool> = shots.iter().map(|shot| shot.is_fat_tail).collect();
+        write_parquet_column::<BoolType>(&mut row_group, &fat_tails)?;
+        row_group.close()?;
+    }
+
+    writer.close()?;
+    Ok(())
+}
+
+/// Write the next column of a Parquet row group
+fn write_parquet_column<T: DataType>(
+    row_group: &mut SerializedRowGroupWriter<'_, File>,
+    values: &[T::T],
+) -> Result<(), Box<dyn Error>> {
+    let mut column = row_group.next_column()?.ok_or("Parquet schema has fewer columns than written")?;
+    column.typed::<T>().write_batch(values, None, None)?;
+    column.close()?;
+    Ok(())
+}
+
 /// Export heatmap data to CSV format
 ///
 /// Creates a CSV matrix with:
@@ -145,6 +564,7 @@ pub fn export_venue_json(result: &VenueResult, path: &str) -> Result<(), Box<dyn
 ///     hours: 8.0,
 ///     shots_per_hour: 100,
 ///     player_archetype: PlayerArchetype::Uniform,
+///     ..Default::default()
 /// };
 /// let result = run_venue_simulation(config);
 /// export_heatmap_csv(&result.heatmap_data, "heatmap.csv").unwrap();
@@ -181,7 +601,11 @@ pub fn export_heatmap_csv(heatmap: &HeatmapData, path: &str) -> Result<(), Box<d
 /// Export P_max history to CSV format
 ///
 /// Creates a time-series CSV showing how P_max values evolved for each club category
-/// as the player's skill was updated via Kalman filtering.
+/// as the player's skill was updated via Kalman filtering. Each row also carries the
+/// sigma estimate (at the category's reference distance) and confidence right after
+/// that update, so odds changes can be lined up with skill changes. Profiles
+/// saved before those histories existed still export every P_max row, with the
+/// sigma and confidence columns left empty.
 ///
 /// # Arguments
 /// * `player` - The player whose P_max history to export
@@ -196,12 +620,14 @@ pub fn export_heatmap_csv(heatmap: &HeatmapData, path: &str) -> Result<(), Box<d
 /// use continuum_golf_simulator::simulators::player_session::{SessionConfig, run_session, HoleSelection};
 /// use continuum_golf_simulator::analytics::export::export_pmax_history;
 ///
-/// let mut player = Player::new(15);
+/// let mut player = Player::new("player_1".to_string(), 15);
 /// let config = SessionConfig {
 ///     num_shots: 100,
-///     wager_range: (5.0, 10.0),
+///     wager_min: 5.0,
+///     wager_max: 10.0,
 ///     hole_selection: HoleSelection::Random,
 ///     developer_mode: None,
+///     ..Default::default()
 /// };
 /// let result = run_session(&mut player, config);
 /// export_pmax_history(&player, "pmax_history.csv").unwrap();
@@ -210,7 +636,7 @@ pub fn export_pmax_history(player: &Player, path: &str) -> Result<(), Box<dyn Er
     let mut wtr = Writer::from_path(path)?;
     
     // Write header
-    wtr.write_record(&["update_num", "club_category", "p_max"])?;
+    wtr.write_record(["update_num", "club_category", "p_max", "sigma", "confidence"])?;
     
     for (category, profile) in &player.skill_profiles {
         let category_name = match category {
@@ -220,10 +646,14 @@ pub fn export_pmax_history(player: &Player, path: &str) -> Result<(), Box<dyn Er
         };
         
         for (i, p_max) in profile.p_max_history.iter().enumerate() {
-            wtr.write_record(&[
+            let sigma = profile.sigma_history.get(i);
+            let confidence = profile.confidence_history.get(i);
+            wtr.write_record([
                 (i + 1).to_string(),
                 category_name.to_string(),
                 format!("{:.4}", p_max),
+                sigma.map_or(String::new(), |sigma| format!("{:.4}", sigma)),
+                confidence.map_or(String::new(), |confidence| format!("{:.2}", confidence)),
             ])?;
         }
     }
@@ -248,10 +678,10 @@ pub fn export_convergence_csv(
 ) -> Result<(), Box<dyn Error>> {
     let mut wtr = Writer::from_path(path)?;
     
-    wtr.write_record(&["shot_num", "confidence_pct", "skill_sigma"])?;
+    wtr.write_record(["shot_num", "confidence_pct", "skill_sigma"])?;
     
     for (shot_num, confidence, sigma) in convergence_data {
-        wtr.write_record(&[
+        wtr.write_record([
             shot_num.to_string(),
             format!("{:.2}", confidence),
             format!("{:.2}", sigma),
@@ -281,6 +711,7 @@ mod tests {
             developer_mode: None,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
         let result = run_session(&mut player, config);
         
@@ -297,6 +728,176 @@ mod tests {
         fs::remove_file(path).ok();
     }
 
+    #[test]
+    fn test_export_session_csv_skill_columns() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 40,
+            hole_selection: HoleSelection::Fixed(4),
+            seed: Some(11),
+            ..Default::default()
+        };
+        let result = run_session(&mut player, config);
+        
+        let path = "test_session_skill_columns.csv";
+        export_session_csv_with_options(
+            &result,
+            path,
+            SessionCsvOptions { include_skill_columns: true },
+        ).unwrap();
+        
+        let mut reader = csv::Reader::from_path(path).unwrap();
+        let headers = reader.headers().unwrap().clone();
+        assert!(headers.iter().any(|h| h == "sigma_after"));
+        assert!(headers.iter().any(|h| h == "confidence_after"));
+        assert!(headers.iter().any(|h| h == "kalman_updated"));
+        
+        let rows: Vec<csv::StringRecord> = reader.records().map(|r| r.unwrap()).collect();
+        assert_eq!(rows.len(), 40);
+        
+        let mut previous_sigma: Option<f64> = None;
+        let mut num_updated = 0;
+        for row in &rows {
+            let sigma_cell = &row[10];
+            let updated: bool = row[12].parse().unwrap();
+            if updated {
+                num_updated += 1;
+                let sigma: f64 = sigma_cell.parse().unwrap();
+                if let Some(previous) = previous_sigma {
+                    assert_ne!(sigma, previous, "Update should change sigma_after");
+                }
+                previous_sigma = Some(sigma);
+            } else if let Some(previous) = previous_sigma {
+                assert_eq!(sigma_cell.parse::<f64>().unwrap(), previous);
+            } else {
+                assert!(sigma_cell.is_empty());
+            }
+        }
+        assert!(num_updated >= 40 / 5);
+        
+        // Default export keeps the original columns
+        export_session_csv(&result, path).unwrap();
+        let contents = fs::read_to_string(path).unwrap();
+        assert!(!contents.lines().next().unwrap().contains("sigma_after"));
+        
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_export_session_json_round_trip() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 25,
+            hole_selection: HoleSelection::Random,
+            seed: Some(29),
+            ..Default::default()
+        };
+        let result = run_session(&mut player, config);
+
+        let path = "test_session.json";
+        export_session_json(&result, path).unwrap();
+
+        let parsed: serde_json::Value = serde_json::from_str(&fs::read_to_string(path).unwrap()).unwrap();
+        assert_eq!(parsed["schema_version"], EXPORT_SCHEMA_VERSION);
+        assert_eq!(parsed["kind"], "session");
+
+        let restored = read_session_json(path).unwrap();
+        assert_eq!(restored.shots.len(), 25);
+        assert_eq!(restored.net_gain_loss, result.net_gain_loss);
+        assert_eq!(restored.shots[0].miss_distance_ft, result.shots[0].miss_distance_ft);
+        assert_eq!(restored.convergence_trajectory, result.convergence_trajectory);
+
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_export_session_ndjson() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 30,
+            hole_selection: HoleSelection::Random,
+            seed: Some(275),
+            ..Default::default()
+        };
+        let result = run_session(&mut player, config);
+
+        let path = "test_session.ndjson";
+        export_session_ndjson(&result, path).unwrap();
+
+        let contents = fs::read_to_string(path).unwrap();
+        let lines: Vec<&str> = contents.lines().collect();
+        assert_eq!(lines.len(), result.shots.len());
+
+        let last: serde_json::Value = serde_json::from_str(lines[lines.len() - 1]).unwrap();
+        assert_eq!(last["hole_id"], result.shots[29].hole_id);
+        assert!((last["cumulative_net"].as_f64().unwrap() - result.net_gain_loss).abs() < 1e-9);
+        let first: ShotOutcome = serde_json::from_str(lines[0]).unwrap();
+        assert_eq!(first, result.shots[0]);
+
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_export_session_markdown() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 200,
+            hole_selection: HoleSelection::Random,
+            seed: Some(276),
+            ..Default::default()
+        };
+        let result = run_session(&mut player, config);
+
+        let path = "test_session_report.md";
+        export_session_markdown(&result, path).unwrap();
+
+        let contents = fs::read_to_string(path).unwrap();
+        assert!(contents.starts_with("# Session Report"));
+        assert!(contents.contains(&format!("| Total Wagered | ${:.2} |", result.total_wagered)));
+        assert!(contents.contains(&format!("| Kalman Updates | {} |", result.num_kalman_updates)));
+        assert!(contents.contains("| Fat-Tail Shots |"));
+        assert_eq!(contents.matches(" ft |").count(), result.final_skill_profiles.len());
+
+        // 200 shots are squeezed into a full-width sparkline
+        let sparkline = contents.lines().skip_while(|line| *line != "```").nth(1).unwrap();
+        assert_eq!(sparkline.chars().count(), SPARKLINE_WIDTH);
+
+        fs::remove_file(path).ok();
+    }
+
+    #[test]
+    fn test_sparkline_scales_to_range() {
+        assert_eq!(sparkline(&[0.0, 1.0, 2.0, 3.0, 4.0, 5.0, 6.0, 7.0], 10), "▁▂▃▄▅▆▇█");
+        assert_eq!(sparkline(&[5.0, 5.0], 10), "▁▁");
+        assert_eq!(sparkline(&[], 10), "");
+    }
+
+    #[test]
+    fn test_load_shot_log_csv_from_session_export() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let result = run_session(&mut player, SessionConfig { num_shots: 30, ..Default::default() });
+
+        let path = "test_shot_log.csv";
+        export_session_csv(&result, path).unwrap();
+        let shots = load_shot_log_csv(path).unwrap();
+        fs::remove_file(path).ok();
+
+        assert_eq!(shots.len(), 30);
+        for ((miss, wager, hole_id), shot) in shots.iter().zip(&result.shots) {
+            assert_eq!(*hole_id, shot.hole_id);
+            // The export rounds to cents / hundredths of a foot
+            assert!((miss - shot.miss_distance_ft).abs() < 0.01);
+            assert!((wager - shot.wager).abs() < 0.01);
+        }
+
+        let imported = Player::from_shot_log("imported".to_string(), &shots);
+        assert!(imported.skill_profiles.values().any(|p| p.kalman_filter.error_covariance < 1000.0));
+
+        fs::write(path, "hole_id,wager\n4,10.0\n").unwrap();
+        assert!(load_shot_log_csv(path).is_err());
+        fs::remove_file(path).ok();
+    }
+
     #[test]
     fn test_export_venue_json() {
         let config = VenueConfig {
@@ -305,6 +906,7 @@ mod tests {
             shots_per_hour: 50,
             player_archetype: PlayerArchetype::Uniform,
             wager_range: (5.0, 10.0),
+            ..Default::default()
         };
         let result = run_venue_simulation(config);
 
@@ -316,11 +918,62 @@ mod tests {
         let parsed: serde_json::Value = serde_json::from_str(&contents).unwrap();
         assert!(parsed["total_wagered"].is_number());
         assert!(parsed["net_profit"].is_number());
+        assert!(parsed["per_hole"].is_object());
+
+        // Per-hole stats survive the round trip (hole ids become string keys)
+        let restored: VenueResult = serde_json::from_str(&contents).unwrap();
+        assert_eq!(restored.per_hole.len(), result.per_hole.len());
+        for (hole_id, stats) in &result.per_hole {
+            assert_eq!(restored.per_hole[hole_id].shot_count, stats.shot_count);
+        }
         
         // Cleanup
         fs::remove_file(path).ok();
     }
 
+    #[test]
+    fn test_export_venue_parquet() {
+        use parquet::file::reader::{FileReader, SerializedFileReader};
+        use parquet::record::RowAccessor;
+
+        let config = VenueConfig {
+            num_bays: 4,
+            hours: 1.0,
+            shots_per_hour: 50,
+            seed: Some(274),
+            ..Default::default()
+        };
+        let path = "test_venue_shots.parquet";
+
+        // Shots are only kept on request
+        let result = run_venue_simulation(config.clone());
+        assert!(result.shots.is_empty());
+        assert!(export_venue_parquet(&result, path).is_err());
+
+        let result = run_venue_simulation(VenueConfig { retain_shots: true, ..config });
+        assert_eq!(result.shots.len(), result.total_shots);
+        export_venue_parquet(&result, path).unwrap();
+
+        let reader = SerializedFileReader::new(File::open(path).unwrap()).unwrap();
+        let schema = reader.metadata().file_metadata().schema_descr();
+        let columns: Vec<&str> = schema.columns().iter().map(|column| column.name()).collect();
+        assert_eq!(columns, ["hole_id", "wager", "miss_distance", "multiplier", "payout", "is_fat_tail"]);
+
+        let rows: Vec<_> = reader.get_row_iter(None).unwrap().map(|row| row.unwrap()).collect();
+        assert_eq!(rows.len(), result.total_shots);
+        for (row, shot) in rows.iter().zip(&result.shots) {
+            assert_eq!(row.get_ubyte(0).unwrap(), shot.hole_id);
+            assert_eq!(row.get_double(1).unwrap(), shot.wager);
+            assert_eq!(row.get_double(2).unwrap(), shot.miss_distance_ft);
+            assert_eq!(row.get_double(4).unwrap(), shot.payout);
+            assert_eq!(row.get_bool(5).unwrap(), shot.is_fat_tail);
+        }
+        let wagered: f64 = rows.iter().map(|row| row.get_double(1).unwrap()).sum();
+        assert!((wagered - result.total_wagered).abs() < 1e-6);
+
+        fs::remove_file(path).ok();
+    }
+
     #[test]
     fn test_export_heatmap_csv() {
         let config = VenueConfig {
@@ -329,6 +982,7 @@ mod tests {
             shots_per_hour: 50,
             player_archetype: PlayerArchetype::Uniform,
             wager_range: (5.0, 10.0),
+            ..Default::default()
         };
         let result = run_venue_simulation(config);
 
@@ -339,7 +993,7 @@ mod tests {
         let contents = fs::read_to_string(path).unwrap();
         assert!(contents.contains("Distance"));
         // Heatmap has bins like "0-4", "5-9", etc
-        assert!(contents.len() > 0);
+        assert!(!contents.is_empty());
         
         // Cleanup
         fs::remove_file(path).ok();
@@ -356,6 +1010,7 @@ mod tests {
             developer_mode: None,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
         let _result = run_session(&mut player, config);
         
@@ -364,9 +1019,20 @@ mod tests {
         
         // Verify file exists
         let contents = fs::read_to_string(path).unwrap();
-        assert!(contents.contains("update_num"));
-        assert!(contents.contains("club_category"));
-        assert!(contents.contains("p_max"));
+        assert!(contents.starts_with("update_num,club_category,p_max,sigma,confidence\n"));
+        let updates: usize = player.skill_profiles.values().map(|profile| profile.p_max_history.len()).sum();
+        assert_eq!(contents.lines().count(), updates + 1);
+        assert!(contents.lines().skip(1).all(|line| line.split(',').count() == 5));
+
+        // A profile saved before the sigma and confidence histories keeps its rows
+        for profile in player.skill_profiles.values_mut() {
+            profile.sigma_history.clear();
+            profile.confidence_history.clear();
+        }
+        export_pmax_history(&player, path).unwrap();
+        let contents = fs::read_to_string(path).unwrap();
+        assert_eq!(contents.lines().count(), updates + 1);
+        assert!(contents.lines().skip(1).all(|line| line.ends_with(",,")));
         
         // Cleanup
         fs::remove_file(path).ok();
@@ -405,6 +1071,7 @@ mod tests {
             developer_mode: None,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
         let result = run_session(&mut player, config);
         
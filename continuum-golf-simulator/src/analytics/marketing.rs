@@ -0,0 +1,166 @@
+//! Marketing scenario module
+//!
+//! Turns simulated sessions into narrative-ready numbers for promotional
+//! copy ("session of the day"): typical outcome, best and worst cases, and
+//! the chance of a big win.
+
+use crate::math::distributions::percentile;
+use crate::simulators::player_session::{run_session_with_rng, HoleSelection, SessionConfig};
+use crate::simulators::venue::{generate_player_pool_with_rng, PlayerArchetype};
+use rand::Rng;
+use serde::{Deserialize, Serialize};
+
+/// Payout multiplier that counts as a "big win"
+pub const BIG_WIN_MULTIPLIER: f64 = 10.0;
+
+/// How a scenario player sizes their wagers
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub enum WagerPolicy {
+    /// Same wager on every shot
+    Flat(f64),
+    /// Wager drawn uniformly from [min, max] on every shot
+    Range { min: f64, max: f64 },
+}
+
+impl WagerPolicy {
+    /// (min, max) wager bounds for a session config
+    pub fn bounds(&self) -> (f64, f64) {
+        match *self {
+            WagerPolicy::Flat(wager) => (wager, wager),
+            WagerPolicy::Range { min, max } => (min, max),
+        }
+    }
+}
+
+/// Summary of a simulated marketing scenario
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct ScenarioReport {
+    /// Number of simulated sessions
+    pub trials: usize,
+    /// Shots per session
+    pub shots: usize,
+    /// Mean amount wagered per session
+    pub mean_wagered: f64,
+    /// Mean net result per session (negative = player loses)
+    pub expected_net: f64,
+    /// Worst case: 5th-percentile net result
+    pub p5_net: f64,
+    /// Median net result
+    pub median_net: f64,
+    /// Best case: 95th-percentile net result
+    pub p95_net: f64,
+    /// Fraction of sessions with at least one shot paying `BIG_WIN_MULTIPLIER`× or more
+    pub big_win_probability: f64,
+}
+
+/// Simulate a "session of the day" scenario end to end
+///
+/// Each trial draws a fresh player from `archetype` and plays one session of
+/// `shots` shots under `wager_policy` on `hole_mix`, then the net results are
+/// summarised into percentiles.
+///
+/// # Arguments
+/// * `archetype` - Target player population
+/// * `wager_policy` - How wagers are sized
+/// * `hole_mix` - (hole_id, weight) pairs; weights are normalized
+/// * `shots` - Shots per session
+/// * `trials` - Number of simulated sessions
+pub fn marketing_scenario(
+    archetype: &PlayerArchetype,
+    wager_policy: WagerPolicy,
+    hole_mix: &[(u8, f64)],
+    shots: usize,
+    trials: usize,
+) -> ScenarioReport {
+    marketing_scenario_with_rng(archetype, wager_policy, hole_mix, shots, trials, &mut rand::thread_rng())
+}
+
+/// Simulate a marketing scenario drawing players and shots from `rng`
+///
+/// Same as [`marketing_scenario`], but reproducible with a seeded `rng`.
+pub fn marketing_scenario_with_rng(
+    archetype: &PlayerArchetype,
+    wager_policy: WagerPolicy,
+    hole_mix: &[(u8, f64)],
+    shots: usize,
+    trials: usize,
+    rng: &mut impl Rng,
+) -> ScenarioReport {
+    let total_weight: f64 = hole_mix.iter().map(|(_, w)| w).sum();
+    let weights = hole_mix
+        .iter()
+        .map(|(hole_id, weight)| (*hole_id, weight / total_weight))
+        .collect();
+    let (wager_min, wager_max) = wager_policy.bounds();
+    let config = SessionConfig {
+        num_shots: shots,
+        wager_min,
+        wager_max,
+        hole_selection: HoleSelection::Weighted(weights),
+        ..Default::default()
+    };
+
+    let mut nets = Vec::with_capacity(trials);
+    let mut total_wagered = 0.0;
+    let mut big_wins = 0;
+
+    for mut player in generate_player_pool_with_rng(archetype, trials, rng) {
+        let result = run_session_with_rng(&mut player, config.clone(), rng);
+        total_wagered += result.total_wagered;
+        if result.shots.iter().any(|shot| shot.multiplier >= BIG_WIN_MULTIPLIER) {
+            big_wins += 1;
+        }
+        nets.push(result.net_gain_loss);
+    }
+
+    nets.sort_by(|a, b| a.total_cmp(b));
+    let n = trials.max(1) as f64;
+
+    ScenarioReport {
+        trials,
+        shots,
+        mean_wagered: total_wagered / n,
+        expected_net: nets.iter().sum::<f64>() / n,
+        p5_net: percentile(&nets, 0.05),
+        median_net: percentile(&nets, 0.50),
+        p95_net: percentile(&nets, 0.95),
+        big_win_probability: big_wins as f64 / n,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use rand::rngs::StdRng;
+    use rand::SeedableRng;
+
+    #[test]
+    fn test_marketing_scenario_percentiles() {
+        let mut rng = StdRng::seed_from_u64(7);
+        let report = marketing_scenario_with_rng(
+            &PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 },
+            WagerPolicy::Range { min: 5.0, max: 20.0 },
+            &[(1, 1.0), (4, 2.0), (7, 1.0)],
+            50,
+            200,
+            &mut rng,
+        );
+
+        assert_eq!(report.trials, 200);
+        assert!(report.p5_net < report.median_net, "{:?}", report);
+        assert!(report.median_net < report.p95_net, "{:?}", report);
+        assert!((0.0..=1.0).contains(&report.big_win_probability));
+        // House edge: the typical session loses money
+        assert!(report.expected_net < 0.0, "{:?}", report);
+    }
+
+    #[test]
+    fn test_percentile_interpolates() {
+        let values = [1.0, 2.0, 3.0, 4.0, 5.0];
+        assert_eq!(percentile(&values, 0.0), 1.0);
+        assert_eq!(percentile(&values, 0.5), 3.0);
+        assert_eq!(percentile(&values, 1.0), 5.0);
+        assert_eq!(percentile(&values, 0.125), 1.5);
+        assert_eq!(percentile(&[], 0.5), 0.0);
+    }
+}
@@ -1,14 +1,21 @@
-/// Analytics module for metrics calculation and data export
-///
-/// This module provides:
-/// - Expected value calculations and validation
-/// - RTP verification across skill levels
-/// - Fairness metrics (EV equality)
-/// - Kalman filter convergence analysis
-/// - Data export utilities (CSV, JSON)
+//! Analytics module for metrics calculation and data export
+//!
+//! This module provides:
+//! - Expected value calculations and validation
+//! - RTP verification across skill levels
+//! - Fairness metrics (EV equality)
+//! - Session-maximum multiplier expectations
+//! - Kalman filter convergence analysis
+//! - Venue planning helpers (capacity decisions)
+//! - Marketing scenarios (narrative-ready session outcomes)
+//! - Data export utilities (CSV, JSON)
 
 pub mod metrics;
 pub mod export;
+pub mod planning;
+pub mod marketing;
 
 pub use metrics::*;
 pub use export::*;
+pub use planning::*;
+pub use marketing::*;
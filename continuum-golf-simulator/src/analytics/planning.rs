@@ -0,0 +1,289 @@
+//! Venue planning module
+//!
+//! Provides business-decision helpers built on the venue simulator:
+//! - Marginal profit of adding a bay
+//! - Dry-run memory and runtime estimates for large venue runs
+//! - Analytic expected hold for a player population
+
+use crate::analytics::metrics::expected_loss_per_hour;
+use crate::models::{hole::ClubCategory, player::Player, shot::ShotOutcome};
+use crate::simulators::player_session::{cohort_session_seed, run_session, HoleSelection, SessionConfig};
+use crate::simulators::venue::{
+    generate_player_pool, handicap_distribution, run_venue_simulation, PlayerArchetype, VenueConfig,
+};
+use serde::{Deserialize, Serialize};
+use std::time::Instant;
+
+/// Shots simulated by the calibration pilot in `estimate_run_cost`
+const PILOT_SHOTS: usize = 2_000;
+
+/// Shots per Kalman update (the batch size), i.e. per trajectory entry
+const SHOTS_PER_KALMAN_UPDATE: f64 = 5.0;
+
+/// Marginal profit estimate for adding one bay to a venue
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct MarginalBayProfit {
+    /// Number of bays in the base configuration
+    pub base_bays: usize,
+    /// Mean profit at `base_bays`
+    pub base_profit: f64,
+    /// Mean profit at `base_bays + 1`
+    pub expanded_profit: f64,
+    /// Mean profit delta (expanded - base)
+    pub marginal_profit: f64,
+    /// Standard error of the mean delta
+    pub std_error: f64,
+    /// 95% confidence interval for the delta: (lower, upper)
+    pub ci_95: (f64, f64),
+    /// Number of paired simulations
+    pub trials: usize,
+}
+
+/// Estimate the expected profit from adding one more bay
+///
+/// Each trial simulates the venue at `num_bays` and `num_bays + 1` and records
+/// the profit difference; the result is the mean difference with a normal
+/// 95% confidence interval. Any occupancy or demand effects modelled by
+/// `run_venue_simulation` are reflected in the delta, so diminishing returns
+/// show up here without special handling.
+///
+/// # Arguments
+/// * `base_config` - Venue configuration before expansion
+/// * `trials` - Number of paired simulations
+pub fn marginal_bay_profit(base_config: &VenueConfig, trials: usize) -> MarginalBayProfit {
+    let expanded_config = VenueConfig {
+        num_bays: base_config.num_bays + 1,
+        ..base_config.clone()
+    };
+
+    let mut base_total = 0.0;
+    let mut expanded_total = 0.0;
+    let mut deltas = Vec::with_capacity(trials);
+
+    for trial in 0..trials {
+        // A seeded config gives each trial its own seed, shared by both venues
+        // so the pair uses common random numbers
+        let trial_seed = base_config.seed.map(|seed| cohort_session_seed(seed, trial));
+        let base = run_venue_simulation(VenueConfig { seed: trial_seed, ..base_config.clone() }).net_profit;
+        let expanded = run_venue_simulation(VenueConfig { seed: trial_seed, ..expanded_config.clone() }).net_profit;
+        base_total += base;
+        expanded_total += expanded;
+        deltas.push(expanded - base);
+    }
+
+    let n = trials.max(1) as f64;
+    let marginal_profit = deltas.iter().sum::<f64>() / n;
+    let std_error = if trials > 1 {
+        let variance = deltas
+            .iter()
+            .map(|d| (d - marginal_profit).powi(2))
+            .sum::<f64>()
+            / (n - 1.0);
+        (variance / n).sqrt()
+    } else {
+        0.0
+    };
+
+    MarginalBayProfit {
+        base_bays: base_config.num_bays,
+        base_profit: base_total / n,
+        expanded_profit: expanded_total / n,
+        marginal_profit,
+        std_error,
+        ci_95: (marginal_profit - 1.96 * std_error, marginal_profit + 1.96 * std_error),
+        trials,
+    }
+}
+
+/// Projected resource usage for a venue run
+#[derive(Debug, Clone, Copy, Serialize, Deserialize)]
+pub struct RunCostEstimate {
+    /// Shots the run will simulate
+    pub total_shots: usize,
+    /// Whether the estimate assumes `VenueConfig::streaming`
+    pub streaming: bool,
+    /// Projected peak memory held by session results, in bytes
+    pub peak_memory_bytes: f64,
+    /// Projected wall-clock runtime in seconds
+    pub estimated_runtime_secs: f64,
+    /// Measured single-thread cost per shot used for the projection
+    pub seconds_per_shot: f64,
+}
+
+/// Estimate peak memory and runtime of `run_venue_simulation(config)` without running it
+///
+/// Runtime is calibrated from a short pilot session on this machine, then
+/// scaled by total shots and the number of bays that can run in parallel.
+/// Memory counts the shot logs alive at the peak: every bay's session without
+/// streaming, only the sessions in flight (one per worker thread) with it.
+pub fn estimate_run_cost(config: &VenueConfig) -> RunCostEstimate {
+    let mut player = generate_player_pool(&config.player_archetype, 1).remove(0);
+    let pilot_config = SessionConfig {
+        num_shots: PILOT_SHOTS,
+        wager_min: config.wager_range.0,
+        wager_max: config.wager_range.1,
+        hole_selection: HoleSelection::Random,
+        seed: config.seed,
+        ..Default::default()
+    };
+
+    let start = Instant::now();
+    run_session(&mut player, pilot_config);
+    let seconds_per_shot = start.elapsed().as_secs_f64() / PILOT_SHOTS as f64;
+
+    project_run_cost(config, seconds_per_shot)
+}
+
+/// Project run cost from a known per-shot cost (see `estimate_run_cost`)
+pub fn project_run_cost(config: &VenueConfig, seconds_per_shot: f64) -> RunCostEstimate {
+    let total_shots = (config.num_bays as f64 * config.hours * config.shots_per_hour as f64) as usize;
+    let shots_per_bay = total_shots / config.num_bays.max(1);
+    let parallel_bays = rayon::current_num_threads().min(config.num_bays).max(1);
+
+    let bays_in_memory = if config.streaming { parallel_bays } else { config.num_bays };
+    let peak_memory_bytes = (bays_in_memory * shots_per_bay) as f64 * bytes_per_retained_shot();
+
+    // Bays are spread over the worker threads, so the slowest thread runs
+    // ceil(bays / threads) sessions back to back
+    let sessions_per_thread = config.num_bays.div_ceil(parallel_bays);
+    let estimated_runtime_secs = (sessions_per_thread * shots_per_bay) as f64 * seconds_per_shot;
+
+    RunCostEstimate {
+        total_shots,
+        streaming: config.streaming,
+        peak_memory_bytes,
+        estimated_runtime_secs,
+        seconds_per_shot,
+    }
+}
+
+/// Bytes a finished session holds per shot: the shot record plus its share of
+/// the convergence trajectory, doubled to allow for `Vec` growth headroom
+fn bytes_per_retained_shot() -> f64 {
+    let shot = std::mem::size_of::<ShotOutcome>() as f64;
+    let trajectory_entry = std::mem::size_of::<(usize, ClubCategory, f64, f64)>() as f64;
+    2.0 * (shot + trajectory_entry / SHOTS_PER_KALMAN_UPDATE)
+}
+
+/// Expected venue hold for a player population, computed analytically
+///
+/// Weights each handicap's analytic RTP (new players, so prior skill
+/// estimates) over the archetype's handicap distribution and the hole mix:
+///
+/// hold = 1 - Σ_h P(h) × Σ_hole w_hole × RTP(h, hole)
+///
+/// Every bay plays the same number of shots and wagers do not depend on
+/// handicap or hole, so this is also the shot-weighted hold of a venue run,
+/// without the simulation noise.
+///
+/// # Arguments
+/// * `archetype` - Player population
+/// * `hole_mix` - (hole_id, weight) pairs; weights are normalized
+pub fn expected_hold(archetype: &PlayerArchetype, hole_mix: &[(u8, f64)]) -> f64 {
+    let expected_rtp: f64 = handicap_distribution(archetype)
+        .into_iter()
+        .filter(|(_, probability)| *probability > 0.0)
+        .map(|(handicap, probability)| {
+            let player = Player::new(format!("player_{}", handicap), handicap);
+            probability * expected_loss_per_hour(&player, hole_mix, 1.0, 1).blended_rtp
+        })
+        .sum();
+
+    1.0 - expected_rtp
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::simulators::venue::PlayerArchetype;
+
+    #[test]
+    fn test_marginal_bay_profit() {
+        let config = VenueConfig {
+            num_bays: 1,
+            hours: 2.0,
+            shots_per_hour: 100,
+            player_archetype: PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 },
+            wager_range: (5.0, 20.0),
+            ..Default::default()
+        };
+
+        let small = marginal_bay_profit(&config, 10);
+        let large = marginal_bay_profit(&config, 160);
+
+        assert_eq!(large.base_bays, 1);
+        assert!(large.marginal_profit > 0.0,
+            "Marginal profit {} should be positive", large.marginal_profit);
+        assert!(large.ci_95.0 < large.marginal_profit && large.marginal_profit < large.ci_95.1);
+
+        let small_width = small.ci_95.1 - small.ci_95.0;
+        let large_width = large.ci_95.1 - large.ci_95.0;
+        assert!(large_width < small_width,
+            "CI should shrink with trials: {} vs {}", large_width, small_width);
+    }
+
+    #[test]
+    fn test_run_cost_scales_with_shots() {
+        let config = VenueConfig {
+            num_bays: 1000,
+            hours: 1.0,
+            shots_per_hour: 100,
+            ..Default::default()
+        };
+        let long_run = VenueConfig { hours: 10.0, ..config.clone() };
+
+        let base = project_run_cost(&config, 1e-6);
+        let scaled = project_run_cost(&long_run, 1e-6);
+
+        assert_eq!(scaled.total_shots, 10 * base.total_shots);
+        let memory_ratio = scaled.peak_memory_bytes / base.peak_memory_bytes;
+        let time_ratio = scaled.estimated_runtime_secs / base.estimated_runtime_secs;
+        assert!((memory_ratio - 10.0).abs() < 0.5, "Memory ratio {}", memory_ratio);
+        assert!((time_ratio - 10.0).abs() < 0.5, "Time ratio {}", time_ratio);
+
+        // Streaming only holds the sessions in flight
+        let streamed = project_run_cost(&VenueConfig { streaming: true, ..long_run.clone() }, 1e-6);
+        assert!(streamed.peak_memory_bytes * 5.0 < scaled.peak_memory_bytes,
+            "Streaming {} vs retained {}", streamed.peak_memory_bytes, scaled.peak_memory_bytes);
+        assert_eq!(streamed.estimated_runtime_secs, scaled.estimated_runtime_secs);
+
+        // The calibrated estimate is positive and finite
+        let estimate = estimate_run_cost(&config);
+        assert!(estimate.seconds_per_shot > 0.0 && estimate.estimated_runtime_secs.is_finite());
+        assert!(estimate.peak_memory_bytes > 0.0);
+    }
+
+    #[test]
+    fn test_expected_hold_matches_venue_simulation() {
+        let archetype = PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 };
+        let all_holes: Vec<(u8, f64)> = (1..=8).map(|id| (id, 1.0)).collect();
+        let analytic = expected_hold(&archetype, &all_holes);
+
+        // Independent seeded venues give a mean realized hold and its standard error
+        let holds: Vec<f64> = (0..12)
+            .map(|i| {
+                run_venue_simulation(VenueConfig {
+                    num_bays: 40,
+                    hours: 3.0,
+                    shots_per_hour: 100,
+                    player_archetype: archetype.clone(),
+                    seed: Some(500 + i),
+                    streaming: true,
+                    ..Default::default()
+                })
+                .hold_percentage
+            })
+            .collect();
+        let n = holds.len() as f64;
+        let mean = holds.iter().sum::<f64>() / n;
+        let std_error = (holds.iter().map(|h| (h - mean).powi(2)).sum::<f64>() / (n - 1.0) / n).sqrt();
+
+        assert!(
+            (analytic - mean).abs() < 3.0 * std_error,
+            "Analytic hold {:.4} vs simulated {:.4} ± {:.4}",
+            analytic,
+            mean,
+            std_error
+        );
+    }
+}
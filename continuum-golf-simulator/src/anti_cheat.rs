@@ -1,12 +1,17 @@
-/// Anti-Cheat Detection Module
-///
-/// Provides detection mechanisms for various cheating strategies including:
-/// - Sandbagging (intentional poor performance to inflate P_max)
-/// - Cherry-picking (only high wagers on good shots)
-/// - Sudden skill jumps (potential account sharing)
-/// - Pattern-based exploitation
-
-use crate::models::shot::ShotOutcome;
+//! Anti-Cheat Detection Module
+//!
+//! Provides detection mechanisms for various cheating strategies including:
+//! - Sandbagging (intentional poor performance to inflate P_max)
+//! - Cherry-picking (only high wagers on good shots)
+//! - Sudden skill jumps (potential account sharing)
+//! - Machine-regular shot timing (automated play)
+//! - Loss-chasing and erratic wager sizing
+//!
+//! [`scan_session`] runs every shot-based detector over a session at once.
+//! - Pattern-based exploitation
+
+use crate::models::shot::{ShotOutcome, TimedShot};
+use crate::simulators::player_session::SessionResult;
 use serde::{Deserialize, Serialize};
 
 /// Anomaly detection result
@@ -18,6 +23,63 @@ pub struct AnomalyReport {
     pub recommended_action: String,
 }
 
+/// Detection sensitivity settings
+///
+/// Minimum sample sizes gate whether a detector runs at all; thresholds are
+/// the confidence above which a report is marked suspicious.
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct AntiCheatConfig {
+    pub sandbagging_min_shots: usize,
+    pub sandbagging_threshold: f64,
+    pub cherry_picking_min_shots: usize,
+    pub cherry_picking_threshold: f64,
+    pub skill_jump_min_historical_shots: usize,
+    pub skill_jump_min_recent_shots: usize,
+    pub skill_jump_threshold: f64,
+    #[serde(default = "default_bot_timing_min_shots")]
+    pub bot_timing_min_shots: usize,
+    #[serde(default = "default_bot_timing_threshold")]
+    pub bot_timing_threshold: f64,
+    #[serde(default = "default_betting_pattern_min_shots")]
+    pub betting_pattern_min_shots: usize,
+    #[serde(default = "default_betting_pattern_threshold")]
+    pub betting_pattern_threshold: f64,
+}
+
+fn default_bot_timing_min_shots() -> usize {
+    AntiCheatConfig::default().bot_timing_min_shots
+}
+
+fn default_bot_timing_threshold() -> f64 {
+    AntiCheatConfig::default().bot_timing_threshold
+}
+
+fn default_betting_pattern_min_shots() -> usize {
+    AntiCheatConfig::default().betting_pattern_min_shots
+}
+
+fn default_betting_pattern_threshold() -> f64 {
+    AntiCheatConfig::default().betting_pattern_threshold
+}
+
+impl Default for AntiCheatConfig {
+    fn default() -> Self {
+        Self {
+            sandbagging_min_shots: 20,
+            sandbagging_threshold: 0.6,
+            cherry_picking_min_shots: 10,
+            cherry_picking_threshold: 0.6,
+            skill_jump_min_historical_shots: 20,
+            skill_jump_min_recent_shots: 10,
+            skill_jump_threshold: 0.7,
+            bot_timing_min_shots: 10,
+            bot_timing_threshold: 0.6,
+            betting_pattern_min_shots: 20,
+            betting_pattern_threshold: 0.6,
+        }
+    }
+}
+
 /// Detect sandbagging pattern
 ///
 /// Indicators:
@@ -25,7 +87,15 @@ pub struct AnomalyReport {
 /// - Low wagers on bad shots
 /// - Sudden high wagers after establishing poor baseline
 pub fn detect_sandbagging(shots: &[ShotOutcome]) -> AnomalyReport {
-    if shots.len() < 20 {
+    detect_sandbagging_with_config(shots, &AntiCheatConfig::default())
+}
+
+/// Detect sandbagging pattern using custom sensitivity settings
+pub fn detect_sandbagging_with_config(
+    shots: &[ShotOutcome],
+    config: &AntiCheatConfig,
+) -> AnomalyReport {
+    if shots.len() < config.sandbagging_min_shots {
         return AnomalyReport {
             is_suspicious: false,
             confidence: 0.0,
@@ -67,7 +137,7 @@ pub fn detect_sandbagging(shots: &[ShotOutcome]) -> AnomalyReport {
         }
     }
 
-    let is_suspicious = confidence > 0.6;
+    let is_suspicious = confidence > config.sandbagging_threshold;
     let recommended_action = if is_suspicious {
         "Flag for manual review - potential sandbagging".to_string()
     } else {
@@ -88,7 +158,15 @@ pub fn detect_sandbagging(shots: &[ShotOutcome]) -> AnomalyReport {
 /// - Low wagers correlated with poor shots
 /// - High wagers correlated with good shots
 pub fn detect_cherry_picking(shots: &[ShotOutcome]) -> AnomalyReport {
-    if shots.len() < 10 {
+    detect_cherry_picking_with_config(shots, &AntiCheatConfig::default())
+}
+
+/// Detect cherry-picking using custom sensitivity settings
+pub fn detect_cherry_picking_with_config(
+    shots: &[ShotOutcome],
+    config: &AntiCheatConfig,
+) -> AnomalyReport {
+    if shots.len() < config.cherry_picking_min_shots {
         return AnomalyReport {
             is_suspicious: false,
             confidence: 0.0,
@@ -129,7 +207,7 @@ pub fn detect_cherry_picking(shots: &[ShotOutcome]) -> AnomalyReport {
         }
     }
 
-    let is_suspicious = confidence > 0.6;
+    let is_suspicious = confidence > config.cherry_picking_threshold;
     let recommended_action = if is_suspicious {
         "Limit max wager variance per session".to_string()
     } else {
@@ -151,7 +229,18 @@ pub fn detect_skill_jump(
     historical_shots: &[ShotOutcome],
     recent_shots: &[ShotOutcome],
 ) -> AnomalyReport {
-    if historical_shots.len() < 20 || recent_shots.len() < 10 {
+    detect_skill_jump_with_config(historical_shots, recent_shots, &AntiCheatConfig::default())
+}
+
+/// Detect sudden skill jumps using custom sensitivity settings
+pub fn detect_skill_jump_with_config(
+    historical_shots: &[ShotOutcome],
+    recent_shots: &[ShotOutcome],
+    config: &AntiCheatConfig,
+) -> AnomalyReport {
+    if historical_shots.len() < config.skill_jump_min_historical_shots
+        || recent_shots.len() < config.skill_jump_min_recent_shots
+    {
         return AnomalyReport {
             is_suspicious: false,
             confidence: 0.0,
@@ -188,7 +277,7 @@ pub fn detect_skill_jump(
         confidence += 0.4;
     }
 
-    let is_suspicious = confidence > 0.7;
+    let is_suspicious = confidence > config.skill_jump_threshold;
     let recommended_action = if is_suspicious {
         "URGENT: Flag for immediate review - possible account sharing".to_string()
     } else if confidence > 0.5 {
@@ -205,6 +294,168 @@ pub fn detect_skill_jump(
     }
 }
 
+/// Detect automated play from inter-shot timing
+///
+/// Human shot intervals vary a lot; a bot's are nearly constant. Shots are
+/// ordered by timestamp, so the input need not be sorted. The coefficient of
+/// variation (σ/μ) of the intervals between consecutive shots is always
+/// reported:
+/// - CV below 0.1: machine-regular timing
+/// - CV below 0.25: unusually regular timing
+pub fn detect_bot_timing(shots: &[TimedShot]) -> AnomalyReport {
+    detect_bot_timing_with_config(shots, &AntiCheatConfig::default())
+}
+
+/// Detect automated play from inter-shot timing using custom sensitivity settings
+pub fn detect_bot_timing_with_config(shots: &[TimedShot], config: &AntiCheatConfig) -> AnomalyReport {
+    if shots.len() < config.bot_timing_min_shots.max(3) {
+        return AnomalyReport {
+            is_suspicious: false,
+            confidence: 0.0,
+            detected_patterns: vec![],
+            recommended_action: "Insufficient data".to_string(),
+        };
+    }
+
+    // Shots may arrive out of order; intervals are between consecutive times
+    let mut timestamps: Vec<u64> = shots.iter().map(TimedShot::timestamp_ms).collect();
+    timestamps.sort_unstable();
+    let intervals: Vec<f64> = timestamps.windows(2).map(|pair| (pair[1] - pair[0]) as f64).collect();
+    let mean_interval = intervals.iter().sum::<f64>() / intervals.len() as f64;
+    let variance = intervals.iter().map(|i| (i - mean_interval).powi(2)).sum::<f64>() / intervals.len() as f64;
+    // Shots all at the same instant are as regular as it gets
+    let cv = if mean_interval > 0.0 { variance.sqrt() / mean_interval } else { 0.0 };
+
+    let mut patterns = vec![format!("Inter-shot interval CV={:.3} (mean {:.0} ms)", cv, mean_interval)];
+    let mut confidence = 0.0;
+
+    if cv < 0.1 {
+        patterns.push("Machine-regular shot timing".to_string());
+        confidence += 0.8;
+    } else if cv < 0.25 {
+        patterns.push("Unusually regular shot timing".to_string());
+        confidence += 0.4;
+    }
+
+    let is_suspicious = confidence > config.bot_timing_threshold;
+    let recommended_action = if is_suspicious {
+        "Flag for manual review - possible automated play".to_string()
+    } else {
+        "Normal shot timing".to_string()
+    };
+
+    AnomalyReport {
+        is_suspicious,
+        confidence,
+        detected_patterns: patterns,
+        recommended_action,
+    }
+}
+
+/// Detect suspicious wager sizing
+///
+/// Indicators:
+/// - Loss chasing: wager raised by half or more after most losing shots
+/// - Erratic sizing: largest wager over 20× the smallest
+pub fn detect_betting_pattern(shots: &[ShotOutcome]) -> AnomalyReport {
+    detect_betting_pattern_with_config(shots, &AntiCheatConfig::default())
+}
+
+/// Detect suspicious wager sizing using custom sensitivity settings
+pub fn detect_betting_pattern_with_config(shots: &[ShotOutcome], config: &AntiCheatConfig) -> AnomalyReport {
+    if shots.len() < config.betting_pattern_min_shots.max(2) {
+        return AnomalyReport {
+            is_suspicious: false,
+            confidence: 0.0,
+            detected_patterns: vec![],
+            recommended_action: "Insufficient data".to_string(),
+        };
+    }
+
+    let mut patterns = Vec::new();
+    let mut confidence = 0.0;
+
+    // Share of losing shots followed by a sharply raised wager
+    let after_loss: Vec<&[ShotOutcome]> = shots.windows(2).filter(|pair| pair[0].net_result() < 0.0).collect();
+    if after_loss.len() >= 5 {
+        let raised = after_loss.iter().filter(|pair| pair[1].wager >= pair[0].wager * 1.5).count();
+        let chase_rate = raised as f64 / after_loss.len() as f64;
+        if chase_rate > 0.6 {
+            patterns.push(format!("Loss chasing: wager raised after {:.0}% of losses", chase_rate * 100.0));
+            confidence += 0.5;
+        }
+    }
+
+    let min_wager = shots.iter().map(|s| s.wager).fold(f64::INFINITY, f64::min);
+    let max_wager = shots.iter().map(|s| s.wager).fold(f64::NEG_INFINITY, f64::max);
+    if min_wager > 0.0 && max_wager > min_wager * 20.0 {
+        patterns.push(format!("Erratic wager sizing: ${:.2} to ${:.2}", min_wager, max_wager));
+        confidence += 0.3;
+    }
+
+    let is_suspicious = confidence > config.betting_pattern_threshold;
+    let recommended_action = if is_suspicious {
+        "Review for problem gambling or martingale exploitation".to_string()
+    } else {
+        "Normal betting pattern".to_string()
+    };
+
+    AnomalyReport {
+        is_suspicious,
+        confidence,
+        detected_patterns: patterns,
+        recommended_action,
+    }
+}
+
+/// Run every shot-based detector over a session
+///
+/// Runs sandbagging, cherry-picking and betting-pattern detection on
+/// `result.shots` and returns the reports that found something, suspicious
+/// ones first, then by descending confidence. A clean session returns an
+/// empty list. See [`session_risk_score`] for an overall score.
+pub fn scan_session(result: &SessionResult) -> Vec<AnomalyReport> {
+    scan_session_with_config(result, &AntiCheatConfig::default())
+}
+
+/// Run every shot-based detector over a session using custom sensitivity settings
+pub fn scan_session_with_config(result: &SessionResult, config: &AntiCheatConfig) -> Vec<AnomalyReport> {
+    let mut reports: Vec<AnomalyReport> = [
+        detect_sandbagging_with_config(&result.shots, config),
+        detect_cherry_picking_with_config(&result.shots, config),
+        detect_betting_pattern_with_config(&result.shots, config),
+    ]
+    .into_iter()
+    .filter(|report| report.confidence > 0.0)
+    .collect();
+    reports.sort_by(|a, b| b.is_suspicious.cmp(&a.is_suspicious).then(b.confidence.total_cmp(&a.confidence)));
+    reports
+}
+
+/// Overall risk in 0.0-1.0 from a set of reports
+///
+/// Treats each report's confidence as an independent chance of foul play:
+/// 1 - Π(1 - confidence). No reports means no risk.
+pub fn session_risk_score(reports: &[AnomalyReport]) -> f64 {
+    1.0 - reports.iter().map(|report| 1.0 - report.confidence.clamp(0.0, 1.0)).product::<f64>()
+}
+
+/// Merge a session scan into a single report
+///
+/// Suspicious if any detector was; confidence is the [`session_risk_score`],
+/// patterns are listed in priority order and the action is the top report's.
+pub fn scan_session_summary(result: &SessionResult) -> AnomalyReport {
+    let reports = scan_session(result);
+    AnomalyReport {
+        is_suspicious: reports.iter().any(|report| report.is_suspicious),
+        confidence: session_risk_score(&reports),
+        detected_patterns: reports.iter().flat_map(|report| report.detected_patterns.clone()).collect(),
+        recommended_action: reports
+            .first()
+            .map_or_else(|| "Continue monitoring".to_string(), |report| report.recommended_action.clone()),
+    }
+}
+
 /// Calculate correlation between wager size and shot quality (inverse of miss distance)
 fn calculate_wager_quality_correlation(shots: &[ShotOutcome]) -> f64 {
     if shots.len() < 2 {
@@ -262,6 +513,7 @@ mod tests {
                 wager: 10.0,
                 hole_id: 4,
                 is_fat_tail: false,
+                timestamp_ms: None,
             })
             .collect();
 
@@ -282,6 +534,7 @@ mod tests {
                 wager: 1.0,
                 hole_id: 4,
                 is_fat_tail: false,
+                timestamp_ms: None,
             });
         }
 
@@ -294,6 +547,7 @@ mod tests {
                 wager: 100.0,
                 hole_id: 4,
                 is_fat_tail: false,
+                timestamp_ms: None,
             });
         }
 
@@ -301,4 +555,107 @@ mod tests {
         assert!(report.is_suspicious, "Obvious sandbagging should be detected");
         assert!(report.confidence > 0.6);
     }
+
+    #[test]
+    fn test_config_threshold_is_respected() {
+        // Wager perfectly correlated with multiplier, but without the 1.5x
+        // bimodal gap: confidence lands at 0.5, just under the default 0.6
+        let shots: Vec<ShotOutcome> = (0..20)
+            .map(|i| {
+                let (wager, multiplier) = if i % 2 == 0 { (10.0, 1.2) } else { (5.0, 1.0) };
+                ShotOutcome {
+                    miss_distance_ft: 10.0,
+                    multiplier,
+                    payout: wager * multiplier,
+                    wager,
+                    hole_id: 4,
+                    is_fat_tail: false,
+                    timestamp_ms: None,
+                }
+            })
+            .collect();
+
+        let default_report = detect_cherry_picking(&shots);
+        assert!((default_report.confidence - 0.5).abs() < 1e-9);
+        assert!(!default_report.is_suspicious);
+
+        let strict = AntiCheatConfig {
+            cherry_picking_threshold: 0.4,
+            ..Default::default()
+        };
+        let strict_report = detect_cherry_picking_with_config(&shots, &strict);
+        assert!(strict_report.is_suspicious, "Lowered threshold should flag borderline pattern");
+
+        let large_sample = AntiCheatConfig {
+            cherry_picking_min_shots: 50,
+            ..strict
+        };
+        let skipped = detect_cherry_picking_with_config(&shots, &large_sample);
+        assert!(!skipped.is_suspicious);
+        assert_eq!(skipped.recommended_action, "Insufficient data");
+    }
+
+    #[test]
+    fn test_detect_bot_timing_uniform_intervals() {
+        let timed = |timestamps: &[u64]| -> Vec<TimedShot> {
+            timestamps
+                .iter()
+                .map(|&t| TimedShot::new(ShotOutcome::new(20.0, 1.0, 10.0, 4, false), t))
+                .collect()
+        };
+
+        let bot: Vec<u64> = (0..30).map(|i| 5_000 + i * 1000).collect();
+        let report = detect_bot_timing(&timed(&bot));
+        assert!(report.is_suspicious, "Uniform 1000ms intervals should be flagged");
+        assert!(report.detected_patterns[0].contains("CV=0.000"), "{:?}", report.detected_patterns);
+
+        // Irregular, human-paced intervals of 8-45 seconds
+        let mut human = vec![0u64];
+        for i in 0..29u64 {
+            let interval = 8_000 + (i * 7919 + 104_729) % 37_000;
+            human.push(human.last().unwrap() + interval);
+        }
+        let report = detect_bot_timing(&timed(&human));
+        assert!(!report.is_suspicious, "{:?}", report.detected_patterns);
+        assert!(report.detected_patterns[0].starts_with("Inter-shot interval CV="));
+
+        assert_eq!(detect_bot_timing(&timed(&bot[..5])).recommended_action, "Insufficient data");
+
+        // Shots logged out of order are timed in order, not by |Δt| between neighbours
+        let mut shuffled = bot.clone();
+        shuffled.swap(3, 17);
+        shuffled.swap(8, 25);
+        let report = detect_bot_timing(&timed(&shuffled));
+        assert!(report.detected_patterns[0].contains("CV=0.000"), "{:?}", report.detected_patterns);
+    }
+
+    #[test]
+    fn test_scan_session_clean_and_chasing() {
+        use crate::simulators::player_session::{run_session, SessionConfig};
+        use crate::models::player::Player;
+
+        let mut player = Player::new("clean".to_string(), 15);
+        let clean = run_session(&mut player, SessionConfig { num_shots: 200, seed: Some(279), ..Default::default() });
+        let reports = scan_session(&clean);
+        assert!(reports.iter().all(|report| !report.is_suspicious), "{:?}", reports);
+        assert!(session_risk_score(&reports) < 0.6);
+        assert!(!scan_session_summary(&clean).is_suspicious);
+
+        // Doubling after every loss, from $1 up to $64, then back to $1
+        let mut chasing = SessionResult::default();
+        let mut wager = 1.0;
+        for i in 0..40 {
+            let multiplier = if i % 7 == 6 { 2.0 } else { 0.5 };
+            chasing.shots.push(ShotOutcome::new(30.0, multiplier, wager, 4, false));
+            wager = if multiplier < 1.0 { wager * 2.0 } else { 1.0 };
+        }
+        let reports = scan_session(&chasing);
+        assert!(reports[0].is_suspicious);
+        assert!(reports[0].detected_patterns[0].starts_with("Loss chasing"));
+
+        let summary = scan_session_summary(&chasing);
+        assert!(summary.is_suspicious);
+        assert_eq!(summary.confidence, session_risk_score(&reports));
+        assert_eq!(summary.detected_patterns.len(), reports.iter().map(|r| r.detected_patterns.len()).sum::<usize>());
+    }
 }
@@ -14,6 +14,7 @@ pub mod simulators;
 pub mod analytics;
 pub mod anti_cheat;
 pub mod config;
+pub mod test_support;
 
 // Re-export commonly used types
 pub use math::{distributions, integration, kalman};
@@ -1,12 +1,17 @@
 // Statistical distributions for shot simulation
 //
 // Implements:
-// - Normal distribution (Box-Muller transform)
+// - Normal distribution (Box-Muller transform, plus a portable inverse-CDF sampler)
 // - Rayleigh distribution (miss distance modeling)
+// - Weibull and Gamma alternatives for heavier or lighter tails
 // - Fat-tail shot logic (2% chance of 3× worse dispersion)
 
+use rand::distributions::Open01;
 use rand::Rng;
-use std::f64::consts::PI;
+use rand_distr::{Distribution, Gamma, Normal};
+use statrs::function::gamma::{gamma_lr, ln_gamma};
+use serde::{Deserialize, Serialize};
+use std::f64::consts::{LN_2, PI, SQRT_2};
 
 /// Generate a random sample from a normal distribution using Box-Muller transform
 ///
@@ -34,6 +39,151 @@ pub fn normal_random(mean: f64, std_dev: f64) -> f64 {
     mean + std_dev * z0
 }
 
+/// Algorithm used to draw normal samples where seeds must be reproducible
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, Serialize, Deserialize)]
+pub enum NormalSampler {
+    /// `rand_distr`'s ziggurat sampler. Fast, but it calls the platform's
+    /// `exp`/`ln`, so a seed can give slightly different samples on other
+    /// architectures.
+    #[default]
+    Ziggurat,
+    /// Inverse-CDF sampler built only from IEEE-754 arithmetic and `sqrt`,
+    /// so a seed gives bit-identical samples on every platform
+    InverseCdf,
+}
+
+/// Generate a normal sample from `rng` with the chosen sampler
+///
+/// # Arguments
+/// * `mean` - The mean (μ) of the distribution
+/// * `std_dev` - The standard deviation (σ) of the distribution
+/// * `sampler` - Sampling algorithm
+/// * `rng` - Random number generator
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::math::distributions::{normal_random_with_rng, NormalSampler};
+/// use rand::{rngs::StdRng, SeedableRng};
+///
+/// let mut rng = StdRng::seed_from_u64(7);
+/// let sample = normal_random_with_rng(15.0, 5.0, NormalSampler::InverseCdf, &mut rng);
+/// assert!(sample.is_finite());
+/// ```
+pub fn normal_random_with_rng<R: Rng + ?Sized>(
+    mean: f64,
+    std_dev: f64,
+    sampler: NormalSampler,
+    rng: &mut R,
+) -> f64 {
+    match sampler {
+        NormalSampler::Ziggurat => Normal::new(mean, std_dev).unwrap().sample(rng),
+        NormalSampler::InverseCdf => {
+            let u: f64 = rng.sample(Open01);
+            mean + std_dev * inverse_normal_cdf(u)
+        }
+    }
+}
+
+/// Inverse of the standard normal CDF (quantile function)
+///
+/// Uses Acklam's rational approximation (relative error below 1.2e-9). The
+/// tails need a logarithm, which comes from [`portable_ln`] rather than the
+/// platform math library, so the result is the same on every architecture.
+///
+/// # Arguments
+/// * `p` - Probability in (0, 1)
+///
+/// # Returns
+/// z such that Φ(z) = p; ±infinity at 0 and 1, NaN outside [0, 1]
+pub fn inverse_normal_cdf(p: f64) -> f64 {
+    const A: [f64; 6] = [
+        -3.969683028665376e+01, 2.209460984245205e+02, -2.759285104469687e+02,
+        1.38357751867269e+02, -3.066479806614716e+01, 2.506628277459239e+00,
+    ];
+    const B: [f64; 5] = [
+        -5.447609879822406e+01, 1.615858368580409e+02, -1.556989798598866e+02,
+        6.680131188771972e+01, -1.328068155288572e+01,
+    ];
+    const C: [f64; 6] = [
+        -7.784894002430293e-03, -3.223964580411365e-01, -2.400758277161838e+00,
+        -2.549732539343734e+00, 4.374664141464968e+00, 2.938163982698783e+00,
+    ];
+    const D: [f64; 4] = [
+        7.784695709041462e-03, 3.224671290700398e-01, 2.445134137142996e+00,
+        3.754408661907416e+00,
+    ];
+    const P_LOW: f64 = 0.02425;
+
+    if p.is_nan() || !(0.0..=1.0).contains(&p) {
+        return f64::NAN;
+    }
+    if p == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if p == 1.0 {
+        return f64::INFINITY;
+    }
+
+    let tail = |q: f64| {
+        (((((C[0] * q + C[1]) * q + C[2]) * q + C[3]) * q + C[4]) * q + C[5])
+            / ((((D[0] * q + D[1]) * q + D[2]) * q + D[3]) * q + 1.0)
+    };
+
+    if p < P_LOW {
+        tail((-2.0 * portable_ln(p)).sqrt())
+    } else if p > 1.0 - P_LOW {
+        -tail((-2.0 * portable_ln(1.0 - p)).sqrt())
+    } else {
+        let q = p - 0.5;
+        let r = q * q;
+        (((((A[0] * r + A[1]) * r + A[2]) * r + A[3]) * r + A[4]) * r + A[5]) * q
+            / (((((B[0] * r + B[1]) * r + B[2]) * r + B[3]) * r + B[4]) * r + 1.0)
+    }
+}
+
+/// Natural logarithm using only IEEE-754 arithmetic
+///
+/// Splits x into m × 2^e with m in [√½, √2) and sums the atanh series for
+/// ln(m). Accurate to a few ulps and, unlike `f64::ln`, identical on every
+/// platform.
+///
+/// # Arguments
+/// * `x` - Positive, finite input
+pub fn portable_ln(x: f64) -> f64 {
+    if x.is_nan() || x < 0.0 {
+        return f64::NAN;
+    }
+    if x == 0.0 {
+        return f64::NEG_INFINITY;
+    }
+    if x.is_infinite() {
+        return f64::INFINITY;
+    }
+
+    // Scale subnormals into the normal range so the exponent bits are usable
+    let (x, scale) = if x < f64::MIN_POSITIVE { (x * 2f64.powi(54), -54) } else { (x, 0) };
+
+    let bits = x.to_bits();
+    let mut exponent = ((bits >> 52) & 0x7ff) as i32 - 1023 + scale;
+    let mut m = f64::from_bits((bits & 0x000f_ffff_ffff_ffff) | 0x3ff0_0000_0000_0000);
+    if m > SQRT_2 {
+        m /= 2.0;
+        exponent += 1;
+    }
+
+    // ln(m) = 2 atanh(s) = 2 (s + s³/3 + s⁵/5 + ...), with |s| < 0.172
+    let s = (m - 1.0) / (m + 1.0);
+    let s2 = s * s;
+    let mut term = s;
+    let mut sum = 0.0;
+    for k in 0..12 {
+        sum += term / (2 * k + 1) as f64;
+        term *= s2;
+    }
+
+    2.0 * sum + exponent as f64 * LN_2
+}
+
 /// Generate a random sample from a Rayleigh distribution
 ///
 /// The Rayleigh distribution models the miss distance for golf shots.
@@ -55,7 +205,14 @@ pub fn normal_random(mean: f64, std_dev: f64) -> f64 {
 /// let miss_distance = rayleigh_random(30.0);  // σ = 30 feet
 /// ```
 pub fn rayleigh_random(sigma: f64) -> f64 {
-    let mut rng = rand::thread_rng();
+    rayleigh_random_with_rng(sigma, &mut rand::thread_rng())
+}
+
+/// Generate a Rayleigh sample from the supplied random number generator
+///
+/// Same as [`rayleigh_random`], but draws from `rng` so seeded simulations
+/// are reproducible.
+pub fn rayleigh_random_with_rng<R: Rng + ?Sized>(sigma: f64, rng: &mut R) -> f64 {
     let u: f64 = rng.gen();
 
     // Inverse transform sampling for Rayleigh distribution
@@ -84,20 +241,83 @@ pub fn rayleigh_random(sigma: f64) -> f64 {
 /// }
 /// ```
 pub fn fat_tail_shot(sigma: f64, fat_tail_prob: f64, fat_tail_mult: f64) -> (f64, bool) {
-    let mut rng = rand::thread_rng();
-    let roll: f64 = rng.gen();
+    fat_tail_shot_with_rng(sigma, fat_tail_prob, fat_tail_mult, &mut rand::thread_rng())
+}
 
-    if roll < fat_tail_prob {
-        // Fat-tail event: use increased sigma
-        let miss_distance = rayleigh_random(sigma * fat_tail_mult);
-        (miss_distance, true)
-    } else {
-        // Normal shot
-        let miss_distance = rayleigh_random(sigma);
-        (miss_distance, false)
+/// Simulate a fat-tail shot using the supplied random number generator
+///
+/// Same as [`fat_tail_shot`], but draws from `rng` so seeded simulations
+/// are reproducible.
+pub fn fat_tail_shot_with_rng<R: Rng + ?Sized>(
+    sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+    rng: &mut R,
+) -> (f64, bool) {
+    fat_tail_shot_capped_with_rng(sigma, fat_tail_prob, fat_tail_mult, None, rng)
+}
+
+/// Simulate a fat-tail shot with an optional physical bound on the miss
+///
+/// Same as [`fat_tail_shot_with_rng`], but a miss beyond `max_miss_distance`
+/// is clamped to it. Real shots cannot land hundreds of feet off line, while
+/// a 3σ draw for a high-σ beginner can. The bound should sit beyond every
+/// hole's `d_max` so clamped misses still pay zero and RTP is unaffected.
+///
+/// # Arguments
+/// * `sigma` - Base skill parameter (miss distance standard deviation)
+/// * `fat_tail_prob` - Probability of fat-tail event
+/// * `fat_tail_mult` - Multiplier for fat-tail dispersion
+/// * `max_miss_distance` - Largest physically possible miss in feet (`None` = unbounded)
+/// * `rng` - Random number generator
+pub fn fat_tail_shot_capped_with_rng<R: Rng + ?Sized>(
+    sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+    max_miss_distance: Option<f64>,
+    rng: &mut R,
+) -> (f64, bool) {
+    let (miss_distance, is_fat_tail) = draw_fat_tail_shot(sigma, fat_tail_prob, fat_tail_mult, rng);
+    match max_miss_distance {
+        Some(max) => (miss_distance.min(max), is_fat_tail),
+        None => (miss_distance, is_fat_tail),
     }
 }
 
+fn draw_fat_tail_shot<R: Rng + ?Sized>(
+    sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+    rng: &mut R,
+) -> (f64, bool) {
+    let roll: f64 = rng.gen();
+    let u: f64 = rng.gen();
+    fat_tail_shot_from_uniforms(sigma, fat_tail_prob, fat_tail_mult, roll, u)
+}
+
+/// Fat-tail shot from explicit uniform draws
+///
+/// `roll` decides whether the shot is a fat-tail event (`roll <
+/// fat_tail_prob`) and `u` is the Rayleigh inverse-transform variate, as in
+/// [`fat_tail_shot_with_rng`], which draws them in that order. Passing
+/// (1 - roll, 1 - u) gives the antithetic partner of a shot.
+///
+/// # Returns
+/// Tuple of (miss_distance, is_fat_tail)
+pub fn fat_tail_shot_from_uniforms(
+    sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+    roll: f64,
+    u: f64,
+) -> (f64, bool) {
+    let is_fat_tail = roll < fat_tail_prob;
+    let sigma = if is_fat_tail { sigma * fat_tail_mult } else { sigma };
+
+    // Inverse transform sampling for Rayleigh distribution
+    (sigma * (-2.0 * u.ln()).sqrt(), is_fat_tail)
+}
+
 /// Calculate the Rayleigh PDF at a given point
 ///
 /// Used for numerical integration when calculating P_max.
@@ -120,6 +340,25 @@ pub fn rayleigh_pdf(d: f64, sigma: f64) -> f64 {
     (d / sigma_sq) * (-(d * d) / (2.0 * sigma_sq)).exp()
 }
 
+/// Calculate the Rayleigh CDF at a given point
+///
+/// # Arguments
+/// * `d` - Miss distance
+/// * `sigma` - Scale parameter
+///
+/// # Returns
+/// Probability that a miss distance is at most d
+///
+/// # Formula
+/// F(d | σ) = 1 - exp(-d² / 2σ²)
+pub fn rayleigh_cdf(d: f64, sigma: f64) -> f64 {
+    if d <= 0.0 || sigma <= 0.0 {
+        return 0.0;
+    }
+
+    1.0 - (-(d * d) / (2.0 * sigma * sigma)).exp()
+}
+
 /// Calculate the expected value (mean) of a Rayleigh distribution
 ///
 /// # Arguments
@@ -148,6 +387,171 @@ pub fn rayleigh_variance(sigma: f64) -> f64 {
     sigma * sigma * (4.0 - PI) / 2.0
 }
 
+/// Quantile (inverse CDF) of a Rayleigh distribution
+///
+/// # Arguments
+/// * `p` - Probability in [0, 1)
+/// * `sigma` - Scale parameter
+///
+/// # Returns
+/// Miss distance within which a fraction `p` of shots land
+///
+/// # Formula
+/// Q(p | σ) = σ * sqrt(-2 ln(1 - p))
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::math::distributions::{rayleigh_cdf, rayleigh_quantile};
+///
+/// let d90 = rayleigh_quantile(0.9, 20.0);
+/// assert!((rayleigh_cdf(d90, 20.0) - 0.9).abs() < 1e-12);
+/// ```
+pub fn rayleigh_quantile(p: f64, sigma: f64) -> f64 {
+    if p <= 0.0 || sigma <= 0.0 {
+        return 0.0;
+    }
+    sigma * (-2.0 * (1.0 - p).ln()).sqrt()
+}
+
+/// Generate a random sample from a Weibull distribution
+///
+/// # Formula
+/// d = scale * (-ln U)^(1/shape) where U ~ Uniform(0, 1)
+///
+/// Rayleigh(σ) is Weibull(σ√2, 2), and for the same `U` both give the same
+/// draw.
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::math::distributions::weibull_random;
+/// let miss_distance = weibull_random(40.0, 1.5);
+/// assert!(miss_distance >= 0.0);
+/// ```
+pub fn weibull_random(scale: f64, shape: f64) -> f64 {
+    weibull_random_with_rng(scale, shape, &mut rand::thread_rng())
+}
+
+/// Same as [`weibull_random`], drawing from `rng`
+pub fn weibull_random_with_rng<R: Rng + ?Sized>(scale: f64, shape: f64, rng: &mut R) -> f64 {
+    let u: f64 = rng.gen();
+    scale * (-u.ln()).powf(1.0 / shape)
+}
+
+/// Calculate the Weibull PDF at a given point
+///
+/// # Formula
+/// f(d | λ, k) = (k/λ) * (d/λ)^(k-1) * exp(-(d/λ)^k)
+pub fn weibull_pdf(d: f64, scale: f64, shape: f64) -> f64 {
+    if d < 0.0 || scale <= 0.0 || shape <= 0.0 {
+        return 0.0;
+    }
+
+    let x = d / scale;
+    (shape / scale) * x.powf(shape - 1.0) * (-x.powf(shape)).exp()
+}
+
+/// Generate a random sample from a Gamma distribution
+///
+/// # Panics
+/// If `shape` or `scale` is not positive and finite
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::math::distributions::gamma_random;
+/// let miss_distance = gamma_random(2.0, 10.0);
+/// assert!(miss_distance >= 0.0);
+/// ```
+pub fn gamma_random(shape: f64, scale: f64) -> f64 {
+    gamma_random_with_rng(shape, scale, &mut rand::thread_rng())
+}
+
+/// Same as [`gamma_random`], drawing from `rng`
+pub fn gamma_random_with_rng<R: Rng + ?Sized>(shape: f64, scale: f64, rng: &mut R) -> f64 {
+    Gamma::new(shape, scale).expect("Gamma shape and scale must be positive").sample(rng)
+}
+
+/// Calculate the Gamma PDF at a given point
+///
+/// # Formula
+/// f(d | k, θ) = d^(k-1) * exp(-d/θ) / (Γ(k) * θ^k)
+pub fn gamma_pdf(d: f64, shape: f64, scale: f64) -> f64 {
+    if d < 0.0 || shape <= 0.0 || scale <= 0.0 {
+        return 0.0;
+    }
+    if d == 0.0 {
+        // Finite only for shape ≥ 1; the density diverges below that
+        return if shape < 1.0 {
+            f64::INFINITY
+        } else if shape == 1.0 {
+            1.0 / scale
+        } else {
+            0.0
+        };
+    }
+
+    ((shape - 1.0) * d.ln() - d / scale - ln_gamma(shape) - shape * scale.ln()).exp()
+}
+
+/// Distribution family for miss distances
+///
+/// Every model takes the Rayleigh σ as its size parameter. Weibull uses
+/// scale σ√2, so `Weibull { shape: 2.0 }` is exactly Rayleigh(σ). Gamma
+/// uses the scale that keeps the Rayleigh mean σ√(π/2). The shape then sets
+/// the tail: lower is heavier.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum MissModel {
+    /// Rayleigh(σ), the radial miss of a symmetric 2D normal
+    #[default]
+    Rayleigh,
+    /// Weibull(σ√2, shape)
+    Weibull { shape: f64 },
+    /// Gamma(shape, σ√(π/2) / shape)
+    Gamma { shape: f64 },
+}
+
+impl MissModel {
+    /// Draw a miss distance for a player with Rayleigh-equivalent `sigma`
+    pub fn sample_with_rng<R: Rng + ?Sized>(&self, sigma: f64, rng: &mut R) -> f64 {
+        match *self {
+            MissModel::Rayleigh => rayleigh_random_with_rng(sigma, rng),
+            MissModel::Weibull { shape } => weibull_random_with_rng(sigma * SQRT_2, shape, rng),
+            MissModel::Gamma { shape } => gamma_random_with_rng(shape, rayleigh_mean(sigma) / shape, rng),
+        }
+    }
+
+    /// Miss-distance density at `d`, for integrating payouts under this model
+    pub fn pdf(&self, d: f64, sigma: f64) -> f64 {
+        match *self {
+            MissModel::Rayleigh => rayleigh_pdf(d, sigma),
+            MissModel::Weibull { shape } => weibull_pdf(d, sigma * SQRT_2, shape),
+            MissModel::Gamma { shape } => gamma_pdf(d, shape, rayleigh_mean(sigma) / shape),
+        }
+    }
+
+    /// Probability that a miss is at most `d`, for pricing ring payouts
+    pub fn cdf(&self, d: f64, sigma: f64) -> f64 {
+        if d <= 0.0 || sigma <= 0.0 {
+            return 0.0;
+        }
+        match *self {
+            MissModel::Rayleigh => rayleigh_cdf(d, sigma),
+            MissModel::Weibull { shape } => 1.0 - (-(d / (sigma * SQRT_2)).powf(shape)).exp(),
+            MissModel::Gamma { shape } => gamma_lr(shape, d * shape / rayleigh_mean(sigma)),
+        }
+    }
+}
+
+/// Linearly interpolated percentile of sorted values (0.0 if empty)
+pub(crate) fn percentile(sorted: &[f64], p: f64) -> f64 {
+    if sorted.is_empty() {
+        return 0.0;
+    }
+    let rank = p * (sorted.len() - 1) as f64;
+    let lower = rank.floor() as usize;
+    let upper = rank.ceil() as usize;
+    sorted[lower] + (sorted[upper] - sorted[lower]) * (rank - lower as f64)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -164,6 +568,54 @@ mod tests {
         assert_relative_eq!(mean, 5.0, epsilon = 0.1);
     }
 
+    #[test]
+    fn test_portable_ln_matches_std() {
+        for &x in &[1e-300, 1e-10, 0.02425, 0.5, 1.0, 1.5, 2.0, 10.0, 1e10] {
+            assert_relative_eq!(portable_ln(x), x.ln(), max_relative = 1e-14, epsilon = 1e-15);
+        }
+        assert_eq!(portable_ln(1.0), 0.0);
+        assert!(portable_ln(-1.0).is_nan());
+    }
+
+    #[test]
+    fn test_inverse_normal_cdf_known_values() {
+        assert_eq!(inverse_normal_cdf(0.5), 0.0);
+        assert_relative_eq!(inverse_normal_cdf(0.975), 1.959963984540054, epsilon = 1e-8);
+        assert_relative_eq!(inverse_normal_cdf(0.001), -3.090232306167813, epsilon = 1e-8);
+        assert_relative_eq!(inverse_normal_cdf(0.3), -inverse_normal_cdf(0.7), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_inverse_cdf_sampler_golden_and_moments() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        // Golden bits: these must not change across platforms or refactors
+        let mut rng = StdRng::seed_from_u64(42);
+        let bits: Vec<u64> = (0..5)
+            .map(|_| normal_random_with_rng(0.0, 1.0, NormalSampler::InverseCdf, &mut rng).to_bits())
+            .collect();
+        assert_eq!(
+            bits,
+            [
+                4589464810819812356,
+                4592396328066652696,
+                4599959113094323453,
+                13821118514555754805,
+                13834249578059894548,
+            ]
+        );
+
+        let mut rng = StdRng::seed_from_u64(7);
+        let n = 100_000;
+        let samples: Vec<f64> = (0..n)
+            .map(|_| normal_random_with_rng(5.0, 2.0, NormalSampler::InverseCdf, &mut rng))
+            .collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        assert_relative_eq!(mean, 5.0, epsilon = 0.03);
+        assert_relative_eq!(variance, 4.0, epsilon = 0.08);
+    }
+
     #[test]
     fn test_rayleigh_random_mean() {
         // Test that rayleigh_random produces samples with approximately correct mean
@@ -191,6 +643,17 @@ mod tests {
         assert_relative_eq!(frequency, 0.02, epsilon = 0.005);
     }
 
+    #[test]
+    fn test_rayleigh_cdf_properties() {
+        let sigma = 30.0;
+
+        assert_eq!(rayleigh_cdf(0.0, sigma), 0.0);
+        // Median of a Rayleigh distribution is σ * sqrt(2 ln 2)
+        let median = sigma * (2.0 * 2.0_f64.ln()).sqrt();
+        assert_relative_eq!(rayleigh_cdf(median, sigma), 0.5, epsilon = 1e-12);
+        assert!(rayleigh_cdf(10.0 * sigma, sigma) > 0.999999);
+    }
+
     #[test]
     fn test_rayleigh_pdf_properties() {
         let sigma = 30.0;
@@ -205,6 +668,60 @@ mod tests {
         assert_eq!(rayleigh_pdf(-5.0, sigma), 0.0);
     }
 
+    #[test]
+    fn test_weibull_shape_two_matches_rayleigh() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let sigma = 20.0;
+        let scale = sigma * SQRT_2;
+
+        // Same uniforms, same draws
+        let mut weibull_rng = StdRng::seed_from_u64(299);
+        let mut rayleigh_rng = StdRng::seed_from_u64(299);
+        for _ in 0..100 {
+            assert_relative_eq!(
+                weibull_random_with_rng(scale, 2.0, &mut weibull_rng),
+                rayleigh_random_with_rng(sigma, &mut rayleigh_rng),
+                max_relative = 1e-12
+            );
+        }
+
+        // And the same statistics from independent thread-local draws
+        let n = 100_000;
+        let samples: Vec<f64> = (0..n).map(|_| weibull_random(scale, 2.0)).collect();
+        let mean = samples.iter().sum::<f64>() / n as f64;
+        let variance = samples.iter().map(|d| (d - mean).powi(2)).sum::<f64>() / (n - 1) as f64;
+        assert_relative_eq!(mean, rayleigh_mean(sigma), max_relative = 0.02);
+        assert_relative_eq!(variance, rayleigh_variance(sigma), max_relative = 0.05);
+
+        let model = MissModel::Weibull { shape: 2.0 };
+        for d in [0.0, 5.0, 20.0, 60.0] {
+            assert_relative_eq!(weibull_pdf(d, scale, 2.0), rayleigh_pdf(d, sigma), max_relative = 1e-12);
+            assert_relative_eq!(model.cdf(d, sigma), rayleigh_cdf(d, sigma), max_relative = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_gamma_miss_model_keeps_rayleigh_mean() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let sigma = 20.0;
+        let model = MissModel::Gamma { shape: 1.5 };
+        let mut rng = StdRng::seed_from_u64(2990);
+        let n = 100_000;
+        let mean = (0..n).map(|_| model.sample_with_rng(sigma, &mut rng)).sum::<f64>() / n as f64;
+        assert_relative_eq!(mean, rayleigh_mean(sigma), max_relative = 0.02);
+
+        // The density integrates to 1
+        let step = 0.01;
+        let mass: f64 = (0..40_000).map(|i| model.pdf((i as f64 + 0.5) * step, sigma) * step).sum();
+        assert_relative_eq!(mass, 1.0, epsilon = 1e-3);
+
+        // And accumulates to the CDF
+        let within_30: f64 = (0..3000).map(|i| model.pdf((i as f64 + 0.5) * step, sigma) * step).sum();
+        assert_relative_eq!(model.cdf(30.0, sigma), within_30, epsilon = 1e-5);
+    }
+
     #[test]
     fn test_rayleigh_mean_formula() {
         let sigma = 25.0;
@@ -3,6 +3,8 @@
 // Implements trapezoidal rule and adaptive integration for computing
 // expected payout integrals needed for dynamic odds calculation.
 
+use serde::{Deserialize, Serialize};
+
 /// Integrate a function using the trapezoidal rule
 ///
 /// The trapezoidal rule approximates the definite integral by dividing
@@ -133,7 +135,7 @@ pub fn simpsons_rule<F>(f: F, a: f64, b: f64, n: usize) -> f64
 where
     F: Fn(f64) -> f64,
 {
-    assert!(n % 2 == 0, "n must be even for Simpson's rule");
+    assert!(n.is_multiple_of(2), "n must be even for Simpson's rule");
     assert!(n > 0, "n must be positive");
 
     let h = (b - a) / n as f64;
@@ -148,6 +150,55 @@ where
     (h / 3.0) * sum
 }
 
+/// Quadrature rule used for P_max integrals
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum IntegrationMethod {
+    /// `trapezoidal_rule`: second order, under-resolves sharply peaked integrands
+    Trapezoidal,
+    /// `simpsons_rule`: fourth order for smooth integrands
+    #[default]
+    Simpson,
+    /// `adaptive_integration` on each of `ADAPTIVE_PANELS` equal panels:
+    /// refines where the integrand varies rapidly; ignores the subdivision count
+    Adaptive,
+}
+
+impl IntegrationMethod {
+    /// Error tolerance for `Adaptive`, shared across its panels
+    pub const ADAPTIVE_TOLERANCE: f64 = 1e-10;
+    /// Maximum recursion depth for `Adaptive`
+    pub const ADAPTIVE_MAX_DEPTH: usize = 20;
+    /// Initial panels for `Adaptive`
+    ///
+    /// A single coarse pass can step over a narrow peak entirely and accept
+    /// zero; starting from several panels keeps a tight Rayleigh peak visible.
+    pub const ADAPTIVE_PANELS: usize = 100;
+
+    /// Integrate `f` over [a, b] with `n` subdivisions
+    ///
+    /// An odd `n` is rounded up to the next even number for `Simpson`.
+    pub fn integrate<F>(&self, f: F, a: f64, b: f64, n: usize) -> f64
+    where
+        F: Fn(f64) -> f64 + Copy,
+    {
+        match self {
+            IntegrationMethod::Trapezoidal => trapezoidal_rule(f, a, b, n),
+            IntegrationMethod::Simpson => simpsons_rule(f, a, b, (n + n % 2).max(2)),
+            IntegrationMethod::Adaptive => {
+                let panels = Self::ADAPTIVE_PANELS;
+                let width = (b - a) / panels as f64;
+                (0..panels)
+                    .map(|i| {
+                        let lo = a + i as f64 * width;
+                        let tol = Self::ADAPTIVE_TOLERANCE / panels as f64;
+                        adaptive_integration(f, lo, lo + width, tol, Self::ADAPTIVE_MAX_DEPTH)
+                    })
+                    .sum()
+            }
+        }
+    }
+}
+
 /// Integrate the payout function for P_max calculation
 ///
 /// Computes: ∫[0, d_max] (1 - d/d_max)^k * PDF(d | σ) dd
@@ -108,6 +108,110 @@ impl KalmanState {
         self.error_covariance *= 1.0 - kalman_gain;
     }
 
+    /// Predict and update with process noise scaled by wager volatility
+    ///
+    /// A player whose wagers swing widely is more likely to be changing how
+    /// they play, so the prediction step adds Q × (1 + CV) instead of Q,
+    /// where CV is the coefficient of variation of the wagers behind this
+    /// measurement. The scale is capped at `MAX_PROCESS_NOISE_SCALE`, and the
+    /// stored `process_noise` is left unchanged. With CV = 0 (flat wagers)
+    /// this is exactly `predict` followed by `update`.
+    ///
+    /// # Arguments
+    /// * `measurement` - Observed miss distance (after debiasing for Rayleigh)
+    /// * `measurement_noise` - Uncertainty in this measurement (R)
+    /// * `wager_cv` - Coefficient of variation of recent wagers (see [`wager_cv`])
+    ///
+    /// # Example
+    /// ```
+    /// use continuum_golf_simulator::math::kalman::KalmanState;
+    ///
+    /// let mut steady = KalmanState::new(30.0, 1.0);
+    /// let mut bursty = KalmanState::new(30.0, 1.0);
+    /// steady.update_with_adaptive_q(28.0, 50.0, 0.0);
+    /// bursty.update_with_adaptive_q(28.0, 50.0, 1.5);
+    /// assert!(bursty.error_covariance > steady.error_covariance);
+    /// ```
+    pub fn update_with_adaptive_q(&mut self, measurement: f64, measurement_noise: f64, wager_cv: f64) {
+        let scale = (1.0 + wager_cv.max(0.0)).min(MAX_PROCESS_NOISE_SCALE);
+        self.error_covariance += self.process_noise * scale;
+        self.update(measurement, measurement_noise);
+    }
+
+    /// Exponentially weighted moving average update, in place of the Kalman gain
+    ///
+    /// The estimate moves a fixed fraction `alpha` toward the measurement and
+    /// no process noise is added. The error covariance follows the EWMA's
+    /// variance, P ← (1-α)²P + α²R, which settles at R·α/(2-α): the
+    /// uncertainty of a plain mean of (2-α)/α measurements, its effective
+    /// sample count. `calculate_confidence` then works unchanged.
+    ///
+    /// # Arguments
+    /// * `measurement` - Observed miss distance (after debiasing for Rayleigh)
+    /// * `measurement_noise` - Uncertainty in this measurement (R)
+    /// * `alpha` - Weight of the new measurement, clamped to [0, 1]
+    ///
+    /// # Example
+    /// ```
+    /// use continuum_golf_simulator::math::kalman::KalmanState;
+    ///
+    /// let mut ewma = KalmanState::new(30.0, 1.0);
+    /// ewma.update_ewma(20.0, 50.0, 0.1);
+    /// assert!((ewma.estimate - 29.0).abs() < 1e-12);
+    /// ```
+    pub fn update_ewma(&mut self, measurement: f64, measurement_noise: f64, alpha: f64) {
+        let alpha = alpha.clamp(0.0, 1.0);
+        self.estimate += alpha * (measurement - self.estimate);
+        self.error_covariance =
+            (1.0 - alpha).powi(2) * self.error_covariance + alpha.powi(2) * measurement_noise;
+    }
+
+    /// Normalized innovation of a measurement (1D Mahalanobis distance)
+    ///
+    /// |z - σ| / √(P + R): how many standard deviations the measurement lies
+    /// from the estimate, given the uncertainty in both.
+    ///
+    /// # Arguments
+    /// * `measurement` - Observed miss distance (after debiasing for Rayleigh)
+    /// * `measurement_noise` - Uncertainty in this measurement (R)
+    pub fn innovation_z_score(&self, measurement: f64, measurement_noise: f64) -> f64 {
+        (measurement - self.estimate).abs() / (self.error_covariance + measurement_noise).sqrt()
+    }
+
+    /// Measurement noise to use for a possible outlier
+    ///
+    /// Returns `measurement_noise` unchanged if the innovation z-score is at
+    /// most `z_threshold`. Otherwise R is inflated until the z-score equals
+    /// the threshold (R' = innovation² / threshold² - P), so the further out a
+    /// measurement lies, the less it moves the estimate.
+    pub fn outlier_adjusted_noise(&self, measurement: f64, measurement_noise: f64, z_threshold: f64) -> f64 {
+        if self.innovation_z_score(measurement, measurement_noise) <= z_threshold {
+            return measurement_noise;
+        }
+        let innovation = measurement - self.estimate;
+        innovation.powi(2) / z_threshold.powi(2) - self.error_covariance
+    }
+
+    /// Update step that down-weights outliers instead of applying them directly
+    ///
+    /// Same as `update`, but with R inflated for measurements more than
+    /// `z_threshold` standard deviations from the estimate (see
+    /// `outlier_adjusted_noise`).
+    ///
+    /// # Example
+    /// ```
+    /// use continuum_golf_simulator::math::kalman::{KalmanState, OUTLIER_Z_THRESHOLD};
+    ///
+    /// let mut kalman = KalmanState::new(30.0, 1.0);
+    /// kalman.error_covariance = 10.0;
+    /// kalman.update_robust(150.0, 50.0, OUTLIER_Z_THRESHOLD); // A shank
+    /// assert!(kalman.estimate < 32.0);
+    /// ```
+    pub fn update_robust(&mut self, measurement: f64, measurement_noise: f64, z_threshold: f64) {
+        let noise = self.outlier_adjusted_noise(measurement, measurement_noise, z_threshold);
+        self.update(measurement, noise);
+    }
+
     /// Calculate confidence score from error covariance
     ///
     /// Maps error covariance (P) to a confidence percentage (0-100%).
@@ -198,7 +302,8 @@ pub fn debias_rayleigh_measurement(measured_miss: f64) -> f64 {
 /// * `measurements` - Vec of (miss_distance, wager) tuples
 ///
 /// # Returns
-/// Weighted average miss distance
+/// Weighted average miss distance; the plain mean if every wager is zero
+/// (free-play shots still measure skill), or 0.0 for an empty batch
 ///
 /// # Formula
 /// z_weighted = Σ(miss_i * wager_i) / Σ(wager_i)
@@ -206,7 +311,10 @@ pub fn weighted_average_measurement(measurements: &[(f64, f64)]) -> f64 {
     let total_weight: f64 = measurements.iter().map(|(_, w)| w).sum();
 
     if total_weight == 0.0 {
-        return 0.0;
+        if measurements.is_empty() {
+            return 0.0;
+        }
+        return measurements.iter().map(|(m, _)| m).sum::<f64>() / measurements.len() as f64;
     }
 
     let weighted_sum: f64 = measurements.iter().map(|(m, w)| m * w).sum();
@@ -214,10 +322,38 @@ pub fn weighted_average_measurement(measurements: &[(f64, f64)]) -> f64 {
     weighted_sum / total_weight
 }
 
+/// Innovation z-score beyond which a measurement is treated as an outlier
+pub const OUTLIER_Z_THRESHOLD: f64 = 3.0;
+
+/// Ceiling on the process noise multiplier in `update_with_adaptive_q`
+///
+/// Q scales with 1 + CV, so this caps wager volatility at a CV of 4 (e.g.
+/// one $400 wager in a batch of $1 wagers); anything wilder adds no more.
+pub const MAX_PROCESS_NOISE_SCALE: f64 = 5.0;
+
+/// Coefficient of variation (population std dev / mean) of a set of wagers
+///
+/// # Returns
+/// 0.0 for fewer than two wagers or a non-positive mean
+pub fn wager_cv(wagers: &[f64]) -> f64 {
+    if wagers.len() < 2 {
+        return 0.0;
+    }
+    let n = wagers.len() as f64;
+    let mean = wagers.iter().sum::<f64>() / n;
+    if mean <= 0.0 {
+        return 0.0;
+    }
+    let variance = wagers.iter().map(|w| (w - mean).powi(2)).sum::<f64>() / n;
+    variance.sqrt() / mean
+}
+
 /// Calculate variance of a batch of measurements
 ///
-/// Used to determine dynamic measurement noise (R) for batch updates.
-/// Higher variance means less trustworthy batch.
+/// Not used for the measurement noise (R) of skill updates: a Rayleigh
+/// batch's spread grows with its mean, so weighting by it biases the estimate
+/// low. `Player::update_skill` uses the variance expected at the current
+/// estimate instead.
 ///
 /// # Arguments
 /// * `measurements` - Vec of miss distances
@@ -239,6 +375,98 @@ pub fn measurement_variance(measurements: &[f64]) -> f64 {
     variance
 }
 
+/// Calculate the wager-weighted variance of a batch about its weighted mean
+///
+/// The spread that matches `weighted_average_measurement`: each miss counts in
+/// proportion to its wager, with the reliability-weights correction
+/// Σw_i(x_i - z)² / (V₁ - V₂/V₁), where V₁ = Σw_i and V₂ = Σw_i². Equal wagers
+/// give the plain sample variance. `Player::update_skill` scales its
+/// measurement noise (R) by this over the unweighted variance.
+///
+/// # Arguments
+/// * `measurements` - Vec of (miss_distance, wager) tuples
+///
+/// # Returns
+/// Weighted sample variance; the unweighted `measurement_variance` if every
+/// wager is zero, or its single-measurement default (100.0) when fewer than
+/// two shots carry weight
+pub fn weighted_measurement_variance(measurements: &[(f64, f64)]) -> f64 {
+    let total_weight: f64 = measurements.iter().map(|(_, w)| w).sum();
+    if total_weight == 0.0 {
+        let misses: Vec<f64> = measurements.iter().map(|(m, _)| *m).collect();
+        return measurement_variance(&misses);
+    }
+
+    let sum_sq_weights: f64 = measurements.iter().map(|(_, w)| w * w).sum();
+    let effective_denominator = total_weight - sum_sq_weights / total_weight;
+    if effective_denominator <= 0.0 {
+        return measurement_variance(&[]);
+    }
+
+    let mean = weighted_average_measurement(measurements);
+    measurements.iter().map(|(m, w)| w * (m - mean).powi(2)).sum::<f64>() / effective_denominator
+}
+
+/// Number of consecutive same-direction updates treated as divergence
+///
+/// The estimate is smoothed, so a healthy filter still drifts one way for
+/// runs of up to ~13 updates in a 300-shot stream; 20 leaves clear headroom.
+pub const DIVERGENCE_RUN_LENGTH: usize = 20;
+
+/// Pace a same-direction run must keep to count as divergence
+///
+/// A filter converging from a far prior also moves one way for many
+/// updates, but it slows as it closes in: over a `DIVERGENCE_RUN_LENGTH` run
+/// its second half covers well under half the distance of the first. A
+/// runaway keeps its pace, so the second half covers at least this fraction.
+pub const DIVERGENCE_PACE_RATIO: f64 = 0.75;
+
+/// Largest physically plausible skill estimate (σ in feet)
+///
+/// A 100-yard dispersion is beyond any hole in the configuration; estimates
+/// past this point indicate a runaway filter rather than a bad golfer.
+pub const MAX_PHYSICAL_SIGMA_FT: f64 = 300.0;
+
+/// Check whether a sequence of post-update estimates shows divergence
+///
+/// A converging filter jitters around the true value, so its updates change
+/// direction regularly, and one converging from a far prior slows as it
+/// approaches. The filter is treated as diverging if:
+/// - any estimate is non-finite, non-positive, or above `MAX_PHYSICAL_SIGMA_FT`
+/// - the last `DIVERGENCE_RUN_LENGTH` updates all moved in the same direction
+///   without slowing: the second half of the run covers at least
+///   `DIVERGENCE_PACE_RATIO` of the first half's distance
+///
+/// # Arguments
+/// * `estimate_history` - Estimates after each update, oldest first
+///
+/// # Returns
+/// True if the filter appears to be running away
+pub fn is_diverging(estimate_history: &[f64]) -> bool {
+    if estimate_history
+        .iter()
+        .any(|&e| !e.is_finite() || e <= 0.0 || e > MAX_PHYSICAL_SIGMA_FT)
+    {
+        return true;
+    }
+
+    if estimate_history.len() <= DIVERGENCE_RUN_LENGTH {
+        return false;
+    }
+
+    let recent = &estimate_history[estimate_history.len() - DIVERGENCE_RUN_LENGTH - 1..];
+    let all_increasing = recent.windows(2).all(|w| w[1] > w[0]);
+    let all_decreasing = recent.windows(2).all(|w| w[1] < w[0]);
+    if !(all_increasing || all_decreasing) {
+        return false;
+    }
+
+    let middle = recent[DIVERGENCE_RUN_LENGTH / 2];
+    let first_half = (middle - recent[0]).abs();
+    let second_half = (recent[DIVERGENCE_RUN_LENGTH] - middle).abs();
+    second_half >= DIVERGENCE_PACE_RATIO * first_half
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -312,6 +540,13 @@ mod tests {
         assert_eq!(avg, 20.0);
     }
 
+    #[test]
+    fn test_weighted_average_zero_wagers() {
+        let free_play = vec![(10.0, 0.0), (20.0, 0.0), (30.0, 0.0)];
+        assert_eq!(weighted_average_measurement(&free_play), 20.0);
+        assert_eq!(weighted_average_measurement(&[]), 0.0);
+    }
+
     #[test]
     fn test_measurement_variance() {
         let measurements = vec![10.0, 12.0, 14.0, 16.0];
@@ -321,6 +556,88 @@ mod tests {
         assert_relative_eq!(variance, 6.666, epsilon = 0.01);
     }
 
+    #[test]
+    fn test_weighted_measurement_variance() {
+        // Equal wagers reduce to the sample variance
+        let flat = vec![(10.0, 5.0), (12.0, 5.0), (14.0, 5.0), (16.0, 5.0)];
+        assert_relative_eq!(weighted_measurement_variance(&flat), 6.666, epsilon = 0.01);
+
+        // A long miss on a big wager: the weighted mean sits near the outlier
+        // (z ≈ 35.1 vs a plain mean of 16.4) and the batch's effective size
+        // falls to ~1.4 shots, so the weighted spread is over twice the plain one
+        let batch = vec![(10.0, 1.0), (12.0, 1.0), (11.0, 1.0), (9.0, 1.0), (40.0, 20.0)];
+        let misses: Vec<f64> = batch.iter().map(|(m, _)| *m).collect();
+        assert_relative_eq!(measurement_variance(&misses), 175.3, epsilon = 1e-9);
+        assert_relative_eq!(weighted_measurement_variance(&batch), 405.4651, epsilon = 1e-3);
+
+        // Free play falls back to the unweighted variance, a lone wager to the default
+        let free_play = vec![(10.0, 0.0), (20.0, 0.0), (40.0, 0.0)];
+        assert_relative_eq!(weighted_measurement_variance(&free_play), 233.333, epsilon = 1e-3);
+        assert_eq!(weighted_measurement_variance(&[(10.0, 0.0), (20.0, 5.0)]), 100.0);
+    }
+
+    #[test]
+    fn test_adaptive_q_scales_process_noise() {
+        let mut plain = KalmanState::new(30.0, 2.0);
+        let mut flat = plain.clone();
+        plain.predict();
+        plain.update(28.0, 50.0);
+        flat.update_with_adaptive_q(28.0, 50.0, 0.0);
+        assert_eq!(flat.estimate, plain.estimate);
+        assert_eq!(flat.error_covariance, plain.error_covariance);
+
+        // Q × 1.5 before the update
+        let mut volatile = KalmanState::new(30.0, 2.0);
+        volatile.error_covariance = 10.0;
+        volatile.update_with_adaptive_q(28.0, 50.0, 0.5);
+        assert_relative_eq!(volatile.error_covariance, 13.0 * 50.0 / 63.0, epsilon = 1e-12);
+        assert_eq!(volatile.process_noise, 2.0);
+
+        // Capped
+        let mut capped = KalmanState::new(30.0, 2.0);
+        capped.error_covariance = 10.0;
+        capped.update_with_adaptive_q(28.0, 50.0, 100.0);
+        let max_prior = 10.0 + 2.0 * MAX_PROCESS_NOISE_SCALE;
+        assert_relative_eq!(capped.error_covariance, max_prior * 50.0 / (max_prior + 50.0), epsilon = 1e-12);
+    }
+
+    #[test]
+    fn test_robust_update_ignores_outlier() {
+        let mut plain = KalmanState::new(30.0, 1.0);
+        for _ in 0..20 {
+            plain.predict();
+            plain.update(30.0, 50.0);
+        }
+        let mut robust = plain.clone();
+        let before = plain.estimate;
+
+        assert!(robust.innovation_z_score(30.0 * 5.0, 50.0) > OUTLIER_Z_THRESHOLD);
+        assert!(robust.innovation_z_score(33.0, 50.0) < OUTLIER_Z_THRESHOLD);
+
+        plain.predict();
+        plain.update(30.0 * 5.0, 50.0);
+        robust.predict();
+        robust.update_robust(30.0 * 5.0, 50.0, OUTLIER_Z_THRESHOLD);
+
+        assert!(plain.estimate - before > 10.0, "plain moved {}", plain.estimate - before);
+        assert!(robust.estimate - before < 1.0, "robust moved {}", robust.estimate - before);
+
+        // Inliers are applied as usual
+        let mut inlier = KalmanState::new(30.0, 1.0);
+        let mut reference = inlier.clone();
+        inlier.update_robust(32.0, 50.0, OUTLIER_Z_THRESHOLD);
+        reference.update(32.0, 50.0);
+        assert_eq!(inlier.estimate, reference.estimate);
+    }
+
+    #[test]
+    fn test_wager_cv() {
+        assert_eq!(wager_cv(&[10.0, 10.0, 10.0]), 0.0);
+        assert_relative_eq!(wager_cv(&[5.0, 15.0]), 0.5, epsilon = 1e-12);
+        assert_eq!(wager_cv(&[10.0]), 0.0);
+        assert_eq!(wager_cv(&[0.0, 0.0]), 0.0);
+    }
+
     #[test]
     fn test_reset() {
         let mut kalman = KalmanState::new(30.0, 1.0);
@@ -338,4 +655,43 @@ mod tests {
         assert_eq!(kalman.estimate, 30.0);
         assert_eq!(kalman.error_covariance, 1000.0);
     }
+
+    #[test]
+    fn test_divergence_detection() {
+        // Monotonically increasing misses drag the estimate up every update
+        let mut diverging = KalmanState::new(30.0, 1.0);
+        let mut history = Vec::new();
+        for i in 0..30 {
+            diverging.predict();
+            diverging.update(30.0 + 5.0 * i as f64, 50.0);
+            history.push(diverging.estimate);
+        }
+        assert!(is_diverging(&history));
+
+        // A stable stream alternates around the true value
+        let mut stable = KalmanState::new(30.0, 1.0);
+        let mut history = Vec::new();
+        for i in 0..30 {
+            stable.predict();
+            stable.update(if i % 2 == 0 { 27.0 } else { 33.0 }, 50.0);
+            history.push(stable.estimate);
+        }
+        assert!(!is_diverging(&history));
+
+        // Converging from a far prior falls on every update, but slows down
+        let mut converging = KalmanState::new(80.0, 1.0);
+        let mut history = Vec::new();
+        for _ in 0..30 {
+            converging.predict();
+            converging.update(20.0, 50.0);
+            history.push(converging.estimate);
+        }
+        assert!(history.windows(2).all(|w| w[1] < w[0]));
+        assert!(!is_diverging(&history));
+
+        // Physical bound and non-finite values
+        assert!(is_diverging(&[30.0, MAX_PHYSICAL_SIGMA_FT + 1.0]));
+        assert!(is_diverging(&[30.0, f64::NAN]));
+        assert!(!is_diverging(&[]));
+    }
 }
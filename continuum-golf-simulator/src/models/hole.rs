@@ -6,7 +6,12 @@
 // - Return to Player (RTP: 0.86-0.90)
 // - Steepness factor (k: 5.0-6.5)
 
+use crate::math::distributions::{rayleigh_cdf, rayleigh_pdf};
+use crate::math::integration::integrate_payout_function;
 use serde::{Deserialize, Serialize};
+use std::error::Error;
+use std::fs::File;
+use std::io::BufReader;
 
 /// Club category based on distance
 #[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
@@ -28,6 +33,61 @@ impl ClubCategory {
             _ => ClubCategory::LongIron,
         }
     }
+
+    /// Representative distance for the category, in yards
+    ///
+    /// A category's skill estimate is the dispersion at this distance; shots
+    /// at other distances in the category are scaled to it.
+    pub fn reference_distance_yds(&self) -> u16 {
+        match self {
+            ClubCategory::Wedge => 100,
+            ClubCategory::MidIron => 162,
+            ClubCategory::LongIron => 225,
+        }
+    }
+}
+
+/// Shape of a hole's payout as a function of miss distance
+#[derive(Debug, Clone, Default, PartialEq, Serialize, Deserialize)]
+pub enum PayoutModel {
+    /// P(d) = P_max * (1 - d/d_max)^k, using the hole's `k`
+    #[default]
+    Continuous,
+    /// Fixed fractions of P_max by ring
+    ///
+    /// Each `(radius_ft, fraction)` pays `P_max * fraction`. The first ring
+    /// whose radius contains the miss applies, so list rings innermost
+    /// first. A miss outside every ring pays 0. For example,
+    /// `[(2.0, 1.0), (5.0, 0.5)]` pays P_max inside 2 ft and half of it
+    /// inside 5 ft.
+    Rings { thresholds: Vec<(f64, f64)> },
+}
+
+impl PayoutModel {
+    /// Whether this is the default continuous curve
+    pub fn is_continuous(&self) -> bool {
+        matches!(self, PayoutModel::Continuous)
+    }
+
+    /// (fraction, probability) for each ring, given the miss-distance CDF
+    ///
+    /// A ring only captures misses not already inside an earlier ring.
+    /// Empty for the continuous model.
+    pub fn ring_probabilities(&self, cdf: impl Fn(f64) -> f64) -> Vec<(f64, f64)> {
+        let PayoutModel::Rings { thresholds } = self else {
+            return Vec::new();
+        };
+        let mut inner = 0.0_f64;
+        thresholds
+            .iter()
+            .map(|&(radius, fraction)| {
+                let outer = inner.max(radius);
+                let probability = cdf(outer) - cdf(inner);
+                inner = outer;
+                (fraction, probability)
+            })
+            .collect()
+    }
 }
 
 /// Hole configuration with scoring parameters
@@ -45,6 +105,9 @@ pub struct Hole {
     pub k: f64,
     /// Club category
     pub category: ClubCategory,
+    /// Payout shape; the continuous `(1 - d/d_max)^k` curve by default
+    #[serde(default)]
+    pub payout_model: PayoutModel,
 }
 
 impl Hole {
@@ -64,6 +127,7 @@ impl Hole {
             rtp,
             k,
             category,
+            payout_model: PayoutModel::Continuous,
         }
     }
 
@@ -73,6 +137,9 @@ impl Hole {
     /// If d ≤ d_max: P(d) = P_max * (1 - d/d_max)^k
     /// If d > d_max: P(d) = 0
     ///
+    /// A [`PayoutModel::Rings`] hole pays `P_max * fraction` for the first
+    /// ring containing the miss instead.
+    ///
     /// # Arguments
     /// * `miss_distance` - Miss distance in feet
     /// * `p_max` - Maximum payout multiplier (calculated from player skill)
@@ -90,13 +157,45 @@ impl Hole {
     /// assert!(payout <= 12.0);
     /// ```
     pub fn calculate_payout(&self, miss_distance: f64, p_max: f64) -> f64 {
-        if miss_distance > self.d_max_ft {
-            return 0.0;
+        match &self.payout_model {
+            PayoutModel::Continuous => {
+                if miss_distance > self.d_max_ft {
+                    return 0.0;
+                }
+
+                // P(d) = P_max * (1 - d/d_max)^k
+                let normalized = 1.0 - (miss_distance / self.d_max_ft);
+                p_max * normalized.powf(self.k)
+            }
+            PayoutModel::Rings { thresholds } => thresholds
+                .iter()
+                .find(|(radius, _)| miss_distance <= *radius)
+                .map_or(0.0, |(_, fraction)| p_max * fraction),
         }
+    }
 
-        // P(d) = P_max * (1 - d/d_max)^k
-        let normalized = 1.0 - (miss_distance / self.d_max_ft);
-        p_max * normalized.powf(self.k)
+    /// Calculate payout multiplier under a dollar liability cap
+    ///
+    /// Same as `calculate_payout`, but a shot whose payout (multiplier ×
+    /// wager) would exceed `max_payout_dollars` pays exactly the cap. The cap
+    /// only ever lowers the multiplier.
+    ///
+    /// # Example
+    /// ```
+    /// use continuum_golf_simulator::models::hole::Hole;
+    ///
+    /// let hole = Hole::new(1, 75, 17.95, 0.86, 5.0);
+    /// // A $10,000 wager at 12× would pay $120,000; capped at $100,000
+    /// assert!((hole.calculate_capped_payout(0.0, 12.0, 10_000.0, 100_000.0) - 10.0).abs() < 1e-12);
+    /// assert_eq!(hole.calculate_capped_payout(0.0, 12.0, 10.0, 100_000.0), 12.0);
+    /// ```
+    pub fn calculate_capped_payout(&self, miss_distance: f64, p_max: f64, wager: f64, max_payout_dollars: f64) -> f64 {
+        let multiplier = self.calculate_payout(miss_distance, p_max);
+        if wager > 0.0 && multiplier * wager > max_payout_dollars {
+            multiplier.min(max_payout_dollars.max(0.0) / wager)
+        } else {
+            multiplier
+        }
     }
 
     /// Calculate breakeven radius for a given P_max
@@ -107,6 +206,10 @@ impl Hole {
     /// # Formula
     /// d_break = d_max * (1 - P_max^(-1/k))
     ///
+    /// For a [`PayoutModel::Rings`] hole it is the outer radius of the last
+    /// ring, counting from the center, before the first ring that pays less
+    /// than the wager.
+    ///
     /// # Arguments
     /// * `p_max` - Maximum payout multiplier
     ///
@@ -124,6 +227,13 @@ impl Hole {
     /// assert!((payout - 1.0).abs() < 0.01);
     /// ```
     pub fn calculate_breakeven_radius(&self, p_max: f64) -> f64 {
+        if let PayoutModel::Rings { thresholds } = &self.payout_model {
+            return thresholds
+                .iter()
+                .take_while(|(_, fraction)| p_max * fraction >= 1.0)
+                .fold(0.0, |radius, (ring, _)| radius.max(*ring));
+        }
+
         if p_max <= 1.0 {
             return 0.0; // No breakeven possible
         }
@@ -137,10 +247,292 @@ impl Hole {
         p_max
     }
 
+    /// Sample the payout curve for plotting
+    ///
+    /// Returns `points` (miss distance, multiplier) pairs evenly spaced from
+    /// 0 to `d_max_ft` inclusive. A single point samples the center only.
+    ///
+    /// # Example
+    /// ```
+    /// use continuum_golf_simulator::models::hole::Hole;
+    ///
+    /// let hole = Hole::new(4, 150, 47.58, 0.88, 6.0);
+    /// let curve = hole.payout_curve(10.0, 5);
+    /// assert_eq!(curve.len(), 5);
+    /// assert_eq!(curve[0], (0.0, 10.0));
+    /// assert_eq!(curve[4], (47.58, 0.0));
+    /// ```
+    pub fn payout_curve(&self, p_max: f64, points: usize) -> Vec<(f64, f64)> {
+        let intervals = points.saturating_sub(1).max(1) as f64;
+        (0..points)
+            .map(|i| {
+                // Fraction first, so the last sample lands exactly on d_max
+                let d = (i as f64 / intervals) * self.d_max_ft;
+                (d, self.calculate_payout(d, p_max))
+            })
+            .collect()
+    }
+
+    /// Winning region for a given P_max, as (0, breakeven radius) in feet
+    ///
+    /// Any miss inside the zone pays more than the wager. The zone is empty,
+    /// `(0.0, 0.0)`, when P_max ≤ 1.
+    pub fn breakeven_zone(&self, p_max: f64) -> (f64, f64) {
+        (0.0, self.calculate_breakeven_radius(p_max))
+    }
+
+    /// Expected payout fraction E[(1 - d/d_max)^k] for a fat-tail Rayleigh mixture
+    ///
+    /// Misses come from Rayleigh(σ) with probability 1 - `fat_tail_prob` and
+    /// Rayleigh(σ × `fat_tail_mult`) otherwise. Multiplying by P_max gives the
+    /// expected payout multiplier (i.e. the RTP).
+    pub fn expected_payout_fraction(&self, sigma: f64, fat_tail_prob: f64, fat_tail_mult: f64) -> f64 {
+        self.payout_fraction_moment(sigma, fat_tail_prob, fat_tail_mult, 1)
+    }
+
+    /// E[fraction^power] of the payout fraction for a fat-tail Rayleigh mixture
+    ///
+    /// The fraction is (1 - d/d_max)^k on a continuous hole and the ring's
+    /// fraction on a ring hole. With `power = 2` this is the second moment.
+    pub fn payout_fraction_moment(&self, sigma: f64, fat_tail_prob: f64, fat_tail_mult: f64, power: i32) -> f64 {
+        if !self.payout_model.is_continuous() {
+            let cdf = |d: f64| {
+                (1.0 - fat_tail_prob) * rayleigh_cdf(d, sigma)
+                    + fat_tail_prob * rayleigh_cdf(d, sigma * fat_tail_mult)
+            };
+            return self
+                .payout_model
+                .ring_probabilities(cdf)
+                .iter()
+                .map(|(fraction, probability)| fraction.powi(power) * probability)
+                .sum();
+        }
+
+        let n = 2000;
+        let exponent = power as f64 * self.k;
+        let normal = integrate_payout_function(self.d_max_ft, exponent, sigma, rayleigh_pdf, n);
+        let fat = integrate_payout_function(self.d_max_ft, exponent, sigma * fat_tail_mult, rayleigh_pdf, n);
+
+        (1.0 - fat_tail_prob) * normal + fat_tail_prob * fat
+    }
+
     /// Get the club category for this hole
     pub fn get_category(&self) -> ClubCategory {
         self.category
     }
+
+    /// Synthetic hole at an arbitrary distance
+    ///
+    /// Interpolates `d_max_ft`, `rtp` and `k` between the standard holes in
+    /// `HOLE_CONFIGURATIONS` with a monotone cubic spline, which passes through
+    /// every configured hole and never overshoots between them (so the stepped
+    /// k values stay flat across a step). Distances outside 75-250 yds clamp
+    /// to the nearest end. The category comes from the requested distance.
+    ///
+    /// The synthetic hole has id 0; give it an id before adding it to a
+    /// [`HoleSet`], e.g. `Hole { id: 1, ..Hole::interpolate(140) }`.
+    pub fn interpolate(distance_yds: u16) -> Hole {
+        let distances = HOLE_CONFIGURATIONS.map(|h| h.distance_yds as f64);
+        let x = (distance_yds as f64).clamp(distances[0], distances[distances.len() - 1]);
+        let at = |values: [f64; 8]| pchip_interpolate(&distances, &values, x);
+
+        Hole::new(
+            0,
+            distance_yds,
+            at(HOLE_CONFIGURATIONS.map(|h| h.d_max_ft)),
+            at(HOLE_CONFIGURATIONS.map(|h| h.rtp)),
+            at(HOLE_CONFIGURATIONS.map(|h| h.k)),
+        )
+    }
+
+    /// Load hole configurations from a JSON file
+    ///
+    /// The file holds an array of objects with `id`, `distance_yds`,
+    /// `d_max_ft`, `rtp` and `k`; each hole's category is derived from its
+    /// distance. The holes are validated as a [`HoleSet`].
+    ///
+    /// # Errors
+    /// If the file cannot be read or parsed, or the holes are invalid
+    pub fn load_from_json(path: &str) -> Result<Vec<Hole>, Box<dyn Error>> {
+        Ok(HoleSet::load_from_json(path)?.holes)
+    }
+}
+
+/// Hole parameters as written in a configuration file (category is derived)
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoleSpec {
+    pub id: u8,
+    pub distance_yds: u16,
+    pub d_max_ft: f64,
+    pub rtp: f64,
+    pub k: f64,
+    #[serde(default, skip_serializing_if = "PayoutModel::is_continuous")]
+    pub payout_model: PayoutModel,
+}
+
+/// A validated set of holes that a venue offers
+///
+/// Ids are unique and numbered 1..=N for N holes (in any order). Defaults to
+/// the 8 standard holes in `HOLE_CONFIGURATIONS`. Serializes as a list of
+/// [`HoleSpec`]s, and deserializing validates it again.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+#[serde(try_from = "Vec<HoleSpec>", into = "Vec<HoleSpec>")]
+pub struct HoleSet {
+    holes: Vec<Hole>,
+}
+
+impl HoleSet {
+    /// The 8 standard holes
+    pub fn standard() -> Self {
+        HoleSet { holes: HOLE_CONFIGURATIONS.to_vec() }
+    }
+
+    /// Validate a list of holes
+    ///
+    /// # Errors
+    /// If the list is empty, an id is duplicated or outside 1..=N, or a
+    /// hole's d_max, RTP or k is not positive
+    pub fn new(holes: Vec<Hole>) -> Result<Self, Box<dyn Error>> {
+        if holes.is_empty() {
+            return Err("Hole set is empty".into());
+        }
+        let mut seen = vec![false; holes.len()];
+        for hole in &holes {
+            let index = (hole.id as usize).wrapping_sub(1);
+            if index >= holes.len() {
+                return Err(format!("Hole id {} is outside 1..={}", hole.id, holes.len()).into());
+            }
+            if seen[index] {
+                return Err(format!("Duplicate hole id {}", hole.id).into());
+            }
+            seen[index] = true;
+            if !(hole.d_max_ft > 0.0 && hole.rtp > 0.0 && hole.k > 0.0) {
+                return Err(format!("Hole {} needs positive d_max_ft, rtp and k", hole.id).into());
+            }
+            if let PayoutModel::Rings { thresholds } = &hole.payout_model {
+                let valid_rings = thresholds.iter().all(|(radius, fraction)| *radius > 0.0 && *fraction >= 0.0);
+                if !valid_rings || !thresholds.iter().any(|(_, fraction)| *fraction > 0.0) {
+                    return Err(format!(
+                        "Hole {} rings need positive radii, non-negative fractions and at least one paying ring",
+                        hole.id
+                    )
+                    .into());
+                }
+            }
+        }
+        Ok(HoleSet { holes })
+    }
+
+    /// Load and validate a hole set from a JSON file (see [`Hole::load_from_json`])
+    pub fn load_from_json(path: &str) -> Result<Self, Box<dyn Error>> {
+        let specs: Vec<HoleSpec> = serde_json::from_reader(BufReader::new(File::open(path)?))?;
+        HoleSet::try_from(specs)
+    }
+
+    /// Get hole by ID
+    pub fn get_hole_by_id(&self, id: u8) -> Option<&Hole> {
+        self.holes.iter().find(|hole| hole.id == id)
+    }
+
+    /// Get all holes for a specific category
+    pub fn get_holes_by_category(&self, category: ClubCategory) -> Vec<&Hole> {
+        self.holes.iter().filter(|h| h.category == category).collect()
+    }
+
+    /// Position of a hole id in the set's order
+    pub fn index_of(&self, id: u8) -> Option<usize> {
+        self.holes.iter().position(|hole| hole.id == id)
+    }
+
+    /// Holes in the set, in file order
+    pub fn holes(&self) -> &[Hole] {
+        &self.holes
+    }
+
+    /// Iterate over the holes in file order
+    pub fn iter(&self) -> std::slice::Iter<'_, Hole> {
+        self.holes.iter()
+    }
+
+    /// Number of holes
+    pub fn len(&self) -> usize {
+        self.holes.len()
+    }
+
+    /// Always false: a validated set has at least one hole
+    pub fn is_empty(&self) -> bool {
+        self.holes.is_empty()
+    }
+}
+
+impl Default for HoleSet {
+    fn default() -> Self {
+        HoleSet::standard()
+    }
+}
+
+impl TryFrom<Vec<HoleSpec>> for HoleSet {
+    type Error = Box<dyn Error>;
+
+    fn try_from(specs: Vec<HoleSpec>) -> Result<Self, Self::Error> {
+        HoleSet::new(
+            specs
+                .into_iter()
+                .map(|spec| Hole {
+                    payout_model: spec.payout_model,
+                    ..Hole::new(spec.id, spec.distance_yds, spec.d_max_ft, spec.rtp, spec.k)
+                })
+                .collect(),
+        )
+    }
+}
+
+impl From<HoleSet> for Vec<HoleSpec> {
+    fn from(set: HoleSet) -> Self {
+        set.holes
+            .into_iter()
+            .map(|hole| HoleSpec {
+                id: hole.id,
+                distance_yds: hole.distance_yds,
+                d_max_ft: hole.d_max_ft,
+                rtp: hole.rtp,
+                k: hole.k,
+                payout_model: hole.payout_model,
+            })
+            .collect()
+    }
+}
+
+/// Miss distance in feet that counts as an ace (hole-in-one)
+pub const ACE_DISTANCE_FT: f64 = 0.1;
+
+/// Progressive jackpot pool, fed by a share of every wager and won on an ace
+///
+/// Contributions come out of the house's hold, so an enabled jackpot raises
+/// the long-run player RTP by `contribution_rate`.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub struct JackpotState {
+    /// Current pool in dollars
+    pub pool: f64,
+    /// Fraction of each wager added to the pool (0.0 disables contributions)
+    pub contribution_rate: f64,
+}
+
+impl JackpotState {
+    /// Add a wager's contribution to the pool
+    pub fn contribute(&mut self, wager: f64) {
+        self.pool += self.contribution_rate * wager;
+    }
+
+    /// Settle a shot: an ace empties the pool and returns the amount won,
+    /// any other shot wins nothing
+    pub fn settle(&mut self, miss_distance_ft: f64) -> f64 {
+        if miss_distance_ft < ACE_DISTANCE_FT {
+            std::mem::take(&mut self.pool)
+        } else {
+            0.0
+        }
+    }
 }
 
 /// The 8 official hole configurations from the business plan
@@ -157,6 +549,7 @@ pub const HOLE_CONFIGURATIONS: [Hole; 8] = [
         rtp: 0.85,
         k: 5.0,
         category: ClubCategory::Wedge,
+        payout_model: PayoutModel::Continuous,
     },
     Hole {
         id: 2,
@@ -165,6 +558,7 @@ pub const HOLE_CONFIGURATIONS: [Hole; 8] = [
         rtp: 0.85,
         k: 5.0,
         category: ClubCategory::Wedge,
+        payout_model: PayoutModel::Continuous,
     },
     Hole {
         id: 3,
@@ -173,6 +567,7 @@ pub const HOLE_CONFIGURATIONS: [Hole; 8] = [
         rtp: 0.85,
         k: 5.5,
         category: ClubCategory::Wedge,
+        payout_model: PayoutModel::Continuous,
     },
     // Mid holes (MidIron category)
     Hole {
@@ -182,6 +577,7 @@ pub const HOLE_CONFIGURATIONS: [Hole; 8] = [
         rtp: 0.85,
         k: 6.0,
         category: ClubCategory::MidIron,
+        payout_model: PayoutModel::Continuous,
     },
     Hole {
         id: 5,
@@ -190,6 +586,7 @@ pub const HOLE_CONFIGURATIONS: [Hole; 8] = [
         rtp: 0.85,
         k: 6.0,
         category: ClubCategory::MidIron,
+        payout_model: PayoutModel::Continuous,
     },
     // Long holes (LongIron category)
     Hole {
@@ -199,6 +596,7 @@ pub const HOLE_CONFIGURATIONS: [Hole; 8] = [
         rtp: 0.85,
         k: 6.5,
         category: ClubCategory::LongIron,
+        payout_model: PayoutModel::Continuous,
     },
     Hole {
         id: 7,
@@ -207,6 +605,7 @@ pub const HOLE_CONFIGURATIONS: [Hole; 8] = [
         rtp: 0.85,
         k: 6.5,
         category: ClubCategory::LongIron,
+        payout_model: PayoutModel::Continuous,
     },
     Hole {
         id: 8,
@@ -215,12 +614,13 @@ pub const HOLE_CONFIGURATIONS: [Hole; 8] = [
         rtp: 0.85,
         k: 6.5,
         category: ClubCategory::LongIron,
+        payout_model: PayoutModel::Continuous,
     },
 ];
 
 /// Get hole by ID (1-8)
 pub fn get_hole_by_id(id: u8) -> Option<&'static Hole> {
-    if id < 1 || id > 8 {
+    if !(1..=8).contains(&id) {
         return None;
     }
     Some(&HOLE_CONFIGURATIONS[(id - 1) as usize])
@@ -234,6 +634,96 @@ pub fn get_holes_by_category(category: ClubCategory) -> Vec<&'static Hole> {
         .collect()
 }
 
+/// Range of steepness values `solve_k_for_rtp` searches
+pub const K_SEARCH_RANGE: (f64, f64) = (0.5, 20.0);
+
+/// Steepness `k` that gives a new hole its target RTP for a reference player
+///
+/// P_max·integral equals the RTP for every k once P_max is derived from it,
+/// so the solve fixes P_max instead: the new hole pays the reference player
+/// (dispersion `reference_sigma`) the same top multiplier as the standard
+/// table does at that distance ([`Hole::interpolate`]). Bisection over
+/// `K_SEARCH_RANGE` then finds the k whose payout integral
+/// ([`Hole::expected_payout_fraction`] for the given fat tail) times that
+/// P_max equals `target_rtp`. This inverts what `calculate_p_max` does.
+///
+/// A target outside what the range can reach returns the nearer bound.
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::models::hole::solve_k_for_rtp;
+///
+/// // A tighter 150-yd hole needs a shallower curve to return the same RTP
+/// let k = solve_k_for_rtp(150, 40.0, 0.85, 24.0, 0.02, 3.0);
+/// assert!(k < 6.0);
+/// ```
+pub fn solve_k_for_rtp(
+    distance_yds: u16,
+    d_max_ft: f64,
+    target_rtp: f64,
+    reference_sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+) -> f64 {
+    let payout_integral = |hole: &Hole| hole.expected_payout_fraction(reference_sigma, fat_tail_prob, fat_tail_mult);
+    let standard = Hole::interpolate(distance_yds);
+    let p_max = standard.rtp / payout_integral(&standard);
+
+    // The integral falls as k rises, so the implied RTP is decreasing in k
+    let (mut lo, mut hi) = K_SEARCH_RANGE;
+    for _ in 0..60 {
+        let mid = 0.5 * (lo + hi);
+        let candidate = Hole::new(standard.id, distance_yds, d_max_ft, target_rtp, mid);
+        if p_max * payout_integral(&candidate) > target_rtp {
+            lo = mid;
+        } else {
+            hi = mid;
+        }
+        if hi - lo < 1e-9 {
+            break;
+        }
+    }
+    0.5 * (lo + hi)
+}
+
+/// Monotone cubic (PCHIP) interpolation through (xs, ys), evaluated at x
+///
+/// `xs` must be strictly increasing with at least two points, and x within
+/// [xs[0], xs[n-1]]. Interior tangents are the weighted harmonic mean of the
+/// neighbouring secant slopes (zero at a local extremum or a flat segment),
+/// so the curve never overshoots the data between nodes.
+fn pchip_interpolate(xs: &[f64], ys: &[f64], x: f64) -> f64 {
+    let n = xs.len();
+    let widths: Vec<f64> = xs.windows(2).map(|w| w[1] - w[0]).collect();
+    let slopes: Vec<f64> = (0..n - 1).map(|j| (ys[j + 1] - ys[j]) / widths[j]).collect();
+
+    let tangent = |i: usize| {
+        if i == 0 {
+            return slopes[0];
+        }
+        if i == n - 1 {
+            return slopes[n - 2];
+        }
+        let (before, after) = (slopes[i - 1], slopes[i]);
+        if before * after <= 0.0 {
+            return 0.0;
+        }
+        let w1 = 2.0 * widths[i] + widths[i - 1];
+        let w2 = widths[i] + 2.0 * widths[i - 1];
+        (w1 + w2) / (w1 / before + w2 / after)
+    };
+
+    let i = xs.iter().rposition(|&xi| xi <= x).unwrap_or(0).min(n - 2);
+    let h = widths[i];
+    let t = (x - xs[i]) / h;
+    let (t2, t3) = (t * t, t * t * t);
+
+    (2.0 * t3 - 3.0 * t2 + 1.0) * ys[i]
+        + (t3 - 2.0 * t2 + t) * h * tangent(i)
+        + (-2.0 * t3 + 3.0 * t2) * ys[i + 1]
+        + (t3 - t2) * h * tangent(i + 1)
+}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -310,6 +800,26 @@ mod tests {
         assert!(payout_10 > payout_20);
     }
 
+    #[test]
+    fn test_payout_curve_decreases_to_zero_at_d_max() {
+        let hole = Hole::new(4, 150, 47.58, 0.88, 6.0);
+        let curve = hole.payout_curve(10.0, 101);
+
+        assert_eq!(curve.len(), 101);
+        assert_eq!(curve[0], (0.0, 10.0));
+        for pair in curve.windows(2) {
+            assert!(pair[1].0 > pair[0].0);
+            assert!(pair[1].1 < pair[0].1);
+        }
+        assert_eq!(curve[100], (47.58, 0.0));
+
+        // The zone ends where the curve crosses 1×
+        let (start, end) = hole.breakeven_zone(10.0);
+        assert_eq!(start, 0.0);
+        assert!((hole.calculate_payout(end, 10.0) - 1.0).abs() < 1e-9);
+        assert_eq!(hole.breakeven_zone(0.8), (0.0, 0.0));
+    }
+
     #[test]
     fn test_breakeven_radius() {
         let hole = Hole::new(4, 150, 47.58, 0.88, 6.0);
@@ -373,4 +883,142 @@ mod tests {
         let h8 = get_hole_by_id(8).unwrap();
         assert_eq!(h8.rtp, 0.85);
     }
+
+    #[test]
+    fn test_load_hole_set_from_json() {
+        let path = std::env::temp_dir().join(format!("continuum_holes_{}.json", std::process::id()));
+        std::fs::write(
+            &path,
+            r#"[
+                {"id": 2, "distance_yds": 200, "d_max_ft": 40.0, "rtp": 0.88, "k": 1.2},
+                {"id": 1, "distance_yds": 90, "d_max_ft": 18.0, "rtp": 0.85, "k": 1.0}
+            ]"#,
+        )
+        .unwrap();
+
+        let holes = Hole::load_from_json(path.to_str().unwrap()).unwrap();
+        std::fs::remove_file(&path).unwrap();
+
+        assert_eq!(holes.len(), 2);
+        assert_eq!(holes[0].id, 2);
+        assert_eq!(holes[0].category, ClubCategory::LongIron);
+        assert_eq!(holes[1].category, ClubCategory::Wedge);
+
+        let set = HoleSet::new(holes).unwrap();
+        assert_eq!(set.get_hole_by_id(1).unwrap().distance_yds, 90);
+        assert_eq!(set.index_of(1), Some(1));
+        assert!(set.get_hole_by_id(3).is_none());
+
+        // Round-trips through serde as a list of specs
+        let json = serde_json::to_string(&set).unwrap();
+        let parsed: HoleSet = serde_json::from_str(&json).unwrap();
+        let ids = |set: &HoleSet| set.iter().map(|h| (h.id, h.distance_yds)).collect::<Vec<_>>();
+        assert_eq!(ids(&parsed), ids(&set));
+    }
+
+    #[test]
+    fn test_hole_set_rejects_invalid_ids() {
+        let hole = |id| Hole::new(id, 100, 20.0, 0.85, 1.0);
+
+        assert!(HoleSet::new(vec![]).is_err());
+        assert!(HoleSet::new(vec![hole(1), hole(1)]).is_err());
+        assert!(HoleSet::new(vec![hole(1), hole(3)]).is_err());
+        assert!(HoleSet::new(vec![hole(0)]).is_err());
+        assert!(HoleSet::new(vec![Hole::new(1, 100, 20.0, 0.0, 1.0)]).is_err());
+        assert!(serde_json::from_str::<HoleSet>(
+            r#"[{"id": 5, "distance_yds": 100, "d_max_ft": 20.0, "rtp": 0.85, "k": 1.0}]"#
+        )
+        .is_err());
+
+        assert_eq!(HoleSet::new(vec![hole(2), hole(1)]).unwrap().len(), 2);
+        assert_eq!(HoleSet::standard().len(), HOLE_CONFIGURATIONS.len());
+    }
+
+    #[test]
+    fn test_interpolate_at_configured_distance() {
+        let h4 = get_hole_by_id(4).unwrap();
+        let hole = Hole::interpolate(150);
+
+        assert_eq!(hole.distance_yds, 150);
+        assert_eq!(hole.d_max_ft, h4.d_max_ft);
+        assert_eq!(hole.rtp, h4.rtp);
+        assert_eq!(hole.k, h4.k);
+        assert_eq!(hole.category, ClubCategory::MidIron);
+
+        for configured in HOLE_CONFIGURATIONS.iter() {
+            let hole = Hole::interpolate(configured.distance_yds);
+            assert_relative_eq!(hole.d_max_ft, configured.d_max_ft, epsilon = 1e-12);
+            assert_relative_eq!(hole.k, configured.k, epsilon = 1e-12);
+        }
+    }
+
+    #[test]
+    fn test_interpolate_between_and_beyond_holes() {
+        let hole = Hole::interpolate(140);
+        assert_eq!(hole.category, ClubCategory::MidIron);
+        assert!(hole.d_max_ft > 36.71 && hole.d_max_ft < 47.58, "{:?}", hole);
+        assert!(hole.k >= 5.5 && hole.k <= 6.0, "{:?}", hole);
+
+        // k is flat between H4 and H5, and stays flat
+        assert_relative_eq!(Hole::interpolate(160).k, 6.0, epsilon = 1e-12);
+
+        // d_max grows with distance
+        let d_max: Vec<f64> = (75..=250).map(|d| Hole::interpolate(d).d_max_ft).collect();
+        assert!(d_max.windows(2).all(|w| w[1] >= w[0]));
+
+        // Clamped outside the configured range, category from the requested distance
+        let short = Hole::interpolate(50);
+        assert_eq!(short.d_max_ft, 17.95);
+        assert_eq!(short.category, ClubCategory::Wedge);
+        let long = Hole::interpolate(300);
+        assert_eq!(long.d_max_ft, 101.14);
+        assert_eq!(long.distance_yds, 300);
+    }
+
+    #[test]
+    fn test_solve_k_for_rtp_recovers_h4() {
+        let h4 = get_hole_by_id(4).unwrap();
+        for sigma in [12.0, 24.4, 36.6] {
+            let k = solve_k_for_rtp(h4.distance_yds, h4.d_max_ft, h4.rtp, sigma, 0.02, 3.0);
+            assert_relative_eq!(k, 6.0, epsilon = 1e-6);
+        }
+
+        // A richer target needs a shallower curve
+        let generous = solve_k_for_rtp(h4.distance_yds, h4.d_max_ft, 0.95, 24.4, 0.02, 3.0);
+        assert!(generous < 6.0, "k = {}", generous);
+
+        // Unreachable targets clamp to the search range
+        let clamped = |target_rtp: f64| solve_k_for_rtp(150, 47.58, target_rtp, 24.4, 0.02, 3.0);
+        assert_relative_eq!(clamped(100.0), K_SEARCH_RANGE.0, epsilon = 1e-6);
+        assert_relative_eq!(clamped(0.0), K_SEARCH_RANGE.1, epsilon = 1e-6);
+    }
+
+    #[test]
+    fn test_solve_k_for_rtp_recovers_known_k() {
+        use crate::math::integration::simpsons_rule;
+
+        // Fat-tail mixture payout fraction on a fine grid, independent of the
+        // pricing helpers
+        let fraction = |d_max: f64, k: f64, sigma: f64, fat_tail_prob: f64, fat_tail_mult: f64| {
+            let integrand = |d: f64| {
+                let mixture = (1.0 - fat_tail_prob) * rayleigh_pdf(d, sigma)
+                    + fat_tail_prob * rayleigh_pdf(d, fat_tail_mult * sigma);
+                (1.0 - d / d_max).powf(k) * mixture
+            };
+            simpsons_rule(integrand, 0.0, d_max, 200_000)
+        };
+
+        // A 40 ft, k = 4 hole paying the reference player H4's top multiplier
+        // returns this RTP; the solve has to find k = 4 again, for the default
+        // fat tail and a heavier one
+        let h4 = get_hole_by_id(4).unwrap();
+        for (fat_tail_prob, fat_tail_mult) in [(0.02, 3.0), (0.1, 4.0)] {
+            for sigma in [12.0, 24.4, 36.6] {
+                let p_max = h4.rtp / fraction(h4.d_max_ft, h4.k, sigma, fat_tail_prob, fat_tail_mult);
+                let target_rtp = p_max * fraction(40.0, 4.0, sigma, fat_tail_prob, fat_tail_mult);
+                let k = solve_k_for_rtp(h4.distance_yds, 40.0, target_rtp, sigma, fat_tail_prob, fat_tail_mult);
+                assert_relative_eq!(k, 4.0, epsilon = 1e-4);
+            }
+        }
+    }
 }
@@ -5,9 +5,18 @@
 
 use std::collections::HashMap;
 use serde::{Deserialize, Serialize};
-use crate::math::kalman::{KalmanState, debias_rayleigh_measurement, weighted_average_measurement, measurement_variance};
-use crate::math::integration::trapezoidal_rule;
-use crate::models::hole::{Hole, ClubCategory};
+use crate::math::kalman::{
+    KalmanState, OUTLIER_Z_THRESHOLD, debias_rayleigh_measurement, measurement_variance, wager_cv,
+    weighted_average_measurement, weighted_measurement_variance,
+};
+use crate::math::distributions::{rayleigh_variance, MissModel};
+use crate::math::integration::IntegrationMethod;
+use crate::models::hole::{get_hole_by_id, Hole, ClubCategory};
+use crate::models::shot::DEFAULT_HIGH_STAKES_MULTIPLIER;
+use std::f64::consts::PI;
+
+/// A logged shot: (miss_distance_ft, wager, hole_id)
+pub type LoggedShot = (f64, f64, u8);
 
 /// A player with dynamic skill tracking
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -22,19 +31,136 @@ pub struct Player {
     pub lifetime_wagers: Vec<f64>,
     /// Lifetime total wagered (for average calculation)
     pub lifetime_total_wagered: f64,
+    /// Fraction of each category's estimate change passed on to the other
+    /// categories (default: 0.0, independent filters)
+    ///
+    /// See [`Player::update_skill`].
+    #[serde(default)]
+    pub skill_transfer: f64,
+    /// Distribution P_max assumes the player's misses follow (default:
+    /// Rayleigh)
+    ///
+    /// Change it with [`Player::set_miss_model`] so cached P_max values are
+    /// dropped.
+    #[serde(default)]
+    pub miss_model: MissModel,
 }
 
 /// Skill profile for a specific club category
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub struct SkillProfile {
     /// Kalman filter for adaptive skill tracking
+    ///
+    /// Tracks sigma at the category's reference distance; see
+    /// [`dispersion_scale`] for how each hole maps to and from it.
     pub kalman_filter: KalmanState,
+    /// The player's actual dispersion at the reference distance, if it differs
+    /// from the house's view
+    ///
+    /// When set, shots are simulated from this value while P_max still comes
+    /// from the Kalman estimate; the odds engine never sees it, so the gap
+    /// between the two is the house's estimation error. `None` (the default)
+    /// simulates shots from the estimate itself, i.e. the player always plays
+    /// exactly as the house believes.
+    #[serde(default)]
+    pub true_sigma: Option<f64>,
+    /// Average miss across the target line in feet at the reference distance
+    /// (positive = right, e.g. a slice; default: 0.0)
+    ///
+    /// With a non-zero bias, sessions draw shots in 2D (see
+    /// [`simulate_shot_2d`](crate::models::shot::simulate_shot_2d)).
+    #[serde(default)]
+    pub bias_x: f64,
+    /// Average miss along the target line in feet at the reference distance
+    /// (positive = long; default: 0.0)
+    #[serde(default)]
+    pub bias_y: f64,
     /// History of P_max values (for analysis)
     pub p_max_history: Vec<f64>,
+    /// Sigma estimate at the reference distance after each P_max update,
+    /// parallel to `p_max_history`
+    #[serde(default)]
+    pub sigma_history: Vec<f64>,
+    /// Skill confidence (0-100%) after each P_max update, parallel to
+    /// `p_max_history`
+    #[serde(default)]
+    pub confidence_history: Vec<f64>,
+    /// Current rate-limited P_max per hole id in this category
+    ///
+    /// P_max depends on each hole's d_max and k, so the category's last history
+    /// entry only applies to the hole that triggered that update.
+    #[serde(default)]
+    pub p_max_by_hole: HashMap<u8, f64>,
+    /// Last freshly integrated (sigma, P_max) per hole id in this category
+    ///
+    /// Reused while the hole's sigma stays within `P_MAX_CACHE_EPSILON` of
+    /// the cached one; cleared by every skill update. Not serialized.
+    #[serde(skip)]
+    pub p_max_cache: HashMap<u8, (f64, f64)>,
     /// Current batch of shots (for batched Kalman updates)
     pub shot_batch: Vec<ShotRecord>,
     /// Maximum batch size before triggering update
     pub batch_size: usize,
+    /// Multiple of the batch's average wager that makes a shot high-stakes
+    /// (default: `DEFAULT_HIGH_STAKES_MULTIPLIER`, 10×)
+    ///
+    /// Lower values flush the batch on smaller raises, so the filter reacts
+    /// to big bets sooner; see [`Player::is_high_stakes_shot`].
+    #[serde(default = "default_high_stakes_multiplier")]
+    pub high_stakes_multiplier: f64,
+    /// Down-weight shots far from the current estimate (default: false)
+    ///
+    /// When set, each shot whose innovation z-score exceeds
+    /// `OUTLIER_Z_THRESHOLD` has its measurement noise inflated, reducing its
+    /// weight in the batch measurement; see [`Player::update_skill`].
+    #[serde(default)]
+    pub reject_outliers: bool,
+    /// How `update_skill` folds a batch into `kalman_filter` (default: Kalman)
+    #[serde(default)]
+    pub estimator: EstimatorKind,
+    /// Restart the estimator when the player improves (default: false)
+    ///
+    /// `update_skill` runs a one-sided CUSUM over the standardized innovation
+    /// of each batch, accumulating batches that land tighter than the
+    /// estimate; once it exceeds `DRIFT_CUSUM_THRESHOLD` the filter is reset
+    /// at the batch's measurement so it re-learns quickly. A player getting
+    /// worse is left to the filter: a reset there would let a sandbagger jump
+    /// the estimate (and P_max) up in a few batches.
+    #[serde(default)]
+    pub auto_reset_on_drift: bool,
+    /// Cumulative improvement (downward innovation) seen by the drift detector
+    #[serde(default)]
+    pub drift_cusum: f64,
+    /// Number of drift resets over the profile's lifetime
+    #[serde(default)]
+    pub drift_resets: usize,
+}
+
+fn default_high_stakes_multiplier() -> f64 {
+    DEFAULT_HIGH_STAKES_MULTIPLIER
+}
+
+/// Standardized innovation per batch the drift CUSUM absorbs as noise
+pub const DRIFT_CUSUM_SLACK: f64 = 0.5;
+
+/// Drift CUSUM level at which the skill estimator is reset
+pub const DRIFT_CUSUM_THRESHOLD: f64 = 5.0;
+
+/// Skill estimator behind a `SkillProfile`
+///
+/// Both keep their estimate and uncertainty in the profile's `KalmanState`,
+/// so P_max, confidence and rate limiting treat them alike.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum EstimatorKind {
+    /// Kalman filter with wager-adaptive process noise
+    #[default]
+    Kalman,
+    /// Exponentially weighted moving average of debiased batch measurements
+    ///
+    /// Each update moves the estimate `alpha` of the way to the measurement
+    /// (see [`KalmanState::update_ewma`]); a simpler baseline to compare the
+    /// Kalman tuning against.
+    Ewma { alpha: f64 },
 }
 
 /// Record of a single shot for batch processing
@@ -66,24 +192,32 @@ impl Player {
     pub fn new(id: String, handicap: u8) -> Self {
         let mut skill_profiles = HashMap::new();
 
-        // Initialize skill profiles for each category
-        // Use representative distances for each category
-        let categories = [
-            (ClubCategory::Wedge, 100),     // 75-125 yds
-            (ClubCategory::MidIron, 162),   // 150-175 yds
-            (ClubCategory::LongIron, 225),  // 200-250 yds
-        ];
+        // Initialize skill profiles for each category at its representative distance
+        let categories = [ClubCategory::Wedge, ClubCategory::MidIron, ClubCategory::LongIron];
 
-        for (category, distance) in categories.iter() {
-            let initial_sigma = calculate_initial_dispersion(handicap, *distance);
+        for category in categories.iter() {
+            let initial_sigma = calculate_initial_dispersion(handicap, category.reference_distance_yds());
 
             let kalman_filter = KalmanState::new(initial_sigma, 1.0);
 
             skill_profiles.insert(*category, SkillProfile {
                 kalman_filter,
+                true_sigma: None,
+                bias_x: 0.0,
+                bias_y: 0.0,
                 p_max_history: Vec::new(),
+                sigma_history: Vec::new(),
+                confidence_history: Vec::new(),
+                p_max_by_hole: HashMap::new(),
+                p_max_cache: HashMap::new(),
                 shot_batch: Vec::new(),
                 batch_size: 5, // Default batch size
+                high_stakes_multiplier: DEFAULT_HIGH_STAKES_MULTIPLIER,
+                reject_outliers: false,
+                estimator: EstimatorKind::Kalman,
+                auto_reset_on_drift: false,
+                drift_cusum: 0.0,
+                drift_resets: 0,
             });
         }
 
@@ -93,9 +227,109 @@ impl Player {
             skill_profiles,
             lifetime_wagers: Vec::new(),
             lifetime_total_wagered: 0.0,
+            skill_transfer: 0.0,
+            miss_model: MissModel::Rayleigh,
         }
     }
 
+    /// Create a player whose skill profiles are fitted to a shot log
+    ///
+    /// Each (miss_distance_ft, wager, hole_id) shot is scaled to its
+    /// category's reference distance and debiased for Rayleigh. A category's
+    /// Kalman estimate is the mean of its debiased misses and its error
+    /// covariance is the variance of that mean (sample variance / n), so the
+    /// filter starts as confident as the data allows. Shots on unknown hole
+    /// ids are skipped.
+    ///
+    /// The handicap is the one whose `calculate_initial_dispersion` best
+    /// fits the logged categories; categories without shots start from it
+    /// with no confidence, as in [`Player::new`].
+    ///
+    /// # Example
+    /// ```
+    /// use continuum_golf_simulator::models::player::Player;
+    ///
+    /// let log = [(12.0, 10.0, 4), (20.0, 10.0, 4), (16.0, 5.0, 5)];
+    /// let player = Player::from_shot_log("imported".to_string(), &log);
+    /// assert!(player.handicap <= 30);
+    /// ```
+    pub fn from_shot_log(id: String, shots: &[LoggedShot]) -> Player {
+        let mut measurements: HashMap<ClubCategory, Vec<f64>> = HashMap::new();
+        for &(miss_distance, _, hole_id) in shots {
+            if let Some(hole) = get_hole_by_id(hole_id) {
+                measurements
+                    .entry(hole.category)
+                    .or_default()
+                    .push(debias_rayleigh_measurement(miss_distance / dispersion_scale(hole)));
+            }
+        }
+
+        let fitted: HashMap<ClubCategory, (f64, f64)> = measurements
+            .iter()
+            .map(|(category, values)| {
+                let n = values.len() as f64;
+                let mean = values.iter().sum::<f64>() / n;
+                // A single shot has no spread; use the Rayleigh spread at the mean
+                let variance = if values.len() > 1 {
+                    values.iter().map(|v| (v - mean).powi(2)).sum::<f64>() / (n - 1.0)
+                } else {
+                    rayleigh_variance(mean) / (PI / 2.0)
+                };
+                (*category, (mean, variance / n))
+            })
+            .collect();
+
+        // Handicap whose initial dispersion is closest (in log terms) to the data
+        let handicap = (0..=30u8)
+            .min_by(|&a, &b| {
+                let misfit = |handicap: u8| {
+                    fitted
+                        .iter()
+                        .map(|(category, (sigma, _))| {
+                            (sigma / calculate_initial_dispersion(handicap, category.reference_distance_yds()))
+                                .ln()
+                                .powi(2)
+                        })
+                        .sum::<f64>()
+                };
+                misfit(a).total_cmp(&misfit(b))
+            })
+            .unwrap_or(15);
+
+        let mut player = Player::new(id, handicap);
+        for (category, (sigma, covariance)) in fitted {
+            if let Some(skill) = player.skill_profiles.get_mut(&category) {
+                skill.kalman_filter.estimate = sigma;
+                skill.kalman_filter.initial_estimate = sigma;
+                skill.kalman_filter.error_covariance = covariance;
+            }
+        }
+        player
+    }
+
+    /// Check that the skill estimates grow with club distance
+    ///
+    /// Dispersion scales with distance, so the Wedge, MidIron and LongIron
+    /// Kalman estimates must be strictly increasing. An inverted pair points
+    /// to a data error or manipulation, e.g. after [`Player::from_shot_log`].
+    ///
+    /// # Errors
+    /// Names the first pair of neighbouring categories out of order
+    pub fn validate_skill_consistency(&self) -> Result<(), String> {
+        let categories = [ClubCategory::Wedge, ClubCategory::MidIron, ClubCategory::LongIron];
+        for pair in categories.windows(2) {
+            let shorter = self.skill_profiles[&pair[0]].kalman_filter.estimate;
+            let longer = self.skill_profiles[&pair[1]].kalman_filter.estimate;
+            if longer <= shorter {
+                return Err(format!(
+                    "{:?} sigma {:.2} ft is not above {:?} sigma {:.2} ft",
+                    pair[1], longer, pair[0], shorter
+                ));
+            }
+        }
+        Ok(())
+    }
+
     /// Get the skill profile for a specific hole
     ///
     /// # Arguments
@@ -126,7 +360,7 @@ impl Player {
     /// With p_fat = 0.02 (2% chance of fat-tail shot)
     ///
     /// # Security
-    /// If P_max history exists, returns the last value (which has rate limiting applied).
+    /// After a skill update, returns the rate-limited value stored for this hole.
     /// This prevents rapid P_max inflation from sandbagging attacks.
     ///
     /// # Arguments
@@ -149,77 +383,132 @@ impl Player {
     pub fn calculate_p_max(&self, hole: &Hole) -> f64 {
         let skill = self.get_skill_for_hole(hole);
 
-        // SECURITY FIX: Use rate-limited P_max from history if available
-        if !skill.p_max_history.is_empty() {
-            return *skill.p_max_history.last().unwrap();
+        // SECURITY FIX: Use rate-limited P_max if an update has set one
+        if let Some(p_max) = skill.p_max_by_hole.get(&hole.id) {
+            return *p_max;
         }
 
         // Otherwise calculate fresh P_max
         self.calculate_p_max_fresh(hole)
     }
 
-    /// Calculate fresh P_max without rate limiting (internal use only)
-    fn calculate_p_max_fresh(&self, hole: &Hole) -> f64 {
+    /// Same as [`Player::calculate_p_max`], remembering fresh values
+    ///
+    /// A freshly integrated P_max is stored in the category's `p_max_cache`
+    /// and reused until the estimate moves, so repeated shots between skill
+    /// updates integrate once instead of every shot.
+    pub fn calculate_p_max_cached(&mut self, hole: &Hole) -> f64 {
+        let sigma = self.get_current_sigma(hole);
         let skill = self.get_skill_for_hole(hole);
-        let sigma = skill.kalman_filter.estimate;
-
-        // Calculate expected payout using numerical integration
-        // Must account for fat-tail distribution (2% chance of 3x sigma)
-        let d_max = hole.d_max_ft;
-        let k = hole.k;
-        let fat_tail_prob = 0.02;
-        let fat_tail_mult = 3.0;
-
-        // Define integrand for normal shots: payout_function(d) * rayleigh_pdf(d, sigma)
-        let integrand_normal = |d: f64| -> f64 {
-            if d > d_max {
-                return 0.0;
-            }
+        if let Some(p_max) = skill.p_max_by_hole.get(&hole.id) {
+            return *p_max;
+        }
+        if let Some(p_max) = cached_p_max(skill, hole, sigma) {
+            return p_max;
+        }
 
-            // Payout function: (1 - d/d_max)^k
-            let payout_factor = (1.0 - d / d_max).powf(k);
+        let p_max = p_max_for_sigma(hole, sigma, self.miss_model);
+        self.get_skill_for_hole_mut(hole).p_max_cache.insert(hole.id, (sigma, p_max));
+        p_max
+    }
 
-            // Rayleigh PDF: (d/σ²) * exp(-d²/(2σ²))
-            let rayleigh_pdf = (d / (sigma * sigma)) * (-d * d / (2.0 * sigma * sigma)).exp();
+    /// Same as [`Player::calculate_p_max`], integrating with `method`
+    ///
+    /// `calculate_p_max` uses Simpson's rule. Only a fresh P_max depends on
+    /// the method; a rate-limited value stored by a skill update is returned
+    /// as is.
+    pub fn calculate_p_max_with(&self, hole: &Hole, method: IntegrationMethod) -> f64 {
+        let skill = self.get_skill_for_hole(hole);
+        if let Some(p_max) = skill.p_max_by_hole.get(&hole.id) {
+            return *p_max;
+        }
+        p_max_for_sigma_with(hole, self.get_current_sigma(hole), self.miss_model, method, P_MAX_SUBDIVISIONS)
+    }
 
-            payout_factor * rayleigh_pdf
-        };
+    /// Calculate fresh P_max without rate limiting (internal use only)
+    ///
+    /// Served from the cache when a still-valid value is there.
+    fn calculate_p_max_fresh(&self, hole: &Hole) -> f64 {
+        let sigma = self.get_current_sigma(hole);
+        cached_p_max(self.get_skill_for_hole(hole), hole, sigma)
+            .unwrap_or_else(|| p_max_for_sigma(hole, sigma, self.miss_model))
+    }
 
-        // Define integrand for fat-tail shots: payout_function(d) * rayleigh_pdf(d, 3*sigma)
-        let sigma_fat = sigma * fat_tail_mult;
-        let integrand_fat = |d: f64| -> f64 {
-            if d > d_max {
-                return 0.0;
-            }
+    /// P_max ramped from a conservative default to the skill-based value
+    ///
+    /// # Formula
+    /// p_max_effective = conf * p_max_skill + (1 - conf) * p_max_conservative
+    ///
+    /// `conf` is [`Player::get_skill_confidence`] as a fraction and
+    /// `p_max_skill` is [`Player::calculate_p_max`]. `p_max_conservative`
+    /// prices the hole for a [`CONSERVATIVE_P_MAX_HANDICAP`] player, so a new
+    /// player starts on scratch odds and earns their own as the filter
+    /// converges.
+    pub fn calculate_p_max_confidence_weighted(&self, hole: &Hole) -> f64 {
+        let confidence = self.get_skill_confidence(hole) / 100.0;
+        let conservative_sigma = calculate_initial_dispersion(CONSERVATIVE_P_MAX_HANDICAP, hole.distance_yds);
+        let p_max_conservative = p_max_for_sigma(hole, conservative_sigma, self.miss_model);
+
+        confidence * self.calculate_p_max(hole) + (1.0 - confidence) * p_max_conservative
+    }
 
-            // Payout function: (1 - d/d_max)^k
-            let payout_factor = (1.0 - d / d_max).powf(k);
+    /// Probability that a shot pays at least `threshold` times the wager
+    ///
+    /// Inverts the payout curve to find the largest miss distance that still
+    /// earns the threshold multiplier, then evaluates the fat-tail mixture CDF
+    /// of the player's `miss_model` at that distance using the current sigma
+    /// estimate and P_max.
+    ///
+    /// # Formula
+    /// d_X = d_max * (1 - (X / P_max)^(1/k))
+    /// P(mult >= X) = (1-p_fat) * F(d_X | σ) + p_fat * F(d_X | 3σ)
+    ///
+    /// For a ring hole it is the total probability of the rings paying at
+    /// least X.
+    ///
+    /// # Arguments
+    /// * `hole` - The hole configuration
+    /// * `threshold` - Payout multiplier threshold (e.g. 5.0 for 5×)
+    ///
+    /// # Returns
+    /// Probability in [0, 1]
+    pub fn prob_multiplier_at_least(&self, hole: &Hole, threshold: f64) -> f64 {
+        if threshold <= 0.0 {
+            return 1.0;
+        }
 
-            // Rayleigh PDF with fat-tail sigma: (d/(3σ)²) * exp(-d²/(2(3σ)²))
-            let rayleigh_pdf = (d / (sigma_fat * sigma_fat)) * (-d * d / (2.0 * sigma_fat * sigma_fat)).exp();
+        let p_max = self.calculate_p_max(hole);
+        if threshold > p_max {
+            return 0.0;
+        }
 
-            payout_factor * rayleigh_pdf
+        let sigma = self.get_current_sigma(hole);
+        let fat_tail_prob = 0.02;
+        let fat_tail_mult = 3.0;
+        let model = self.miss_model;
+        let cdf = |d: f64| {
+            (1.0 - fat_tail_prob) * model.cdf(d, sigma) + fat_tail_prob * model.cdf(d, sigma * fat_tail_mult)
         };
 
-        // Integrate from 0 to d_max (use higher bound for numerical stability)
-        // Use the fat-tail sigma for upper bound since it has longer tail
-        let upper_bound = (d_max * 1.5).max(sigma_fat * 5.0);
-        let n_subdivisions = 2000; // High accuracy
-
-        let expected_payout_normal = trapezoidal_rule(integrand_normal, 0.0, upper_bound, n_subdivisions);
-        let expected_payout_fat = trapezoidal_rule(integrand_fat, 0.0, upper_bound, n_subdivisions);
-
-        // Weighted average: (1 - p_fat) * E[normal] + p_fat * E[fat]
-        let expected_payout = (1.0 - fat_tail_prob) * expected_payout_normal + fat_tail_prob * expected_payout_fat;
+        if !hole.payout_model.is_continuous() {
+            return hole
+                .payout_model
+                .ring_probabilities(cdf)
+                .iter()
+                .filter(|(fraction, _)| p_max * fraction >= threshold)
+                .map(|(_, probability)| probability)
+                .sum();
+        }
 
-        // P_max = RTP / expected_payout
-        // Add small epsilon to prevent division by zero
-        let epsilon = 1e-10;
-        hole.rtp / (expected_payout + epsilon)
+        let d_threshold = hole.d_max_ft * (1.0 - (threshold / p_max).powf(1.0 / hole.k));
+        cdf(d_threshold)
     }
 
     /// Add a shot to the batch for a specific hole
     ///
+    /// The miss is scaled to the category's reference distance (see
+    /// [`dispersion_scale`]) so holes of different lengths can share one batch.
+    ///
     /// # Arguments
     /// * `hole` - The hole that was played
     /// * `miss_distance` - Miss distance in feet
@@ -228,17 +517,19 @@ impl Player {
     /// # Returns
     /// True if the batch is full and should be processed
     pub fn add_shot_to_batch(&mut self, hole: &Hole, miss_distance: f64, wager: f64) -> bool {
+        let scale = dispersion_scale(hole);
         let skill = self.get_skill_for_hole_mut(hole);
 
         skill.shot_batch.push(ShotRecord {
-            miss_distance,
+            miss_distance: miss_distance / scale,
             wager,
         });
 
         skill.shot_batch.len() >= skill.batch_size
     }
 
-    /// Check if a new shot qualifies as high-stakes (≥10× average wager)
+    /// Check if a new shot qualifies as high-stakes (≥ the profile's
+    /// `high_stakes_multiplier` × average wager, 10× by default)
     ///
     /// # Arguments
     /// * `hole` - The hole being played
@@ -256,7 +547,7 @@ impl Player {
         let total_wagers: f64 = skill.shot_batch.iter().map(|s| s.wager).sum();
         let avg_wager = total_wagers / skill.shot_batch.len() as f64;
 
-        wager >= 10.0 * avg_wager
+        wager >= skill.high_stakes_multiplier * avg_wager
     }
 
     /// Update skill profile using Kalman filter with current batch
@@ -265,26 +556,45 @@ impl Player {
     ///
     /// # Arguments
     /// * `hole` - The hole that was played
-    /// * `p_max` - The P_max value used for these shots
     ///
     /// # Process
-    /// 1. Calculate wager-weighted average miss distance
-    /// 2. Debias for Rayleigh distribution
-    /// 3. Calculate batch variance for measurement noise
-    /// 4. Update Kalman filter
+    /// 1. Set measurement noise from the miss variance expected at the current estimate
+    /// 2. Calculate wager-weighted average miss distance (down-weighting
+    ///    outliers if the profile's `reject_outliers` is set)
+    /// 3. Debias for Rayleigh distribution
+    /// 4. Update Kalman filter, with process noise scaled by the batch's wager
+    ///    volatility (see [`KalmanState::update_with_adaptive_q`]), or the
+    ///    EWMA if the profile's `estimator` is [`EstimatorKind::Ewma`]
     /// 5. Store P_max in history
     /// 6. Clear shot batch
     ///
+    /// # Drift reset
+    /// With `auto_reset_on_drift` set, a batch that pushes the improvement
+    /// CUSUM past `DRIFT_CUSUM_THRESHOLD` resets the filter (P = 1000) at the
+    /// batch's measurement instead of step 4. The P_max rate limit still
+    /// applies to the jump.
+    ///
+    /// # Skill transfer
+    /// With `skill_transfer` = c > 0, the other categories' estimates move by
+    /// c times this category's relative change (σ ← σ × (1 + c × Δσ/σ)).
+    /// Dispersion is handicap × distance separable, so a relative change in
+    /// one category implies the same relative change at every distance.
+    ///
     /// # Security
     /// - Limits P_max changes to 20% per update to prevent sandbagging exploitation
     /// - Applies outlier detection to reduce impact of suspicious miss distances
-    pub fn update_skill(&mut self, hole: &Hole, p_max: f64) {
+    pub fn update_skill(&mut self, hole: &Hole) {
+        let miss_model = self.miss_model;
         let skill = self.get_skill_for_hole_mut(hole);
 
         if skill.shot_batch.is_empty() {
             return;
         }
 
+        // Wager volatility over the whole batch scales the process noise
+        let wagers: Vec<f64> = skill.shot_batch.iter().map(|s| s.wager).collect();
+        let batch_wager_cv = wager_cv(&wagers);
+
         // Extract miss distances and wagers
         let measurements: Vec<(f64, f64)> = skill.shot_batch.iter()
             .map(|s| (s.miss_distance, s.wager))
@@ -314,108 +624,89 @@ impl Player {
             filtered_measurements
         };
 
+        // Measurement noise (R) is the miss variance expected at the current
+        // estimate, Var[d] = σ²(4 - π)/2, scaled by how much the wager
+        // weighting widens the batch's spread, so R matches the wager-weighted
+        // measurement below. Taking R's level from the batch's own spread
+        // would correlate it with the measurement (a Rayleigh batch with a
+        // large mean also has a large spread), so long-miss batches would get
+        // less weight and the estimate would drift ~5% below the true sigma.
+        let expected_variance = rayleigh_variance(skill.kalman_filter.estimate);
+        let measurement_noise = expected_variance.max(50.0) * weighted_spread_ratio(&final_measurements);
+
+        // Outlier rejection: each shot is screened against the variance of a
+        // single debiased miss, σ²(4 - π)/π; a shot whose noise is inflated to
+        // R' counts R / R' as much in the weighted average
+        let final_measurements = if skill.reject_outliers {
+            let shot_noise = expected_variance / (std::f64::consts::PI / 2.0);
+            let free_play = final_measurements.iter().all(|(_, wager)| *wager == 0.0);
+            final_measurements
+                .iter()
+                .map(|&(miss, wager)| {
+                    let weight = if free_play { 1.0 } else { wager };
+                    let noise = skill.kalman_filter.outlier_adjusted_noise(
+                        debias_rayleigh_measurement(miss),
+                        shot_noise,
+                        OUTLIER_Z_THRESHOLD,
+                    );
+                    (miss, weight * shot_noise / noise)
+                })
+                .collect()
+        } else {
+            final_measurements
+        };
+
         // Calculate wager-weighted average
         let weighted_avg = weighted_average_measurement(&final_measurements);
 
         // Debias for Rayleigh distribution
         let unbiased_measurement = debias_rayleigh_measurement(weighted_avg);
 
-        // Calculate batch variance for dynamic measurement noise
-        let batch_variance = measurement_variance(&miss_distances);
-
-        // Measurement noise (R) is based on batch variance
-        // Higher variance = less trustworthy batch
-        let measurement_noise = batch_variance.max(50.0); // Minimum R = 50
-
         // Store previous estimate for P_max limiting
         let previous_sigma = skill.kalman_filter.estimate;
 
-        // Kalman filter update
-        skill.kalman_filter.predict();
-        skill.kalman_filter.update(unbiased_measurement, measurement_noise);
-
-        // Calculate fresh P_max based on new sigma
-        let fresh_p_max = {
-            // Temporarily calculate P_max with new sigma
-            let temp_sigma = skill.kalman_filter.estimate;
-            let hole_category = &hole.category;
-
-            // Calculate using the fresh method (bypass rate limiting for calculation)
-            let d_max = hole.d_max_ft;
-            let k = hole.k;
-            let fat_tail_prob = 0.02;
-            let fat_tail_mult = 3.0;
-
-            let integrand_normal = |d: f64| -> f64 {
-                if d > d_max {
-                    return 0.0;
-                }
-                let payout_factor = (1.0 - d / d_max).powf(k);
-                let rayleigh_pdf = (d / (temp_sigma * temp_sigma)) * (-d * d / (2.0 * temp_sigma * temp_sigma)).exp();
-                payout_factor * rayleigh_pdf
-            };
-
-            let sigma_fat = temp_sigma * fat_tail_mult;
-            let integrand_fat = |d: f64| -> f64 {
-                if d > d_max {
-                    return 0.0;
-                }
-                let payout_factor = (1.0 - d / d_max).powf(k);
-                let rayleigh_pdf = (d / (sigma_fat * sigma_fat)) * (-d * d / (2.0 * sigma_fat * sigma_fat)).exp();
-                payout_factor * rayleigh_pdf
-            };
+        // Drift detection: the innovation is standardized by the estimate's
+        // uncertainty plus the variance of the batch's debiased mean
+        let mut drifted = false;
+        if skill.auto_reset_on_drift {
+            let batch_noise = expected_variance / (PI / 2.0) / final_measurements.len() as f64;
+            let z = (unbiased_measurement - previous_sigma)
+                / (skill.kalman_filter.error_covariance + batch_noise).sqrt();
+            skill.drift_cusum = (skill.drift_cusum - z - DRIFT_CUSUM_SLACK).max(0.0);
+            drifted = skill.drift_cusum > DRIFT_CUSUM_THRESHOLD;
+        }
 
-            let upper_bound = (d_max * 1.5).max(sigma_fat * 5.0);
-            let n_subdivisions = 2000;
+        match skill.estimator {
+            // Skill changed: restart the filter at the new mean
+            _ if drifted => {
+                skill.kalman_filter.reset();
+                skill.kalman_filter.estimate = unbiased_measurement;
+                skill.drift_cusum = 0.0;
+                skill.drift_resets += 1;
+            }
+            // Kalman filter update; bursty betting inflates Q so the filter adapts faster
+            EstimatorKind::Kalman => skill
+                .kalman_filter
+                .update_with_adaptive_q(unbiased_measurement, measurement_noise, batch_wager_cv),
+            EstimatorKind::Ewma { alpha } => {
+                skill.kalman_filter.update_ewma(unbiased_measurement, measurement_noise, alpha)
+            }
+        }
 
-            let expected_payout_normal = trapezoidal_rule(integrand_normal, 0.0, upper_bound, n_subdivisions);
-            let expected_payout_fat = trapezoidal_rule(integrand_fat, 0.0, upper_bound, n_subdivisions);
-            let expected_payout = (1.0 - fat_tail_prob) * expected_payout_normal + fat_tail_prob * expected_payout_fat;
-            let epsilon = 1e-10;
-            hole.rtp / (expected_payout + epsilon)
-        };
+        // Calculate fresh P_max based on new sigma (bypass rate limiting for calculation)
+        let scale = dispersion_scale(hole);
+        let fresh_p_max = p_max_for_sigma(hole, skill.kalman_filter.estimate * scale, miss_model);
 
         // SECURITY FIX: Limit P_max changes to prevent exploitation
         // Maximum 20% change per update to prevent sandbagging -> exploitation cycles
 
-        // Get previous P_max (either from history or calculate from pre-update sigma)
-        let previous_p_max = if !skill.p_max_history.is_empty() {
-            *skill.p_max_history.last().unwrap()
-        } else {
+        // Get previous P_max (either this hole's current value or calculate from pre-update sigma)
+        let previous_p_max = match skill.p_max_by_hole.get(&hole.id) {
+            Some(p_max) => *p_max,
             // First update: calculate P_max with PREVIOUS sigma (before this update)
             // This establishes the baseline for rate limiting
-            let d_max = hole.d_max_ft;
-            let k = hole.k;
-            let fat_tail_prob = 0.02;
-            let fat_tail_mult = 3.0;
-
-            let integrand_normal = |d: f64| -> f64 {
-                if d > d_max {
-                    return 0.0;
-                }
-                let payout_factor = (1.0 - d / d_max).powf(k);
-                let rayleigh_pdf = (d / (previous_sigma * previous_sigma)) * (-d * d / (2.0 * previous_sigma * previous_sigma)).exp();
-                payout_factor * rayleigh_pdf
-            };
-
-            let sigma_fat = previous_sigma * fat_tail_mult;
-            let integrand_fat = |d: f64| -> f64 {
-                if d > d_max {
-                    return 0.0;
-                }
-                let payout_factor = (1.0 - d / d_max).powf(k);
-                let rayleigh_pdf = (d / (sigma_fat * sigma_fat)) * (-d * d / (2.0 * sigma_fat * sigma_fat)).exp();
-                payout_factor * rayleigh_pdf
-            };
-
-            let upper_bound = (d_max * 1.5).max(sigma_fat * 5.0);
-            let n_subdivisions = 2000;
-
-            let expected_payout_normal = trapezoidal_rule(integrand_normal, 0.0, upper_bound, n_subdivisions);
-            let expected_payout_fat = trapezoidal_rule(integrand_fat, 0.0, upper_bound, n_subdivisions);
-            let expected_payout = (1.0 - fat_tail_prob) * expected_payout_normal + fat_tail_prob * expected_payout_fat;
-            let epsilon = 1e-10;
-            hole.rtp / (expected_payout + epsilon)
+            None => cached_p_max(skill, hole, previous_sigma * scale)
+                .unwrap_or_else(|| p_max_for_sigma(hole, previous_sigma * scale, miss_model)),
         };
 
         let max_p_max_increase = previous_p_max * 1.20; // 20% max increase
@@ -425,17 +716,103 @@ impl Player {
 
         // If P_max would have changed too much, roll back sigma change proportionally
         if (fresh_p_max - limited_p_max).abs() > 0.01 {
-            // P_max is inversely related to performance, so limit sigma changes
+            // Keep the same fraction of the sigma change as of the P_max change,
+            // so the estimate stays between the previous and updated values
             let sigma_change = skill.kalman_filter.estimate - previous_sigma;
-            let limited_sigma_change = sigma_change * (limited_p_max / fresh_p_max);
+            let allowed_fraction = (limited_p_max - previous_p_max) / (fresh_p_max - previous_p_max);
+            let limited_sigma_change = sigma_change * allowed_fraction;
             skill.kalman_filter.estimate = previous_sigma + limited_sigma_change;
         }
 
         // Store the limited P_max
         skill.p_max_history.push(limited_p_max);
+        skill.sigma_history.push(skill.kalman_filter.estimate);
+        skill.confidence_history.push(skill.kalman_filter.calculate_confidence());
+        skill.p_max_by_hole.insert(hole.id, limited_p_max);
+
+        // Other holes in the category share the (rolled-back) sigma but have
+        // their own d_max and k; dropping their stored values makes
+        // `calculate_p_max` price them fresh from that sigma
+        skill.p_max_by_hole.retain(|id, _| *id == hole.id);
+
+        // The estimate moved, so cached integrals are stale
+        skill.p_max_cache.clear();
 
         // Clear batch
         skill.shot_batch.clear();
+
+        let relative_change = skill.kalman_filter.estimate / previous_sigma - 1.0;
+        if self.skill_transfer > 0.0 {
+            self.transfer_skill_change(hole.category, relative_change);
+        }
+    }
+
+    /// Pass a fraction of one category's relative estimate change to the others
+    ///
+    /// Their stored P_max values are dropped so they are priced fresh from
+    /// the nudged estimate.
+    fn transfer_skill_change(&mut self, source: ClubCategory, relative_change: f64) {
+        let factor = 1.0 + self.skill_transfer * relative_change;
+        for (category, skill) in self.skill_profiles.iter_mut() {
+            if *category == source {
+                continue;
+            }
+            skill.kalman_filter.estimate *= factor;
+            skill.p_max_by_hole.clear();
+            skill.p_max_cache.clear();
+        }
+    }
+
+    /// Drift resets across all skill profiles (see `SkillProfile::auto_reset_on_drift`)
+    pub fn drift_resets(&self) -> usize {
+        self.skill_profiles.values().map(|skill| skill.drift_resets).sum()
+    }
+
+    /// Price P_max for misses following `model`
+    ///
+    /// Drops cached P_max values integrated under the previous model.
+    /// Rate-limited P_max values already set by skill updates are kept; the
+    /// next update moves them toward the new model's price.
+    pub fn set_miss_model(&mut self, model: MissModel) {
+        if self.miss_model != model {
+            self.miss_model = model;
+            for skill in self.skill_profiles.values_mut() {
+                skill.p_max_cache.clear();
+            }
+        }
+    }
+
+    /// Return every skill profile to its prior, as if no shots had been played
+    ///
+    /// Each Kalman filter is reset to its initial estimate with no confidence,
+    /// and the pending batch, P_max and skill histories, stored P_max values
+    /// and drift state are cleared. Settings such as `batch_size`, `estimator`,
+    /// `true_sigma` and shot bias are kept.
+    pub fn reset_all_skills(&mut self) {
+        for skill in self.skill_profiles.values_mut() {
+            skill.kalman_filter.reset();
+            skill.shot_batch.clear();
+            skill.p_max_history.clear();
+            skill.sigma_history.clear();
+            skill.confidence_history.clear();
+            skill.p_max_by_hole.clear();
+            skill.p_max_cache.clear();
+            skill.drift_cusum = 0.0;
+            skill.drift_resets = 0;
+        }
+    }
+
+    /// Copy of this player with reset skills and no wager history
+    ///
+    /// Same id, handicap and profile settings, starting from the prior like a
+    /// new player (see [`Player::reset_all_skills`]). Handy for A/B runs on a
+    /// customized player, where `Player::new` would lose the customization.
+    pub fn cloned_fresh(&self) -> Player {
+        let mut player = self.clone();
+        player.reset_all_skills();
+        player.lifetime_wagers.clear();
+        player.lifetime_total_wagered = 0.0;
+        player
     }
 
     /// Get current skill confidence for a hole (0-100%)
@@ -445,9 +822,51 @@ impl Player {
     }
 
     /// Get current sigma estimate for a hole
+    ///
+    /// The category estimate scaled from the reference distance to this hole's distance.
     pub fn get_current_sigma(&self, hole: &Hole) -> f64 {
         let skill = self.get_skill_for_hole(hole);
-        skill.kalman_filter.estimate
+        skill.kalman_filter.estimate * dispersion_scale(hole)
+    }
+
+    /// Get the player's true sigma for a hole
+    ///
+    /// The dispersion shots are actually drawn from, scaled to this hole's
+    /// distance. Only the simulation may use it; odds use `get_current_sigma`.
+    pub fn get_true_sigma(&self, hole: &Hole) -> f64 {
+        let skill = self.get_skill_for_hole(hole);
+        skill.true_sigma.unwrap_or(skill.kalman_filter.estimate) * dispersion_scale(hole)
+    }
+
+    /// Set the player's true sigma for a category, at its reference distance
+    ///
+    /// Leaves the Kalman estimate untouched, e.g. to model a player who is
+    /// better (or worse) than their handicap suggests.
+    pub fn set_true_sigma(&mut self, category: ClubCategory, sigma: f64) {
+        if let Some(skill) = self.skill_profiles.get_mut(&category) {
+            skill.true_sigma = Some(sigma);
+        }
+    }
+
+    /// Directional miss bias (x, y) for a hole, scaled to its distance
+    ///
+    /// # Returns
+    /// None if the category has no bias, so shots stay radial
+    pub fn get_shot_bias(&self, hole: &Hole) -> Option<(f64, f64)> {
+        let skill = self.get_skill_for_hole(hole);
+        if skill.bias_x == 0.0 && skill.bias_y == 0.0 {
+            return None;
+        }
+        let scale = dispersion_scale(hole);
+        Some((skill.bias_x * scale, skill.bias_y * scale))
+    }
+
+    /// Set the directional miss bias for a category, at its reference distance
+    pub fn set_shot_bias(&mut self, category: ClubCategory, bias_x: f64, bias_y: f64) {
+        if let Some(skill) = self.skill_profiles.get_mut(&category) {
+            skill.bias_x = bias_x;
+            skill.bias_y = bias_y;
+        }
     }
 
     /// Get number of shots in current batch for a hole
@@ -477,6 +896,149 @@ impl Player {
     }
 }
 
+/// Ratio of expected dispersion at a hole's distance to its category's reference distance
+///
+/// Handicap cancels out of the ratio in `calculate_initial_dispersion`, so one
+/// factor per hole maps any player's category estimate to that hole (H1 at
+/// 75 yds is about 0.73 of the 100 yd Wedge reference). Misses are divided by
+/// it before batching, which keeps holes of different lengths from pulling a
+/// shared category estimate between their scales.
+pub fn dispersion_scale(hole: &Hole) -> f64 {
+    calculate_initial_dispersion(0, hole.distance_yds)
+        / calculate_initial_dispersion(0, hole.category.reference_distance_yds())
+}
+
+/// How much the wager weighting widens a batch's spread
+///
+/// `weighted_measurement_variance` over the unweighted `measurement_variance`
+/// of the same misses. Equal wagers give exactly 1.0; big wagers on the far
+/// misses give more, big wagers on typical misses less. The ratio does not
+/// depend on the batch's overall scale, which keeps R from tracking the
+/// measurement. 1.0 for batches with fewer than two weighted shots or no
+/// spread.
+fn weighted_spread_ratio(measurements: &[(f64, f64)]) -> f64 {
+    let weighted_shots = measurements.iter().filter(|(_, wager)| *wager > 0.0).count();
+    if measurements.len() < 2 || weighted_shots == 1 {
+        return 1.0;
+    }
+    let misses: Vec<f64> = measurements.iter().map(|(miss, _)| *miss).collect();
+    let unweighted = measurement_variance(&misses);
+    if unweighted <= 0.0 {
+        return 1.0;
+    }
+    weighted_measurement_variance(measurements) / unweighted
+}
+
+/// Subdivisions for the P_max integrals
+const P_MAX_SUBDIVISIONS: usize = 2000;
+
+/// Multiples of sigma past which a Rayleigh component is left out of the
+/// P_max integrals
+///
+/// The density beyond 12σ is below e^-72, so cutting each component's range
+/// there changes nothing but where the subdivisions fall: a tight sigma (a
+/// 0.05 ft peak on a 150 ft range) gets all of them on its peak instead of
+/// a handful.
+const P_MAX_RAYLEIGH_SUPPORT_SIGMAS: f64 = 12.0;
+
+/// Handicap whose P_max is offered to a player the filter knows nothing about
+///
+/// Better players get lower P_max, so pricing an unknown player as scratch
+/// can't overpay a sandbagger.
+pub const CONSERVATIVE_P_MAX_HANDICAP: u8 = 0;
+
+/// Relative sigma change that invalidates a cached P_max
+pub const P_MAX_CACHE_EPSILON: f64 = 1e-12;
+
+/// Cached P_max for `hole` if it was integrated at (nearly) `sigma`
+fn cached_p_max(skill: &SkillProfile, hole: &Hole, sigma: f64) -> Option<f64> {
+    skill
+        .p_max_cache
+        .get(&hole.id)
+        .filter(|(cached_sigma, _)| (cached_sigma - sigma).abs() <= P_MAX_CACHE_EPSILON * sigma.abs())
+        .map(|(_, p_max)| *p_max)
+}
+
+/// P_max that gives the hole's RTP for a player with dispersion `sigma`
+/// whose misses follow `model`
+fn p_max_for_sigma(hole: &Hole, sigma: f64, model: MissModel) -> f64 {
+    p_max_for_sigma_with(hole, sigma, model, IntegrationMethod::default(), P_MAX_SUBDIVISIONS)
+}
+
+/// [`p_max_for_sigma`] with an explicit quadrature rule and subdivision count
+fn p_max_for_sigma_with(
+    hole: &Hole,
+    sigma: f64,
+    model: MissModel,
+    method: IntegrationMethod,
+    n_subdivisions: usize,
+) -> f64 {
+    // Calculate expected payout using numerical integration
+    // Must account for fat-tail distribution (2% chance of 3x sigma)
+    let d_max = hole.d_max_ft;
+    let k = hole.k;
+    let fat_tail_prob = 0.02;
+    let fat_tail_mult = 3.0;
+
+    // Rings pay a constant inside each band, so the expectation is a sum of
+    // ring probabilities under the fat-tail mixture CDF
+    if !hole.payout_model.is_continuous() {
+        let cdf = |d: f64| {
+            (1.0 - fat_tail_prob) * model.cdf(d, sigma) + fat_tail_prob * model.cdf(d, sigma * fat_tail_mult)
+        };
+        let expected_payout: f64 = hole
+            .payout_model
+            .ring_probabilities(cdf)
+            .iter()
+            .map(|(fraction, probability)| fraction * probability)
+            .sum();
+        return hole.rtp / (expected_payout + 1e-10);
+    }
+
+    // Integrand: payout_function(d) * pdf(d | sigma). Heavy-tailed models
+    // (shape < 1) have a singular density at d = 0, a single point that
+    // carries no probability, so it is skipped
+    let integrand = |sigma: f64| {
+        move |d: f64| -> f64 {
+            if d <= 0.0 || d > d_max {
+                return 0.0;
+            }
+
+            // Payout function: (1 - d/d_max)^k
+            let payout_factor = (1.0 - d / d_max).powf(k);
+
+            payout_factor * model.pdf(d, sigma)
+        }
+    };
+    let integrand_normal = integrand(sigma);
+
+    // Fat-tail shots follow the same model at 3x sigma
+    let sigma_fat = sigma * fat_tail_mult;
+    let integrand_fat = integrand(sigma_fat);
+
+    // Integrate from 0 to d_max (use higher bound for numerical stability)
+    // Use the fat-tail sigma for upper bound since it has longer tail
+    let upper_bound = (d_max * 1.5).max(sigma_fat * 5.0);
+    // A small sigma's peak would otherwise get a few of the subdivisions.
+    // Only Rayleigh's tail is known to be negligible that far out; other
+    // models spend every subdivision on the paying range
+    let support = |sigma: f64| match model {
+        MissModel::Rayleigh => upper_bound.min(P_MAX_RAYLEIGH_SUPPORT_SIGMAS * sigma),
+        _ => d_max,
+    };
+
+    let expected_payout_normal = method.integrate(integrand_normal, 0.0, support(sigma), n_subdivisions);
+    let expected_payout_fat = method.integrate(integrand_fat, 0.0, support(sigma_fat), n_subdivisions);
+
+    // Weighted average: (1 - p_fat) * E[normal] + p_fat * E[fat]
+    let expected_payout = (1.0 - fat_tail_prob) * expected_payout_normal + fat_tail_prob * expected_payout_fat;
+
+    // P_max = RTP / expected_payout
+    // Add small epsilon to prevent division by zero
+    let epsilon = 1e-10;
+    hole.rtp / (expected_payout + epsilon)
+}
+
 /// Calculate initial dispersion (sigma) based on handicap and distance
 ///
 /// # Formula
@@ -563,6 +1125,23 @@ mod tests {
         assert!(skill.kalman_filter.estimate > 0.0);
     }
 
+    #[test]
+    fn test_true_sigma_is_hidden_from_odds() {
+        let mut player = Player::new("test".to_string(), 20);
+        let hole = get_hole_by_id(2).unwrap();
+
+        // By default the player plays exactly as the house estimates
+        assert_eq!(player.get_true_sigma(hole), player.get_current_sigma(hole));
+
+        let estimate = player.get_current_sigma(hole);
+        let p_max = player.calculate_p_max(hole);
+        player.set_true_sigma(ClubCategory::Wedge, 5.0);
+
+        assert!((player.get_true_sigma(hole) - 5.0 * dispersion_scale(hole)).abs() < 1e-12);
+        assert_eq!(player.get_current_sigma(hole), estimate);
+        assert_eq!(player.calculate_p_max(hole), p_max);
+    }
+
     #[test]
     fn test_calculate_p_max() {
         let player = Player::new("test".to_string(), 15);
@@ -591,6 +1170,152 @@ mod tests {
             "Pro P_max: {}, Beginner P_max: {}", p_max_pro, p_max_beginner);
     }
 
+    #[test]
+    fn test_prob_multiplier_at_least_matches_monte_carlo() {
+        use crate::models::shot::simulate_shot;
+
+        let player = Player::new("test".to_string(), 15);
+        let hole = get_hole_by_id(4).unwrap();
+        let sigma = player.get_current_sigma(hole);
+        let p_max = player.calculate_p_max(hole);
+
+        let analytic = player.prob_multiplier_at_least(hole, 5.0);
+        assert!(analytic > 0.0 && analytic < 1.0);
+
+        let trials = 50_000;
+        let hits = (0..trials)
+            .map(|_| simulate_shot(sigma, 0.02, 3.0).0)
+            .filter(|&d| hole.calculate_payout(d, p_max) >= 5.0)
+            .count();
+        let frequency = hits as f64 / trials as f64;
+        let std_error = (analytic * (1.0 - analytic) / trials as f64).sqrt();
+
+        assert!((frequency - analytic).abs() < 4.0 * std_error,
+            "MC frequency {} vs analytic {} (SE {})", frequency, analytic, std_error);
+
+        // Monotonically decreasing in the threshold
+        let thresholds = [0.0, 0.5, 1.0, 2.0, 5.0, 10.0, p_max];
+        for pair in thresholds.windows(2) {
+            assert!(player.prob_multiplier_at_least(hole, pair[0])
+                >= player.prob_multiplier_at_least(hole, pair[1]));
+        }
+        assert_eq!(player.prob_multiplier_at_least(hole, 0.0), 1.0);
+        assert_eq!(player.prob_multiplier_at_least(hole, p_max * 1.01), 0.0);
+    }
+
+    #[test]
+    fn test_confidence_weighted_p_max_ramps_to_skill_value() {
+        use approx::assert_relative_eq;
+
+        let hole = get_hole_by_id(4).unwrap();
+        let mut player = Player::new("test".to_string(), 25);
+        let conservative = Player::new("scratch".to_string(), CONSERVATIVE_P_MAX_HANDICAP).calculate_p_max(hole);
+        let skill = player.calculate_p_max(hole);
+        assert!(skill > conservative);
+
+        // Brand new: 0% confidence, scratch odds
+        assert_eq!(player.get_skill_confidence(hole), 0.0);
+        assert_relative_eq!(player.calculate_p_max_confidence_weighted(hole), conservative, max_relative = 1e-9);
+
+        // Converged: 100% confidence, the player's own odds
+        player.get_skill_for_hole_mut(hole).kalman_filter.error_covariance = 10.0;
+        assert_eq!(player.get_skill_confidence(hole), 100.0);
+        assert_relative_eq!(player.calculate_p_max_confidence_weighted(hole), skill, max_relative = 1e-12);
+
+        // In between, strictly between the two
+        player.get_skill_for_hole_mut(hole).kalman_filter.error_covariance = 500.0;
+        let blended = player.calculate_p_max_confidence_weighted(hole);
+        assert!(blended > conservative && blended < skill);
+    }
+
+    #[test]
+    fn test_ring_hole_rtp_matches_target() {
+        use crate::models::hole::PayoutModel;
+        use crate::models::shot::simulate_shot_with_rng;
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let player = Player::new("test".to_string(), 15);
+        let hole = Hole {
+            payout_model: PayoutModel::Rings { thresholds: vec![(5.0, 1.0), (15.0, 0.4), (30.0, 0.1)] },
+            ..get_hole_by_id(4).unwrap().clone()
+        };
+        let sigma = player.get_true_sigma(&hole);
+        let p_max = player.calculate_p_max(&hole);
+        assert!(p_max > 1.0);
+
+        let mut rng = StdRng::seed_from_u64(297);
+        let trials = 200_000;
+        let payouts: Vec<f64> = (0..trials)
+            .map(|_| hole.calculate_payout(simulate_shot_with_rng(sigma, 0.02, 3.0, &mut rng).0, p_max))
+            .collect();
+        let rtp = payouts.iter().sum::<f64>() / trials as f64;
+        let variance = payouts.iter().map(|p| (p - rtp).powi(2)).sum::<f64>() / (trials - 1) as f64;
+        let std_error = (variance / trials as f64).sqrt();
+
+        assert!((rtp - hole.rtp).abs() < 4.0 * std_error,
+            "ring RTP {} vs target {} (SE {})", rtp, hole.rtp, std_error);
+
+        // Ring payouts are flat inside each band and zero outside the last
+        assert_eq!(hole.calculate_payout(4.9, p_max), p_max);
+        assert_eq!(hole.calculate_payout(10.0, p_max), 0.4 * p_max);
+        assert_eq!(hole.calculate_payout(30.1, p_max), 0.0);
+    }
+
+    #[test]
+    fn test_p_max_accurate_for_tiny_sigma() {
+        use crate::math::distributions::rayleigh_pdf;
+        use crate::math::integration::simpsons_rule;
+
+        // Hole 8's d_max is ~101 ft, so these peaks span a small fraction of
+        // the integration range
+        let hole = get_hole_by_id(8).unwrap();
+        for sigma in [2.0, 0.2, 0.05] {
+            // Reference E[payout] on a grid fine enough for the narrowest peak
+            let integrand = |d: f64| {
+                let mixture = 0.98 * rayleigh_pdf(d, sigma) + 0.02 * rayleigh_pdf(d, 3.0 * sigma);
+                (1.0 - d / hole.d_max_ft).powf(hole.k) * mixture
+            };
+            let expected_payout = simpsons_rule(integrand, 0.0, hole.d_max_ft, 1_000_000);
+
+            for method in [IntegrationMethod::Trapezoidal, IntegrationMethod::Simpson] {
+                let p_max = p_max_for_sigma_with(hole, sigma, MissModel::Rayleigh, method, P_MAX_SUBDIVISIONS);
+                let rtp = p_max * expected_payout;
+                assert!((rtp / hole.rtp - 1.0).abs() < 1e-5,
+                    "sigma {} {:?}: RTP {} vs target {}", sigma, method, rtp, hole.rtp);
+            }
+        }
+    }
+
+    #[test]
+    fn test_p_max_prices_miss_model() {
+        use crate::math::integration::simpsons_rule;
+        use approx::assert_relative_eq;
+
+        let hole = get_hole_by_id(4).unwrap();
+        let mut player = Player::new("test".to_string(), 15);
+        let rayleigh = player.calculate_p_max_cached(hole);
+
+        // Weibull with shape 2 is Rayleigh
+        player.set_miss_model(MissModel::Weibull { shape: 2.0 });
+        assert_relative_eq!(player.calculate_p_max_cached(hole), rayleigh, max_relative = 1e-6);
+
+        // At the same mean a low-shape Gamma puts far more misses near the
+        // pin, so P_max falls to hold the RTP
+        let model = MissModel::Gamma { shape: 1.2 };
+        player.set_miss_model(model);
+        let p_max = player.calculate_p_max_cached(hole);
+        assert!(p_max < rayleigh * 0.9, "{} vs {}", p_max, rayleigh);
+
+        let sigma = player.get_current_sigma(hole);
+        let integrand = |d: f64| {
+            let mixture = 0.98 * model.pdf(d, sigma) + 0.02 * model.pdf(d, 3.0 * sigma);
+            (1.0 - d / hole.d_max_ft).powf(hole.k) * mixture
+        };
+        let expected_payout = simpsons_rule(integrand, 1e-9, hole.d_max_ft, 1_000_000);
+        // The density's cusp at 0 limits the quadrature to a few parts in 10^4
+        assert_relative_eq!(p_max * expected_payout, hole.rtp, max_relative = 1e-3);
+    }
+
     #[test]
     fn test_add_shot_to_batch() {
         let mut player = Player::new("test".to_string(), 15);
@@ -623,6 +1348,198 @@ mod tests {
         assert!(player.is_high_stakes_shot(hole, 100.0));
     }
 
+    #[test]
+    fn test_lower_high_stakes_multiplier_flags_more_shots() {
+        // Steady $5 bets with periodic raises
+        let wagers = [5.0, 5.0, 50.0, 5.0, 30.0, 5.0, 5.0, 100.0, 5.0, 28.0];
+        let flagged = |multiplier: f64| {
+            let mut player = Player::new("test".to_string(), 15);
+            let hole = get_hole_by_id(1).unwrap();
+            player.get_skill_for_hole_mut(hole).high_stakes_multiplier = multiplier;
+            player.get_skill_for_hole_mut(hole).batch_size = usize::MAX;
+            wagers
+                .iter()
+                .filter(|&&wager| {
+                    let high_stakes = player.is_high_stakes_shot(hole, wager);
+                    player.add_shot_to_batch(hole, 10.0, wager);
+                    high_stakes
+                })
+                .count()
+        };
+
+        // Only the first raise reaches 10× the running average; the $100 bet
+        // clears 5× but not 10× once the earlier raises lift the average
+        assert_eq!(flagged(DEFAULT_HIGH_STAKES_MULTIPLIER), 1);
+        assert_eq!(flagged(5.0), 2);
+        assert_eq!(flagged(20.0), 0);
+    }
+
+    #[test]
+    fn test_from_shot_log_starts_converged() {
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+        use crate::math::distributions::rayleigh_random_with_rng;
+
+        // 60 mid-iron shots from a player with sigma 20 ft at the reference distance
+        let hole = get_hole_by_id(5).unwrap();
+        let mut rng = StdRng::seed_from_u64(262);
+        let log: Vec<(f64, f64, u8)> = (0..60)
+            .map(|_| (rayleigh_random_with_rng(20.0 * dispersion_scale(hole), &mut rng), 10.0, 5))
+            .chain([(5.0, 10.0, 99)]) // Unknown hole, skipped
+            .collect();
+
+        let player = Player::from_shot_log("imported".to_string(), &log);
+
+        let mid_iron = &player.skill_profiles[&ClubCategory::MidIron].kalman_filter;
+        assert!((mid_iron.estimate - 20.0).abs() < 2.0, "estimate {}", mid_iron.estimate);
+        assert!(mid_iron.calculate_confidence() > 80.0, "P {}", mid_iron.error_covariance);
+
+        // Closest handicap to the fitted mid-iron sigma, other categories untouched
+        let fresh = Player::new("fresh".to_string(), player.handicap);
+        assert!((calculate_initial_dispersion(player.handicap, 162) - mid_iron.estimate).abs() < 0.5);
+        let wedge = &player.skill_profiles[&ClubCategory::Wedge].kalman_filter;
+        assert_eq!(wedge.estimate, fresh.skill_profiles[&ClubCategory::Wedge].kalman_filter.estimate);
+        assert_eq!(wedge.error_covariance, 1000.0);
+    }
+
+    #[test]
+    fn test_ewma_estimator_converges_but_lags_kalman() {
+        // Hole 2 sits at the wedge reference distance, so misses need no scaling
+        let hole = get_hole_by_id(2).unwrap();
+        let player_with = |estimator: EstimatorKind| {
+            let mut player = Player::new("test_player".to_string(), 0);
+            let skill = player.get_skill_for_hole_mut(hole);
+            skill.estimator = estimator;
+            skill.batch_size = 1;
+            player
+        };
+        let mut kalman = player_with(EstimatorKind::Kalman);
+        let mut ewma = player_with(EstimatorKind::Ewma { alpha: 0.1 });
+
+        // A miss that debiases to exactly `sigma`
+        let play = |player: &mut Player, sigma: f64, shots: usize| {
+            for _ in 0..shots {
+                player.add_shot_to_batch(hole, sigma * (PI / 2.0).sqrt(), 10.0);
+                player.update_skill(hole);
+            }
+            player.get_current_sigma(hole)
+        };
+
+        // Consistent shots: both settle on the same sigma
+        assert!((play(&mut kalman, 9.0, 150) - 9.0).abs() < 1e-3);
+        assert!((play(&mut ewma, 9.0, 150) - 9.0).abs() < 1e-3);
+        assert!(ewma.get_skill_confidence(hole) > 80.0);
+
+        // Step change: at the R floor the settled Kalman gain (~0.13) beats alpha = 0.1
+        let kalman_gap = 11.0 - play(&mut kalman, 11.0, 5);
+        let ewma_gap = 11.0 - play(&mut ewma, 11.0, 5);
+        assert!(ewma_gap > kalman_gap && kalman_gap > 0.0, "EWMA {:.3} vs Kalman {:.3}", ewma_gap, kalman_gap);
+    }
+
+    #[test]
+    fn test_validate_skill_consistency_flags_inverted_profile() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        assert!(player.validate_skill_consistency().is_ok());
+
+        // Imported log with tight mid irons but wild wedges
+        let log = [(40.0, 10.0, 2), (45.0, 10.0, 2), (6.0, 10.0, 4), (8.0, 10.0, 4)];
+        let imported = Player::from_shot_log("imported".to_string(), &log);
+        let err = imported.validate_skill_consistency().unwrap_err();
+        assert!(err.contains("MidIron") && err.contains("Wedge"), "{}", err);
+
+        player.skill_profiles.get_mut(&ClubCategory::LongIron).unwrap().kalman_filter.estimate = 1.0;
+        let err = player.validate_skill_consistency().unwrap_err();
+        assert!(err.starts_with("LongIron") && err.contains("MidIron"), "{}", err);
+    }
+
+    #[test]
+    fn test_bursty_wagers_keep_filter_adaptive() {
+        let hole = get_hole_by_id(4).unwrap();
+        let mut steady = Player::new("steady".to_string(), 15);
+        let mut bursty = Player::new("bursty".to_string(), 15);
+
+        // Same misses and total stake; only the wager spread differs
+        for _ in 0..6 {
+            for wager in [10.0, 10.0, 10.0, 10.0, 10.0] {
+                steady.add_shot_to_batch(hole, 40.0, wager);
+            }
+            steady.update_skill(hole);
+            for wager in [2.0, 2.0, 2.0, 2.0, 42.0] {
+                bursty.add_shot_to_batch(hole, 40.0, wager);
+            }
+            bursty.update_skill(hole);
+        }
+
+        let steady_filter = &steady.get_skill_for_hole(hole).kalman_filter;
+        let bursty_filter = &bursty.get_skill_for_hole(hole).kalman_filter;
+        assert!(bursty_filter.error_covariance > steady_filter.error_covariance,
+            "bursty P {} vs steady P {}", bursty_filter.error_covariance, steady_filter.error_covariance);
+        assert_eq!(bursty_filter.process_noise, steady_filter.process_noise);
+    }
+
+    #[test]
+    fn test_reject_outliers_limits_shank_impact() {
+        // H2 sits at the wedge reference distance, so misses are unscaled
+        let hole = get_hole_by_id(2).unwrap();
+        let estimate_change = |reject_outliers: bool| {
+            let mut player = Player::new("test".to_string(), 15);
+            player.get_skill_for_hole_mut(hole).reject_outliers = reject_outliers;
+            let consistent = player.get_skill_for_hole(hole).kalman_filter.estimate
+                * (std::f64::consts::PI / 2.0).sqrt();
+
+            for _ in 0..20 {
+                if player.add_shot_to_batch(hole, consistent, 10.0) {
+                    player.update_skill(hole);
+                }
+            }
+            let before = player.get_skill_for_hole(hole).kalman_filter.estimate;
+
+            player.add_shot_to_batch(hole, consistent * 5.0, 10.0);
+            for _ in 0..4 {
+                player.add_shot_to_batch(hole, consistent, 10.0);
+            }
+            player.update_skill(hole);
+            player.get_skill_for_hole(hole).kalman_filter.estimate / before - 1.0
+        };
+
+        let plain = estimate_change(false);
+        let robust = estimate_change(true);
+        assert!(robust < plain / 2.0, "robust {:.4} vs plain {:.4}", robust, plain);
+        assert!(robust < 0.05, "robust moved {:.2}%", robust * 100.0);
+    }
+
+    #[test]
+    fn test_skill_transfer_moves_other_categories() {
+        let mut player = Player::new("test".to_string(), 15);
+        player.skill_transfer = 0.5;
+        let hole = get_hole_by_id(4).unwrap();
+        let long_iron_hole = get_hole_by_id(7).unwrap();
+
+        // Give the long irons a stored (rate-limited) P_max first
+        for _ in 0..5 {
+            player.add_shot_to_batch(long_iron_hole, 40.0, 10.0);
+        }
+        player.update_skill(long_iron_hole);
+        let wedge_before = player.skill_profiles[&ClubCategory::Wedge].kalman_filter.estimate;
+        let long_iron_p_max = player.calculate_p_max(long_iron_hole);
+        let mid_iron_before = player.get_skill_for_hole(hole).kalman_filter.estimate;
+
+        for _ in 0..5 {
+            player.add_shot_to_batch(hole, 5.0, 10.0);
+        }
+        player.update_skill(hole);
+
+        let relative_change = player.get_skill_for_hole(hole).kalman_filter.estimate / mid_iron_before - 1.0;
+        assert!(relative_change < 0.0);
+        let wedge_after = player.skill_profiles[&ClubCategory::Wedge].kalman_filter.estimate;
+        assert!((wedge_after / wedge_before - (1.0 + 0.5 * relative_change)).abs() < 1e-12);
+
+        // The stored long-iron P_max follows the nudged (smaller) sigma down
+        let long_iron_p_max_after = player.calculate_p_max(long_iron_hole);
+        assert!(long_iron_p_max_after < long_iron_p_max);
+        assert_eq!(long_iron_p_max_after, p_max_for_sigma(long_iron_hole, player.get_current_sigma(long_iron_hole), MissModel::Rayleigh));
+    }
+
     #[test]
     fn test_update_skill() {
         let mut player = Player::new("test".to_string(), 15);
@@ -635,10 +1552,8 @@ mod tests {
         player.add_shot_to_batch(hole, 12.0, 5.0);
         player.add_shot_to_batch(hole, 11.0, 5.0);
 
-        let p_max = player.calculate_p_max(hole);
-
         // Update skill
-        player.update_skill(hole, p_max);
+        player.update_skill(hole);
 
         // Batch should be cleared
         assert_eq!(player.get_batch_size(hole), 0);
@@ -656,6 +1571,135 @@ mod tests {
         assert_eq!(skill.p_max_history.len(), 1);
     }
 
+    #[test]
+    fn test_cloned_fresh_resets_skills() {
+        let mut player = Player::new("test".to_string(), 15);
+        let hole = get_hole_by_id(3).unwrap();
+        player.get_skill_for_hole_mut(hole).batch_size = 3;
+        player.get_skill_for_hole_mut(hole).estimator = EstimatorKind::Ewma { alpha: 0.3 };
+        let prior_sigma = player.get_current_sigma(hole);
+
+        for _ in 0..4 {
+            for miss in [5.0, 6.0, 4.0] {
+                player.add_shot_to_batch(hole, miss, 10.0);
+                player.track_wager(10.0);
+            }
+            player.update_skill(hole);
+        }
+        player.add_shot_to_batch(hole, 5.0, 10.0);
+        assert!(player.get_skill_confidence(hole) > 0.0);
+        assert_ne!(player.get_current_sigma(hole), prior_sigma);
+
+        let fresh = player.cloned_fresh();
+        let skill = fresh.get_skill_for_hole(hole);
+        assert_eq!(fresh.id, player.id);
+        assert_eq!(fresh.handicap, player.handicap);
+        assert_eq!(fresh.get_skill_confidence(hole), 0.0);
+        assert_eq!(fresh.get_current_sigma(hole), prior_sigma);
+        assert_eq!(fresh.calculate_p_max(hole), Player::new("other".to_string(), 15).calculate_p_max(hole));
+        assert!(skill.shot_batch.is_empty() && skill.p_max_history.is_empty() && skill.sigma_history.is_empty());
+        assert!(fresh.lifetime_wagers.is_empty());
+        // Customized settings survive the reset
+        assert_eq!(skill.batch_size, 3);
+        assert_eq!(skill.estimator, EstimatorKind::Ewma { alpha: 0.3 });
+
+        // The original keeps its learned state
+        assert!(player.get_skill_confidence(hole) > 0.0);
+        assert_eq!(player.get_skill_for_hole(hole).p_max_history.len(), 4);
+    }
+
+    #[test]
+    fn test_skill_histories_stay_parallel() {
+        let mut player = Player::new("test".to_string(), 15);
+        let hole = get_hole_by_id(3).unwrap();
+
+        for update in 1..=4 {
+            for miss in [8.0, 12.0, 15.0, 9.0, 20.0] {
+                player.add_shot_to_batch(hole, miss, 10.0);
+            }
+            player.update_skill(hole);
+
+            let skill = player.get_skill_for_hole(hole);
+            assert_eq!(skill.p_max_history.len(), update);
+            assert_eq!(skill.sigma_history.len(), update);
+            assert_eq!(skill.confidence_history.len(), update);
+            assert_eq!(*skill.sigma_history.last().unwrap(), skill.kalman_filter.estimate);
+            assert_eq!(*skill.confidence_history.last().unwrap(), player.get_skill_confidence(hole));
+        }
+    }
+
+    #[test]
+    fn test_rate_limited_update_keeps_sigma_between_estimates() {
+        let mut player = Player::new("test".to_string(), 27);
+        let hole = get_hole_by_id(4).unwrap();
+
+        // Repeated near-perfect batches push P_max down faster than 20% per update
+        for _ in 0..10 {
+            let previous_sigma = player.get_current_sigma(hole);
+            for _ in 0..5 {
+                player.add_shot_to_batch(hole, 0.5, 10.0);
+            }
+            player.update_skill(hole);
+
+            let sigma = player.get_current_sigma(hole);
+            assert!(sigma > 0.0, "Sigma went non-positive: {}", sigma);
+            assert!(sigma <= previous_sigma);
+        }
+    }
+
+    #[test]
+    fn test_update_sets_p_max_for_each_hole_in_category() {
+        let mut player = Player::new("test".to_string(), 15);
+        let hole1 = get_hole_by_id(1).unwrap();
+        let hole3 = get_hole_by_id(3).unwrap();
+
+        for _ in 0..5 {
+            player.add_shot_to_batch(hole1, 12.0, 10.0);
+        }
+        player.update_skill(hole1);
+
+        // The shared Wedge sigma moved; H3 gets its own P_max for that sigma
+        let sigma = player.get_current_sigma(hole3);
+        assert_eq!(player.calculate_p_max(hole1), *player.get_skill_for_hole(hole1).p_max_history.last().unwrap());
+        assert_eq!(player.calculate_p_max(hole3), p_max_for_sigma(hole3, sigma, MissModel::Rayleigh));
+        assert!(player.calculate_p_max(hole3) < player.calculate_p_max(hole1));
+    }
+
+    #[test]
+    fn test_interleaved_holes_share_coherent_estimate() {
+        use crate::math::distributions::rayleigh_random_with_rng;
+        use rand::{rngs::StdRng, SeedableRng};
+
+        // Prior from handicap 25, shots from a handicap-10 player alternating H1 (75 yds) and H2 (100 yds)
+        let mut player = Player::new("test".to_string(), 25);
+        let hole1 = get_hole_by_id(1).unwrap();
+        let hole2 = get_hole_by_id(2).unwrap();
+        let mut rng = StdRng::seed_from_u64(21);
+
+        for shot in 0..600 {
+            let hole = if shot % 2 == 0 { hole1 } else { hole2 };
+            let miss = rayleigh_random_with_rng(calculate_initial_dispersion(10, hole.distance_yds), &mut rng);
+            if player.add_shot_to_batch(hole, miss, 10.0) {
+                player.update_skill(hole);
+            }
+        }
+
+        // One Wedge estimate serves both holes at their own scales
+        for hole in [hole1, hole2] {
+            let truth = calculate_initial_dispersion(10, hole.distance_yds);
+            let estimate = player.get_current_sigma(hole);
+            assert!(
+                ((estimate - truth) / truth).abs() < 0.05,
+                "H{} estimate {:.2} vs true {:.2}",
+                hole.id,
+                estimate,
+                truth
+            );
+        }
+        let ratio = player.get_current_sigma(hole1) / player.get_current_sigma(hole2);
+        assert!((ratio - dispersion_scale(hole1)).abs() < 1e-12);
+    }
+
     #[test]
     fn test_skill_convergence() {
         let mut player = Player::new("test".to_string(), 15);
@@ -669,8 +1713,7 @@ mod tests {
                 player.add_shot_to_batch(hole, 30.0, 5.0);
             }
 
-            let p_max = player.calculate_p_max(hole);
-            player.update_skill(hole, p_max);
+            player.update_skill(hole);
         }
 
         // Confidence should increase significantly
@@ -680,6 +1723,41 @@ mod tests {
             initial_confidence, final_confidence);
     }
 
+    #[test]
+    fn test_weighted_spread_ratio() {
+        let flat = [(20.0, 5.0), (22.0, 5.0), (18.0, 5.0), (25.0, 5.0), (60.0, 5.0)];
+        assert_eq!(weighted_spread_ratio(&flat), 1.0);
+        assert_eq!(weighted_spread_ratio(&[(60.0, 5.0)]), 1.0);
+        assert_eq!(weighted_spread_ratio(&[(20.0, 0.0), (60.0, 5.0)]), 1.0);
+        assert_eq!(weighted_spread_ratio(&[(20.0, 5.0), (20.0, 10.0)]), 1.0);
+    }
+
+    #[test]
+    fn test_measurement_noise_follows_wager_weighting() {
+        // The same misses and wagers, with the big wagers on the two far
+        // misses or on two typical ones
+        let on_outliers = [(15.0, 2.0), (18.0, 2.0), (16.0, 2.0), (55.0, 40.0), (60.0, 40.0)];
+        let on_typical = [(15.0, 40.0), (18.0, 40.0), (16.0, 2.0), (55.0, 2.0), (60.0, 2.0)];
+
+        let misses = |batch: &[(f64, f64)]| batch.iter().map(|(miss, _)| *miss).collect::<Vec<_>>();
+        assert_eq!(measurement_variance(&misses(&on_outliers)), measurement_variance(&misses(&on_typical)));
+        assert!(weighted_measurement_variance(&on_outliers) > weighted_measurement_variance(&on_typical));
+
+        // Same unweighted spread and wager volatility, so only the weighting
+        // separates the two updates: the heavily wagered outliers get the
+        // larger R and leave the estimate less certain
+        let hole = get_hole_by_id(4).unwrap();
+        let covariance_after = |batch: &[(f64, f64)]| {
+            let mut player = Player::new("test".to_string(), 15);
+            for &(miss, wager) in batch {
+                player.add_shot_to_batch(hole, miss, wager);
+            }
+            player.update_skill(hole);
+            player.get_skill_for_hole(hole).kalman_filter.error_covariance
+        };
+        assert!(covariance_after(&on_outliers) > covariance_after(&on_typical));
+    }
+
     #[test]
     fn test_separate_skill_profiles() {
         let mut player = Player::new("test".to_string(), 15);
@@ -691,8 +1769,7 @@ mod tests {
         for _ in 0..5 {
             player.add_shot_to_batch(wedge_hole, 15.0, 5.0);
         }
-        let p_max_wedge = player.calculate_p_max(wedge_hole);
-        player.update_skill(wedge_hole, p_max_wedge);
+        player.update_skill(wedge_hole);
 
         // Wedge should have update, but long iron should not
         let wedge_skill = player.get_skill_for_hole(wedge_hole);
@@ -701,4 +1778,62 @@ mod tests {
         assert_eq!(wedge_skill.p_max_history.len(), 1);
         assert_eq!(long_skill.p_max_history.len(), 0);
     }
+
+    #[test]
+    fn test_simpson_p_max_matches_reference_with_half_the_subdivisions() {
+        use crate::math::integration::integrate_payout_function;
+
+        let rayleigh = |d: f64, sigma: f64| (d / (sigma * sigma)) * (-d * d / (2.0 * sigma * sigma)).exp();
+        for hole_id in [1, 4, 8] {
+            let hole = get_hole_by_id(hole_id).unwrap();
+            for sigma in [0.5, 5.0, 20.0] {
+                let payout_fraction = 0.98 * integrate_payout_function(hole.d_max_ft, hole.k, sigma, rayleigh, 200_000)
+                    + 0.02 * integrate_payout_function(hole.d_max_ft, hole.k, 3.0 * sigma, rayleigh, 200_000);
+                let reference = hole.rtp / (payout_fraction + 1e-10);
+                let relative_error = |method, n| (p_max_for_sigma_with(hole, sigma, MissModel::Rayleigh, method, n) - reference).abs() / reference;
+
+                let trapezoidal = relative_error(IntegrationMethod::Trapezoidal, 2000);
+                let simpson = relative_error(IntegrationMethod::Simpson, 1000);
+                assert!(simpson < trapezoidal, "H{} σ={}: Simpson {:.2e} vs trapezoidal {:.2e}", hole_id, sigma, simpson, trapezoidal);
+                assert!(relative_error(IntegrationMethod::Adaptive, 0) < 1e-6, "H{} σ={}", hole_id, sigma);
+            }
+        }
+
+        // The default is Simpson
+        let player = Player::new("test".to_string(), 15);
+        let hole = get_hole_by_id(8).unwrap();
+        assert_eq!(player.calculate_p_max(hole), player.calculate_p_max_with(hole, IntegrationMethod::Simpson));
+        assert_ne!(player.calculate_p_max(hole), player.calculate_p_max_with(hole, IntegrationMethod::Trapezoidal));
+    }
+
+    #[test]
+    fn test_cached_p_max_matches_uncached_recompute() {
+        let mut player = Player::new("test".to_string(), 12);
+        let hole = get_hole_by_id(5).unwrap();
+        let sibling = get_hole_by_id(4).unwrap();
+
+        let first = player.calculate_p_max_cached(hole);
+        assert_eq!(first, p_max_for_sigma(hole, player.get_current_sigma(hole), MissModel::Rayleigh));
+        assert!(player.get_skill_for_hole(hole).p_max_cache.contains_key(&hole.id));
+        assert_eq!(player.calculate_p_max_cached(hole), first);
+        assert_eq!(player.calculate_p_max(hole), first);
+
+        // Moving the estimate directly invalidates the entry
+        player.get_skill_for_hole_mut(hole).kalman_filter.estimate *= 1.1;
+        let moved = player.calculate_p_max_cached(hole);
+        assert_eq!(moved, p_max_for_sigma(hole, player.get_current_sigma(hole), MissModel::Rayleigh));
+        assert!(moved > first);
+
+        // A skill update clears the cache; the sibling hole is priced fresh
+        player.calculate_p_max_cached(sibling);
+        for miss in [20.0, 25.0, 30.0, 22.0, 28.0] {
+            player.add_shot_to_batch(hole, miss, 10.0);
+        }
+        player.update_skill(hole);
+        assert!(player.get_skill_for_hole(hole).p_max_cache.is_empty());
+        assert_eq!(
+            player.calculate_p_max_cached(sibling),
+            p_max_for_sigma(sibling, player.get_current_sigma(sibling), MissModel::Rayleigh)
+        );
+    }
 }
@@ -7,12 +7,23 @@
 // - Metadata for analysis
 
 use serde::{Deserialize, Serialize};
-use crate::math::distributions::{rayleigh_random, fat_tail_shot};
+use std::collections::HashMap;
+use crate::math::distributions::{
+    rayleigh_random, fat_tail_shot, fat_tail_shot_capped_with_rng, fat_tail_shot_from_uniforms,
+    fat_tail_shot_with_rng, normal_random_with_rng, MissModel, NormalSampler,
+};
+use rand::Rng;
+
+/// Multiplier at or above which a shot counts as a big win
+pub const BIG_WIN_MULTIPLIER: f64 = 5.0;
+
+/// Tolerance used when deciding whether a multiplier is exactly 1×
+const BREAKEVEN_TOLERANCE: f64 = 1e-9;
 
 /// Result of a single shot attempt
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
 pub struct ShotOutcome {
-    /// Miss distance from target in feet
+    /// Miss distance from target in feet, as measured by the bay sensor
     pub miss_distance_ft: f64,
     /// Payout multiplier (e.g., 5.0 = 5× return)
     pub multiplier: f64,
@@ -24,6 +35,10 @@ pub struct ShotOutcome {
     pub hole_id: u8,
     /// Whether this was a fat-tail event (extreme mishit)
     pub is_fat_tail: bool,
+    /// When the shot was taken, in milliseconds since an arbitrary epoch
+    /// (None when the source has no clock, e.g. simulated sessions)
+    #[serde(default, skip_serializing_if = "Option::is_none")]
+    pub timestamp_ms: Option<u64>,
 }
 
 impl ShotOutcome {
@@ -53,6 +68,7 @@ impl ShotOutcome {
             wager,
             hole_id,
             is_fat_tail,
+            timestamp_ms: None,
         }
     }
 
@@ -70,6 +86,174 @@ impl ShotOutcome {
     pub fn is_ace(&self) -> bool {
         self.miss_distance_ft < 0.1 // Within 1 inch
     }
+
+    /// Classify this shot for reporting
+    ///
+    /// Classes are checked in priority order: an ace is always an `Ace`,
+    /// and a zero-payout fat-tail shot is a `Blowup` rather than a plain `Loss`.
+    ///
+    /// # Example
+    /// ```
+    /// use continuum_golf_simulator::models::shot::{ShotOutcome, ShotClass};
+    ///
+    /// let shot = ShotOutcome::new(3.0, 6.5, 10.0, 4, false);
+    /// assert_eq!(shot.classify(), ShotClass::BigWin);
+    /// ```
+    pub fn classify(&self) -> ShotClass {
+        if self.is_ace() {
+            ShotClass::Ace
+        } else if self.is_fat_tail && self.payout == 0.0 {
+            ShotClass::Blowup
+        } else if self.multiplier >= BIG_WIN_MULTIPLIER {
+            ShotClass::BigWin
+        } else if (self.multiplier - 1.0).abs() < BREAKEVEN_TOLERANCE {
+            ShotClass::Breakeven
+        } else if self.multiplier > 1.0 {
+            ShotClass::Win
+        } else {
+            ShotClass::Loss
+        }
+    }
+}
+
+/// A shot known to carry a timestamp
+///
+/// Input to timing analyses such as `anti_cheat::detect_bot_timing`, which
+/// need every shot's time rather than an optional one. The time lives in the
+/// wrapped shot's `timestamp_ms`, which is always set. Serializes as the
+/// shot, and deserializing rejects a shot without a timestamp.
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+#[serde(try_from = "ShotOutcome", into = "ShotOutcome")]
+pub struct TimedShot {
+    shot: ShotOutcome,
+}
+
+impl TimedShot {
+    /// Wrap a shot, stamping it with `timestamp_ms`
+    pub fn new(mut shot: ShotOutcome, timestamp_ms: u64) -> Self {
+        shot.timestamp_ms = Some(timestamp_ms);
+        TimedShot { shot }
+    }
+
+    /// Wrap a shot using its own timestamp, or None if it has none
+    pub fn from_shot(shot: ShotOutcome) -> Option<Self> {
+        shot.timestamp_ms.is_some().then_some(TimedShot { shot })
+    }
+
+    /// The shot itself
+    pub fn shot(&self) -> &ShotOutcome {
+        &self.shot
+    }
+
+    /// When the shot was taken, in milliseconds
+    pub fn timestamp_ms(&self) -> u64 {
+        self.shot.timestamp_ms.expect("TimedShot always carries a timestamp")
+    }
+}
+
+impl TryFrom<ShotOutcome> for TimedShot {
+    type Error = String;
+
+    fn try_from(shot: ShotOutcome) -> Result<Self, Self::Error> {
+        TimedShot::from_shot(shot).ok_or_else(|| "timed shot is missing timestamp_ms".to_string())
+    }
+}
+
+impl From<TimedShot> for ShotOutcome {
+    fn from(timed: TimedShot) -> Self {
+        timed.shot
+    }
+}
+
+/// Dispersion added per mph of wind for every 100 yds of shot distance
+pub const WIND_SIGMA_PER_MPH: f64 = 0.01;
+
+/// Temperature at which `Environment` air density is the reference, °F
+pub const REFERENCE_TEMPERATURE_F: f64 = 70.0;
+
+/// Outdoor conditions that widen a player's dispersion
+///
+/// Wind pushes the ball further off line the longer it is in the air, so the
+/// sigma multiplier grows with both wind speed and hole distance. Colder air
+/// is denser and carries the wind's push further; the effect scales with the
+/// ratio of absolute temperatures to `REFERENCE_TEMPERATURE_F`.
+///
+/// # Formula
+/// multiplier = 1 + WIND_SIGMA_PER_MPH · wind_mph · (distance_yds / 100) · (T_ref / T)
+#[derive(Debug, Clone, Copy, PartialEq, Serialize, Deserialize)]
+pub struct Environment {
+    /// Wind speed in mph
+    pub wind_mph: f64,
+    /// Air temperature in °F
+    pub temperature_f: f64,
+}
+
+impl Default for Environment {
+    fn default() -> Self {
+        Environment {
+            wind_mph: 0.0,
+            temperature_f: REFERENCE_TEMPERATURE_F,
+        }
+    }
+}
+
+impl Environment {
+    /// Factor applied to a player's sigma on a hole `distance_yds` long
+    ///
+    /// # Example
+    /// ```
+    /// use continuum_golf_simulator::models::shot::Environment;
+    ///
+    /// let windy = Environment { wind_mph: 20.0, ..Default::default() };
+    /// assert!((windy.sigma_multiplier(250) - 1.5).abs() < 1e-12);
+    /// assert_eq!(Environment::default().sigma_multiplier(250), 1.0);
+    /// ```
+    pub fn sigma_multiplier(&self, distance_yds: u16) -> f64 {
+        const FAHRENHEIT_TO_RANKINE: f64 = 459.67;
+        let density_ratio =
+            (REFERENCE_TEMPERATURE_F + FAHRENHEIT_TO_RANKINE) / (self.temperature_f + FAHRENHEIT_TO_RANKINE);
+        1.0 + WIND_SIGMA_PER_MPH * self.wind_mph.max(0.0) * (distance_yds as f64 / 100.0) * density_ratio
+    }
+}
+
+/// Reporting class for a single shot outcome
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Serialize, Deserialize)]
+pub enum ShotClass {
+    /// Landed within an inch of the pin
+    Ace,
+    /// Multiplier of 5× or more
+    BigWin,
+    /// Payout exceeded the wager (1× < multiplier < 5×)
+    Win,
+    /// Payout exactly returned the wager (1×)
+    Breakeven,
+    /// Payout below the wager
+    Loss,
+    /// Fat-tail mishit with zero payout
+    Blowup,
+}
+
+/// Count shots per reporting class
+///
+/// Every class is present in the returned map, with a count of 0 if no shot matched.
+pub fn shot_class_histogram(shots: &[ShotOutcome]) -> HashMap<ShotClass, usize> {
+    let mut histogram: HashMap<ShotClass, usize> = [
+        ShotClass::Ace,
+        ShotClass::BigWin,
+        ShotClass::Win,
+        ShotClass::Breakeven,
+        ShotClass::Loss,
+        ShotClass::Blowup,
+    ]
+    .iter()
+    .map(|class| (*class, 0))
+    .collect();
+
+    for shot in shots {
+        *histogram.entry(shot.classify()).or_insert(0) += 1;
+    }
+
+    histogram
 }
 
 /// Simulate a shot with optional fat-tail behavior
@@ -93,6 +277,142 @@ pub fn simulate_shot(sigma: f64, fat_tail_prob: f64, fat_tail_mult: f64) -> (f64
     fat_tail_shot(sigma, fat_tail_prob, fat_tail_mult)
 }
 
+/// Simulate a shot using the supplied random number generator
+///
+/// Same as [`simulate_shot`], but draws from `rng` so seeded sessions are
+/// reproducible.
+pub fn simulate_shot_with_rng<R: Rng + ?Sized>(
+    sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+    rng: &mut R,
+) -> (f64, bool) {
+    fat_tail_shot_with_rng(sigma, fat_tail_prob, fat_tail_mult, rng)
+}
+
+/// Simulate a shot whose miss distance follows `model`
+///
+/// Same fat-tail logic as [`simulate_shot_with_rng`], with the miss drawn
+/// from `model` at the (possibly widened) sigma. `MissModel::Rayleigh`
+/// reproduces `simulate_shot_with_rng` draw for draw.
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::math::distributions::MissModel;
+/// use continuum_golf_simulator::models::shot::simulate_shot_with_model;
+///
+/// let model = MissModel::Weibull { shape: 1.5 };
+/// let (miss, _) = simulate_shot_with_model(30.0, 0.02, 3.0, model, &mut rand::thread_rng());
+/// assert!(miss >= 0.0);
+/// ```
+pub fn simulate_shot_with_model<R: Rng + ?Sized>(
+    sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+    model: MissModel,
+    rng: &mut R,
+) -> (f64, bool) {
+    let is_fat_tail = rng.gen::<f64>() < fat_tail_prob;
+    let sigma = if is_fat_tail { sigma * fat_tail_mult } else { sigma };
+    (model.sample_with_rng(sigma, rng), is_fat_tail)
+}
+
+/// Simulate a shot whose miss distance is clamped to a physical bound
+///
+/// See [`fat_tail_shot_capped_with_rng`]; `None` behaves like
+/// [`simulate_shot_with_rng`].
+pub fn simulate_shot_capped_with_rng<R: Rng + ?Sized>(
+    sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+    max_miss_distance: Option<f64>,
+    rng: &mut R,
+) -> (f64, bool) {
+    fat_tail_shot_capped_with_rng(sigma, fat_tail_prob, fat_tail_mult, max_miss_distance, rng)
+}
+
+/// Simulate an antithetic pair of shots
+///
+/// The second shot reuses the first shot's uniform draws as 1 - U, so a
+/// close first shot is paired with a wide second one. Each shot on its own
+/// has the usual distribution, but payouts within a pair are negatively
+/// correlated, which lowers the variance of averages (e.g. RTP estimates).
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::models::shot::simulate_shot_antithetic;
+///
+/// let [(first, _), (second, _)] = simulate_shot_antithetic(30.0, 0.02, 3.0);
+/// assert!(first >= 0.0 && second >= 0.0);
+/// ```
+pub fn simulate_shot_antithetic(sigma: f64, fat_tail_prob: f64, fat_tail_mult: f64) -> [(f64, bool); 2] {
+    simulate_shot_antithetic_with_rng(sigma, fat_tail_prob, fat_tail_mult, &mut rand::thread_rng())
+}
+
+/// Simulate an antithetic pair of shots using the supplied random number generator
+///
+/// Same as [`simulate_shot_antithetic`], but draws from `rng`.
+pub fn simulate_shot_antithetic_with_rng<R: Rng + ?Sized>(
+    sigma: f64,
+    fat_tail_prob: f64,
+    fat_tail_mult: f64,
+    rng: &mut R,
+) -> [(f64, bool); 2] {
+    let roll: f64 = rng.gen();
+    let u: f64 = rng.gen();
+    [
+        fat_tail_shot_from_uniforms(sigma, fat_tail_prob, fat_tail_mult, roll, u),
+        fat_tail_shot_from_uniforms(sigma, fat_tail_prob, fat_tail_mult, 1.0 - roll, 1.0 - u),
+    ]
+}
+
+/// Simulate a shot's landing point with directional dispersion and bias
+///
+/// The offsets from the pin are independent normals: dx ~ N(bias_x, σx²)
+/// across the target line and dy ~ N(bias_y, σy²) along it. With equal
+/// sigmas and no bias the radial distance is Rayleigh(σ), i.e. the same as
+/// [`simulate_standard_shot`]. The radial distance is what
+/// `Hole::calculate_payout` takes.
+///
+/// # Returns
+/// Tuple of (dx, dy, radial_distance) in feet
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::models::shot::simulate_shot_2d;
+///
+/// // A slicer: misses drift 8 ft right on average
+/// let (dx, dy, miss) = simulate_shot_2d(20.0, 15.0, 8.0, 0.0);
+/// assert!((miss - dx.hypot(dy)).abs() < 1e-12);
+/// ```
+pub fn simulate_shot_2d(sigma_x: f64, sigma_y: f64, bias_x: f64, bias_y: f64) -> (f64, f64, f64) {
+    simulate_shot_2d_with_rng(sigma_x, sigma_y, bias_x, bias_y, &mut rand::thread_rng())
+}
+
+/// Simulate a 2D shot using the supplied random number generator
+///
+/// Same as [`simulate_shot_2d`], but draws from `rng`.
+pub fn simulate_shot_2d_with_rng<R: Rng + ?Sized>(
+    sigma_x: f64,
+    sigma_y: f64,
+    bias_x: f64,
+    bias_y: f64,
+    rng: &mut R,
+) -> (f64, f64, f64) {
+    let dx = normal_random_with_rng(bias_x, sigma_x, NormalSampler::default(), rng);
+    let dy = normal_random_with_rng(bias_y, sigma_y, NormalSampler::default(), rng);
+    (dx, dy, dx.hypot(dy))
+}
+
+/// Rayleigh sigma with the same mean squared miss as a 2D dispersion
+///
+/// E[dx² + dy²] = σx² + σy² + bias_x² + bias_y², and a Rayleigh(σ) miss has
+/// E[d²] = 2σ², so σ = √((σx² + σy² + bias_x² + bias_y²) / 2). This is the
+/// scalar skill the Kalman filter tracks for a player with a biased pattern.
+pub fn equivalent_rayleigh_sigma(sigma_x: f64, sigma_y: f64, bias_x: f64, bias_y: f64) -> f64 {
+    ((sigma_x.powi(2) + sigma_y.powi(2) + bias_x.powi(2) + bias_y.powi(2)) / 2.0).sqrt()
+}
+
 /// Simulate a standard shot without fat-tail behavior
 ///
 /// # Arguments
@@ -112,6 +432,10 @@ pub fn simulate_standard_shot(sigma: f64) -> f64 {
     rayleigh_random(sigma)
 }
 
+/// Default high-stakes trigger: a wager at least this many times the batch
+/// average forces an immediate skill update
+pub const DEFAULT_HIGH_STAKES_MULTIPLIER: f64 = 10.0;
+
 /// Batch of shot records for skill updates
 ///
 /// Used to accumulate shots before triggering a Kalman filter update
@@ -121,6 +445,9 @@ pub struct ShotBatch {
     pub shots: Vec<(f64, f64)>,
     /// Maximum batch size before triggering update
     pub max_size: usize,
+    /// Multiple of the average wager that counts as high-stakes
+    /// (default: `DEFAULT_HIGH_STAKES_MULTIPLIER`)
+    pub high_stakes_multiplier: f64,
 }
 
 impl ShotBatch {
@@ -132,6 +459,7 @@ impl ShotBatch {
         ShotBatch {
             shots: Vec::with_capacity(max_size),
             max_size,
+            high_stakes_multiplier: DEFAULT_HIGH_STAKES_MULTIPLIER,
         }
     }
 
@@ -145,7 +473,8 @@ impl ShotBatch {
         self.shots.len() >= self.max_size
     }
 
-    /// Check if batch contains a high-stakes shot (≥10× average wager)
+    /// Check if a new wager is high-stakes (≥ `high_stakes_multiplier` ×
+    /// the batch's average wager, 10× by default)
     ///
     /// High-stakes shots trigger immediate updates
     pub fn has_high_stakes_shot(&self, new_wager: f64) -> bool {
@@ -156,7 +485,7 @@ impl ShotBatch {
         let avg_wager: f64 = self.shots.iter().map(|(_, w)| w).sum::<f64>()
             / self.shots.len() as f64;
 
-        new_wager >= 10.0 * avg_wager
+        new_wager >= self.high_stakes_multiplier * avg_wager
     }
 
     /// Clear all shots from batch
@@ -226,6 +555,47 @@ mod tests {
         assert!(!near_ace.is_ace());
     }
 
+    #[test]
+    fn test_timed_shot_keeps_one_timestamp() {
+        let timed = TimedShot::new(ShotOutcome::new(20.0, 1.0, 10.0, 4, false), 1_500);
+        assert_eq!(timed.timestamp_ms(), 1_500);
+        assert_eq!(timed.shot().timestamp_ms, Some(1_500));
+        assert!(TimedShot::from_shot(ShotOutcome::new(20.0, 1.0, 10.0, 4, false)).is_none());
+
+        // Serialized as the shot; a shot without a time is rejected
+        let json = serde_json::to_string(&timed).unwrap();
+        assert_eq!(serde_json::from_str::<ShotOutcome>(&json).unwrap(), *timed.shot());
+        assert_eq!(serde_json::from_str::<TimedShot>(&json).unwrap(), timed);
+        let untimed = serde_json::to_string(&ShotOutcome::new(20.0, 1.0, 10.0, 4, false)).unwrap();
+        assert!(serde_json::from_str::<TimedShot>(&untimed).is_err());
+    }
+
+    #[test]
+    fn test_classify_and_histogram() {
+        let shots = vec![
+            ShotOutcome::new(0.05, 12.0, 10.0, 4, false), // Ace
+            ShotOutcome::new(2.0, 7.5, 10.0, 4, false),   // BigWin
+            ShotOutcome::new(2.5, 5.0, 10.0, 4, false),   // BigWin (exactly 5×)
+            ShotOutcome::new(8.0, 2.0, 10.0, 4, false),   // Win
+            ShotOutcome::new(15.0, 1.0, 10.0, 4, false),  // Breakeven
+            ShotOutcome::new(30.0, 0.4, 10.0, 4, false),  // Loss
+            ShotOutcome::new(60.0, 0.0, 10.0, 4, false),  // Loss (not fat-tail)
+            ShotOutcome::new(140.0, 0.0, 10.0, 4, true),  // Blowup
+        ];
+
+        assert_eq!(shots[0].classify(), ShotClass::Ace);
+        assert_eq!(shots[7].classify(), ShotClass::Blowup);
+
+        let histogram = shot_class_histogram(&shots);
+        assert_eq!(histogram[&ShotClass::Ace], 1);
+        assert_eq!(histogram[&ShotClass::BigWin], 2);
+        assert_eq!(histogram[&ShotClass::Win], 1);
+        assert_eq!(histogram[&ShotClass::Breakeven], 1);
+        assert_eq!(histogram[&ShotClass::Loss], 2);
+        assert_eq!(histogram[&ShotClass::Blowup], 1);
+        assert_eq!(histogram.values().sum::<usize>(), shots.len());
+    }
+
     #[test]
     fn test_simulate_shot_produces_valid_distances() {
         // Run 100 simulations to ensure all are valid
@@ -270,6 +640,49 @@ mod tests {
             "Fat-tail frequency was {}, expected ~0.02", frequency);
     }
 
+    #[test]
+    fn test_rayleigh_miss_model_matches_simulate_shot() {
+        use rand::{rngs::StdRng, SeedableRng};
+
+        let mut model_rng = StdRng::seed_from_u64(299);
+        let mut shot_rng = StdRng::seed_from_u64(299);
+        for _ in 0..200 {
+            assert_eq!(
+                simulate_shot_with_model(30.0, 0.02, 3.0, MissModel::Rayleigh, &mut model_rng),
+                simulate_shot_with_rng(30.0, 0.02, 3.0, &mut shot_rng)
+            );
+        }
+    }
+
+    #[test]
+    fn test_simulate_shot_2d() {
+        use crate::math::distributions::rayleigh_mean;
+        use crate::test_support::{assert_within_ci, mean_and_std_error};
+        use rand::rngs::StdRng;
+        use rand::SeedableRng;
+
+        let mut rng = StdRng::seed_from_u64(261);
+        let n = 20_000;
+
+        // Unbiased and circular: Rayleigh(σ)
+        let circular: Vec<f64> = (0..n).map(|_| simulate_shot_2d_with_rng(20.0, 20.0, 0.0, 0.0, &mut rng).2).collect();
+        let (mean, se) = mean_and_std_error(&circular);
+        assert_within_ci(mean, rayleigh_mean(20.0), se, 4.0);
+        assert_eq!(equivalent_rayleigh_sigma(20.0, 20.0, 0.0, 0.0), 20.0);
+
+        // A slice shifts dx, not dy, and widens the radial miss
+        let shots: Vec<(f64, f64, f64)> = (0..n).map(|_| simulate_shot_2d_with_rng(15.0, 10.0, 12.0, 0.0, &mut rng)).collect();
+        let (mean_dx, se_dx) = mean_and_std_error(&shots.iter().map(|s| s.0).collect::<Vec<_>>());
+        let (mean_dy, se_dy) = mean_and_std_error(&shots.iter().map(|s| s.1).collect::<Vec<_>>());
+        assert_within_ci(mean_dx, 12.0, se_dx, 4.0);
+        assert_within_ci(mean_dy, 0.0, se_dy, 4.0);
+
+        let sigma = equivalent_rayleigh_sigma(15.0, 10.0, 12.0, 0.0);
+        let squares: Vec<f64> = shots.iter().map(|s| s.2 * s.2).collect();
+        let (mean_sq, se_sq) = mean_and_std_error(&squares);
+        assert_within_ci(mean_sq, 2.0 * sigma * sigma, se_sq, 4.0);
+    }
+
     #[test]
     fn test_shot_batch_creation() {
         let batch = ShotBatch::new(5);
@@ -304,6 +717,10 @@ mod tests {
         assert!(!batch.has_high_stakes_shot(40.0));
         assert!(batch.has_high_stakes_shot(50.0));
         assert!(batch.has_high_stakes_shot(100.0));
+
+        batch.high_stakes_multiplier = 5.0;
+        assert!(batch.has_high_stakes_shot(25.0));
+        assert!(!batch.has_high_stakes_shot(24.0));
     }
 
     #[test]
@@ -0,0 +1,225 @@
+//! Multi-day Campaign Simulator
+//!
+//! Projects a promotional campaign over several days:
+//! - A fraction of each day's players return the next day
+//! - Returning players keep their skill profiles (Kalman state) between visits
+//! - New players arrive every day from a player archetype
+//! - Per-day economics and skill-confidence tracking
+
+use crate::models::player::Player;
+use crate::simulators::assert_finite;
+use crate::simulators::player_session::{run_session_with_rng, SessionConfig};
+use crate::simulators::venue::{generate_player_pool_with_rng, PlayerArchetype};
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
+use serde::{Deserialize, Serialize};
+
+/// Economics and player mix for one campaign day
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignDay {
+    /// Day number (1-based)
+    pub day: usize,
+    /// Players returning from earlier days
+    pub returning_players: usize,
+    /// Players visiting for the first time
+    pub new_players: usize,
+    /// Total amount wagered during the day
+    pub total_wagered: f64,
+    /// Total payouts during the day
+    pub total_won: f64,
+    /// House profit for the day (wagered - payouts)
+    pub net_profit: f64,
+    /// Hold percentage (profit / wagered)
+    pub hold_percentage: f64,
+    /// Mean skill confidence (0-100%) of returning players after the day's session
+    pub returning_confidence: Option<f64>,
+    /// Mean skill confidence (0-100%) of new players after the day's session
+    pub new_confidence: Option<f64>,
+}
+
+/// Results from a multi-day campaign
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct CampaignResult {
+    /// Per-day results in order
+    pub days: Vec<CampaignDay>,
+    /// Total amount wagered across the campaign
+    pub total_wagered: f64,
+    /// Total payouts across the campaign
+    pub total_won: f64,
+    /// House profit across the campaign
+    pub net_profit: f64,
+    /// Hold percentage across the campaign
+    pub hold_percentage: f64,
+}
+
+/// Simulate a multi-day campaign with player retention
+///
+/// Every day, `daily_new_players` players drawn from `archetype` join the
+/// players retained from the day before, and everyone plays one session
+/// under `config`. Afterwards each player independently returns the next day
+/// with probability `retention_rate`, carrying their `Player` (and so their
+/// Kalman state and wager history) forward.
+///
+/// # Arguments
+/// * `days` - Campaign length in days
+/// * `retention_rate` - Probability that a player returns the next day (0.0-1.0)
+/// * `daily_new_players` - First-time players arriving each day
+/// * `archetype` - Population that new players are drawn from
+/// * `config` - Session configuration; `config.seed` makes the campaign reproducible
+pub fn run_campaign(
+    days: usize,
+    retention_rate: f64,
+    daily_new_players: usize,
+    archetype: &PlayerArchetype,
+    config: SessionConfig,
+) -> CampaignResult {
+    match config.seed {
+        Some(seed) => run_campaign_with_rng(
+            days,
+            retention_rate,
+            daily_new_players,
+            archetype,
+            config,
+            &mut StdRng::seed_from_u64(seed),
+        ),
+        None => run_campaign_with_rng(
+            days,
+            retention_rate,
+            daily_new_players,
+            archetype,
+            config,
+            &mut rand::thread_rng(),
+        ),
+    }
+}
+
+/// Simulate a multi-day campaign drawing all randomness from `rng`
+///
+/// Same as [`run_campaign`]; `config.seed` is ignored here.
+pub fn run_campaign_with_rng(
+    days: usize,
+    retention_rate: f64,
+    daily_new_players: usize,
+    archetype: &PlayerArchetype,
+    config: SessionConfig,
+    rng: &mut impl Rng,
+) -> CampaignResult {
+    let retention_rate = retention_rate.clamp(0.0, 1.0);
+    let mut returning: Vec<Player> = Vec::new();
+    let mut day_results = Vec::with_capacity(days);
+
+    for day in 1..=days {
+        let mut arrivals = generate_player_pool_with_rng(archetype, daily_new_players, rng);
+        for player in &mut arrivals {
+            player.id = format!("day{}_{}", day, player.id);
+        }
+
+        let returning_players = returning.len();
+        let mut visitors = std::mem::take(&mut returning);
+        visitors.extend(arrivals);
+
+        let mut total_wagered = 0.0;
+        let mut total_won = 0.0;
+        for player in &mut visitors {
+            let result = run_session_with_rng(player, config.clone(), rng);
+            total_wagered += result.total_wagered;
+            total_won += result.total_won;
+        }
+
+        let net_profit = total_wagered - total_won;
+        let hold_percentage = if total_wagered > 0.0 { net_profit / total_wagered } else { 0.0 };
+        assert_finite("run_campaign", &format!("day {} total_wagered", day), total_wagered);
+        assert_finite("run_campaign", &format!("day {} total_won", day), total_won);
+
+        day_results.push(CampaignDay {
+            day,
+            returning_players,
+            new_players: visitors.len() - returning_players,
+            total_wagered,
+            total_won,
+            net_profit,
+            hold_percentage,
+            returning_confidence: mean_confidence(&visitors[..returning_players]),
+            new_confidence: mean_confidence(&visitors[returning_players..]),
+        });
+
+        returning = visitors
+            .into_iter()
+            .filter(|_| rng.gen_bool(retention_rate))
+            .collect();
+    }
+
+    let total_wagered: f64 = day_results.iter().map(|day| day.total_wagered).sum();
+    let total_won: f64 = day_results.iter().map(|day| day.total_won).sum();
+    let net_profit = total_wagered - total_won;
+
+    CampaignResult {
+        days: day_results,
+        total_wagered,
+        total_won,
+        net_profit,
+        hold_percentage: if total_wagered > 0.0 { net_profit / total_wagered } else { 0.0 },
+    }
+}
+
+/// Mean skill confidence over every player and club category (None if no players)
+fn mean_confidence(players: &[Player]) -> Option<f64> {
+    let confidences: Vec<f64> = players
+        .iter()
+        .flat_map(|player| player.skill_profiles.values())
+        .map(|profile| profile.kalman_filter.calculate_confidence())
+        .collect();
+    if confidences.is_empty() {
+        return None;
+    }
+    Some(confidences.iter().sum::<f64>() / confidences.len() as f64)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn campaign_config() -> SessionConfig {
+        SessionConfig {
+            num_shots: 40,
+            seed: Some(240),
+            ..Default::default()
+        }
+    }
+
+    #[test]
+    fn test_retained_players_are_more_confident() {
+        let result = run_campaign(
+            3,
+            0.6,
+            25,
+            &PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 },
+            campaign_config(),
+        );
+
+        assert_eq!(result.days.len(), 3);
+        assert_eq!(result.days[0].returning_players, 0);
+        assert_eq!(result.days[0].returning_confidence, None);
+
+        for day in &result.days[1..] {
+            assert!(day.returning_players > 0, "{:?}", day);
+            assert_eq!(day.new_players, 25);
+            let returning = day.returning_confidence.unwrap();
+            let new = day.new_confidence.unwrap();
+            assert!(returning > new, "Day {}: returning {:.1}% vs new {:.1}%", day.day, returning, new);
+        }
+
+        let total: f64 = result.days.iter().map(|day| day.net_profit).sum();
+        assert!((result.net_profit - total).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_no_retention_means_only_new_players() {
+        let result = run_campaign(3, 0.0, 10, &PlayerArchetype::Uniform, campaign_config());
+
+        for day in &result.days {
+            assert_eq!(day.returning_players, 0);
+            assert_eq!(day.new_players, 10);
+        }
+    }
+}
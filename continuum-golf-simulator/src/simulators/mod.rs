@@ -1,5 +1,53 @@
-// Simulation engines for player sessions, venue economics, and tournaments
+// Simulation engines for player sessions, venue economics, tournaments and
+// multi-day campaigns
 
 pub mod player_session;
 pub mod venue;
 pub mod tournament;
+pub mod campaign;
+
+pub use player_session::{run_session_cohort, SessionState};
+pub use campaign::run_campaign;
+
+use std::sync::atomic::{AtomicBool, Ordering};
+use std::sync::Arc;
+
+/// Shots a session plays between checks of its `CancelToken`
+pub(crate) const CANCEL_CHECK_INTERVAL: usize = 1000;
+
+/// Shared flag for stopping a long-running simulation from another thread
+///
+/// Clones share the flag, so a GUI can keep one clone and hand another to
+/// the simulation. Simulations check it periodically rather than per shot.
+#[derive(Debug, Clone, Default)]
+pub struct CancelToken(Arc<AtomicBool>);
+
+impl CancelToken {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Ask every simulation holding a clone of this token to stop
+    pub fn cancel(&self) {
+        self.0.store(true, Ordering::Relaxed);
+    }
+
+    pub fn is_cancelled(&self) -> bool {
+        self.0.load(Ordering::Relaxed)
+    }
+}
+
+/// Panic if a finalized result field is NaN or infinite
+///
+/// Called where results are assembled so a bad value is reported at its
+/// source instead of surfacing as "NaN" in an export or table.
+#[track_caller]
+pub(crate) fn assert_finite(context: &str, field: &str, value: f64) {
+    assert!(
+        value.is_finite(),
+        "{}: {} is {} (expected a finite value)",
+        context,
+        field,
+        value
+    );
+}
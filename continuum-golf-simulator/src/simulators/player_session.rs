@@ -7,15 +7,31 @@
 //! - Batch processing and high-stakes shot detection
 //! - Developer mode for manual testing
 
+use crate::math::distributions::{
+    fat_tail_shot_from_uniforms, normal_random_with_rng, percentile, MissModel, NormalSampler,
+};
+use crate::math::kalman::is_diverging;
+use crate::simulators::{assert_finite, CancelToken, CANCEL_CHECK_INTERVAL};
 use crate::models::{
-    hole::{get_hole_by_id, Hole, HOLE_CONFIGURATIONS},
+    hole::{ClubCategory, Hole, HoleSet, JackpotState, PayoutModel},
     player::Player,
-    shot::{simulate_shot, ShotOutcome},
+    shot::{
+        shot_class_histogram, simulate_shot_2d_with_rng, simulate_shot_with_model, Environment, ShotClass,
+        ShotOutcome,
+    },
+};
+use crate::anti_cheat::{
+    detect_cherry_picking_with_config, detect_sandbagging_with_config, AnomalyReport,
+    AntiCheatConfig,
 };
-use crate::anti_cheat::{detect_cherry_picking, detect_sandbagging, AnomalyReport};
-use rand::Rng;
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
-use std::collections::HashMap;
+use sha2::{Digest, Sha256};
+use std::collections::{BTreeMap, HashMap};
+use std::error::Error;
 
 /// Configuration for a player gaming session
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -34,6 +50,126 @@ pub struct SessionConfig {
     pub fat_tail_prob: f64,
     /// Fat-tail multiplier (default: 3.0)
     pub fat_tail_mult: f64,
+    /// Anti-cheat detection sensitivity
+    #[serde(default)]
+    pub anti_cheat: AntiCheatConfig,
+    /// Optional RNG seed; when set, `run_session` and a `SessionState`'s
+    /// `play_shot` are reproducible
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Fraction of each positive payout retained by the house (default: 0.0)
+    ///
+    /// Lets posted odds be fair (RTP = 1.0) with the edge taken as a vig on
+    /// winnings instead; the effective player RTP becomes (1 - vig) × RTP.
+    #[serde(default)]
+    pub winnings_vig: f64,
+    /// Loyalty comps earned per dollar wagered, in dollars (default: 0.0)
+    #[serde(default)]
+    pub comp_rate: f64,
+    /// Physical bound on a simulated miss in feet (default: unbounded)
+    ///
+    /// Longer misses are clamped to this distance. Keep it beyond the largest
+    /// hole `d_max` (101 ft) so clamped misses still pay zero; P_max is
+    /// computed from the unclamped distribution either way.
+    #[serde(default)]
+    pub max_miss_distance: Option<f64>,
+    /// Standard deviation of the bay sensor's distance error in feet (default: 0.0)
+    ///
+    /// Gaussian noise is added to each true miss (clamped at 0) and the
+    /// measured value drives both the payout and the Kalman update.
+    #[serde(default)]
+    pub sensor_noise_std: f64,
+    /// Number of promotional onboarding shots at the start of the session (default: 0)
+    ///
+    /// Each of the first `onboarding_shots` shots pays at least the wager back
+    /// (multiplier floored at 1.0); the house's subsidy is reported as
+    /// `SessionResult::onboarding_cost`. Skill estimation is unaffected.
+    #[serde(default)]
+    pub onboarding_shots: usize,
+    /// Strength of the link between wager size and skill confidence, 0.0-1.0 (default: 0.0)
+    ///
+    /// At 0.0 wagers are uniform over [wager_min, wager_max]. Higher values
+    /// pull each wager toward the part of the range matching the confidence
+    /// rank of the shot's category: the player's most certain category is bet
+    /// near `wager_max`, the least certain near `wager_min`. At 1.0 the wager
+    /// is fully determined by the rank.
+    #[serde(default)]
+    pub wager_confidence_link: f64,
+    /// Holes the venue offers (default: the 8 standard holes)
+    ///
+    /// Hole ids in `hole_selection` refer to this set.
+    #[serde(default)]
+    pub holes: HoleSet,
+    /// House liability limit on a single shot's payout in dollars (default: none)
+    ///
+    /// A shot whose multiplier × wager exceeds the cap pays the cap instead
+    /// (before any vig); see `Hole::calculate_capped_payout`.
+    #[serde(default)]
+    pub max_payout_dollars: Option<f64>,
+    /// Draw simulated shots in antithetic pairs (default: false)
+    ///
+    /// Every second shot reuses the previous shot's uniform draws as 1 - U
+    /// (see `simulate_shot_antithetic`), reducing the variance of session
+    /// totals without changing their expectation. Pairs span consecutive
+    /// shots, whichever holes they are played on. Shots of a category with a
+    /// directional bias are drawn in 2D and not paired.
+    #[serde(default)]
+    pub use_antithetic: bool,
+    /// Progressive jackpot at the start of the session (default: empty, no contributions)
+    ///
+    /// Every shot adds `contribution_rate × wager` to the pool, and an ace
+    /// (`miss < ACE_DISTANCE_FT`) wins the whole pool on top of its payout.
+    #[serde(default)]
+    pub jackpot: JackpotState,
+    /// Player's money at the start of the session in dollars (default: unlimited)
+    ///
+    /// When set, each shot's wager is debited and its winnings credited, and
+    /// `run_session` ends early once the balance can't cover the next wager.
+    #[serde(default)]
+    pub starting_bankroll: Option<f64>,
+    /// How `run_session` sizes each wager (default: `Flat`)
+    #[serde(default)]
+    pub betting_strategy: BettingStrategy,
+    /// Outdoor conditions widening simulated shots (default: none, indoor)
+    ///
+    /// Scales the player's true sigma by `Environment::sigma_multiplier` for
+    /// each hole's distance. The house's P_max still comes from the skill
+    /// estimate, so wind lowers the player's RTP.
+    #[serde(default)]
+    pub environment: Option<Environment>,
+    /// Shots per Kalman batch, applied to every skill profile when
+    /// `run_session` starts (default: 5, as in `Player::new`; at least 1)
+    ///
+    /// Larger batches smooth the skill updates, smaller ones react faster.
+    #[serde(default = "default_kalman_batch_size")]
+    pub kalman_batch_size: usize,
+    /// High-stakes multiple of the reference wager (default: 2.0; must be
+    /// positive)
+    ///
+    /// A shot wagering at least this multiple of the player's lifetime or
+    /// session average wager, whichever is larger, flushes the batch and
+    /// updates the skill estimate immediately. Lower values make the filter
+    /// react to smaller raises. Separate from the player's
+    /// `SkillProfile::high_stakes_multiplier` (10× the batch average), which
+    /// the session leaves unchanged.
+    #[serde(default = "default_session_high_stakes_multiplier")]
+    pub session_high_stakes_multiplier: f64,
+    /// Distribution of simulated misses (default: Rayleigh)
+    ///
+    /// Applied to the player with `Player::set_miss_model` when `run_session`
+    /// starts, so P_max is priced for the same distribution the shots are
+    /// drawn from. Directional (biased) and antithetic shots are always
+    /// drawn as Rayleigh.
+    #[serde(default)]
+    pub miss_model: MissModel,
+}
+
+fn default_kalman_batch_size() -> usize {
+    5
+}
+
+fn default_session_high_stakes_multiplier() -> f64 {
+    2.0
 }
 
 impl Default for SessionConfig {
@@ -46,8 +182,248 @@ impl Default for SessionConfig {
             developer_mode: None,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            anti_cheat: AntiCheatConfig::default(),
+            seed: None,
+            winnings_vig: 0.0,
+            comp_rate: 0.0,
+            max_miss_distance: None,
+            sensor_noise_std: 0.0,
+            onboarding_shots: 0,
+            wager_confidence_link: 0.0,
+            holes: HoleSet::standard(),
+            max_payout_dollars: None,
+            use_antithetic: false,
+            jackpot: JackpotState::default(),
+            starting_bankroll: None,
+            betting_strategy: BettingStrategy::Flat,
+            environment: None,
+            kalman_batch_size: default_kalman_batch_size(),
+            session_high_stakes_multiplier: default_session_high_stakes_multiplier(),
+            miss_model: MissModel::Rayleigh,
+        }
+    }
+}
+
+impl SessionConfig {
+    /// Stable 64-bit fingerprint of every configuration field
+    ///
+    /// Suitable as a results-cache key: identical configs always produce the
+    /// same value, across processes and platforms. Floats are hashed by bit
+    /// pattern (so `0.0` and `-0.0` differ) and enum/option variants are
+    /// tagged so different shapes cannot collide by concatenation.
+    pub fn fingerprint(&self) -> u64 {
+        // Destructure so that adding a field fails to compile until it is hashed
+        let SessionConfig {
+            num_shots,
+            wager_min,
+            wager_max,
+            hole_selection,
+            developer_mode,
+            fat_tail_prob,
+            fat_tail_mult,
+            anti_cheat,
+            seed,
+            winnings_vig,
+            comp_rate,
+            max_miss_distance,
+            sensor_noise_std,
+            onboarding_shots,
+            wager_confidence_link,
+            holes,
+            max_payout_dollars,
+            use_antithetic,
+            jackpot,
+            starting_bankroll,
+            betting_strategy,
+            environment,
+            kalman_batch_size,
+            session_high_stakes_multiplier,
+            miss_model,
+        } = self;
+
+        let mut hasher = Fnv1a::new();
+        hasher.write_u64(*num_shots as u64);
+        hasher.write_f64(*wager_min);
+        hasher.write_f64(*wager_max);
+
+        match hole_selection {
+            HoleSelection::Random => hasher.write_u8(0),
+            HoleSelection::Weighted(weights) => {
+                hasher.write_u8(1);
+                hasher.write_u64(weights.len() as u64);
+                for (hole_id, weight) in weights {
+                    hasher.write_u8(*hole_id);
+                    hasher.write_f64(*weight);
+                }
+            }
+            HoleSelection::Fixed(hole_id) => {
+                hasher.write_u8(2);
+                hasher.write_u8(*hole_id);
+            }
+            HoleSelection::RoundRobin { shuffle } => {
+                hasher.write_u8(3);
+                hasher.write_u8(*shuffle as u8);
+            }
+        }
+
+        match developer_mode {
+            None => hasher.write_u8(0),
+            Some(dev_mode) => {
+                hasher.write_u8(1);
+                hasher.write_option_f64(dev_mode.manual_miss_distance);
+                match &dev_mode.manual_miss_sequence {
+                    None => hasher.write_u8(0),
+                    Some(sequence) => {
+                        hasher.write_u8(1);
+                        hasher.write_u64(sequence.len() as u64);
+                        for &miss in sequence {
+                            hasher.write_f64(miss);
+                        }
+                    }
+                }
+                hasher.write_u8(dev_mode.disable_kalman as u8);
+            }
+        }
+
+        hasher.write_f64(*fat_tail_prob);
+        hasher.write_f64(*fat_tail_mult);
+
+        hasher.write_u64(anti_cheat.sandbagging_min_shots as u64);
+        hasher.write_f64(anti_cheat.sandbagging_threshold);
+        hasher.write_u64(anti_cheat.cherry_picking_min_shots as u64);
+        hasher.write_f64(anti_cheat.cherry_picking_threshold);
+        hasher.write_u64(anti_cheat.skill_jump_min_historical_shots as u64);
+        hasher.write_u64(anti_cheat.skill_jump_min_recent_shots as u64);
+        hasher.write_f64(anti_cheat.skill_jump_threshold);
+        hasher.write_u64(anti_cheat.bot_timing_min_shots as u64);
+        hasher.write_f64(anti_cheat.bot_timing_threshold);
+        hasher.write_u64(anti_cheat.betting_pattern_min_shots as u64);
+        hasher.write_f64(anti_cheat.betting_pattern_threshold);
+
+        match seed {
+            None => hasher.write_u8(0),
+            Some(seed) => {
+                hasher.write_u8(1);
+                hasher.write_u64(*seed);
+            }
+        }
+
+        hasher.write_f64(*winnings_vig);
+        hasher.write_f64(*comp_rate);
+        hasher.write_option_f64(*max_miss_distance);
+        hasher.write_f64(*sensor_noise_std);
+        hasher.write_u64(*onboarding_shots as u64);
+        hasher.write_f64(*wager_confidence_link);
+
+        hasher.write_u64(holes.len() as u64);
+        for hole in holes.iter() {
+            hasher.write_u8(hole.id);
+            hasher.write_u64(hole.distance_yds as u64);
+            hasher.write_f64(hole.d_max_ft);
+            hasher.write_f64(hole.rtp);
+            hasher.write_f64(hole.k);
+            match &hole.payout_model {
+                PayoutModel::Continuous => hasher.write_u8(0),
+                PayoutModel::Rings { thresholds } => {
+                    hasher.write_u8(1);
+                    hasher.write_u64(thresholds.len() as u64);
+                    for &(radius, fraction) in thresholds {
+                        hasher.write_f64(radius);
+                        hasher.write_f64(fraction);
+                    }
+                }
+            }
+        }
+        hasher.write_option_f64(*max_payout_dollars);
+        hasher.write_u8(*use_antithetic as u8);
+        hasher.write_f64(jackpot.pool);
+        hasher.write_f64(jackpot.contribution_rate);
+        hasher.write_option_f64(*starting_bankroll);
+        match betting_strategy {
+            BettingStrategy::Flat => hasher.write_u8(0),
+            BettingStrategy::Martingale { base, max } => {
+                hasher.write_u8(1);
+                hasher.write_f64(*base);
+                hasher.write_f64(*max);
+            }
+            BettingStrategy::AntiMartingale { base, max } => {
+                hasher.write_u8(2);
+                hasher.write_f64(*base);
+                hasher.write_f64(*max);
+            }
+            BettingStrategy::Proportional { fraction } => {
+                hasher.write_u8(3);
+                hasher.write_f64(*fraction);
+            }
+        }
+        match environment {
+            None => hasher.write_u8(0),
+            Some(environment) => {
+                hasher.write_u8(1);
+                hasher.write_f64(environment.wind_mph);
+                hasher.write_f64(environment.temperature_f);
+            }
+        }
+        hasher.write_u64(*kalman_batch_size as u64);
+        hasher.write_f64(*session_high_stakes_multiplier);
+        match miss_model {
+            MissModel::Rayleigh => hasher.write_u8(0),
+            MissModel::Weibull { shape } => {
+                hasher.write_u8(1);
+                hasher.write_f64(*shape);
+            }
+            MissModel::Gamma { shape } => {
+                hasher.write_u8(2);
+                hasher.write_f64(*shape);
+            }
+        }
+
+        hasher.finish()
+    }
+}
+
+/// FNV-1a hasher with a fixed basis, used for config fingerprints
+///
+/// `std::collections::hash_map::DefaultHasher` is not guaranteed stable across
+/// Rust releases, so fingerprints use this fixed algorithm instead.
+struct Fnv1a(u64);
+
+impl Fnv1a {
+    const OFFSET_BASIS: u64 = 0xcbf2_9ce4_8422_2325;
+    const PRIME: u64 = 0x0000_0100_0000_01b3;
+
+    fn new() -> Self {
+        Fnv1a(Self::OFFSET_BASIS)
+    }
+
+    fn write_u8(&mut self, byte: u8) {
+        self.0 ^= byte as u64;
+        self.0 = self.0.wrapping_mul(Self::PRIME);
+    }
+
+    fn write_u64(&mut self, value: u64) {
+        for byte in value.to_le_bytes() {
+            self.write_u8(byte);
+        }
+    }
+
+    fn write_f64(&mut self, value: f64) {
+        self.write_u64(value.to_bits());
+    }
+
+    fn write_option_f64(&mut self, value: Option<f64>) {
+        match value {
+            None => self.write_u8(0),
+            Some(v) => {
+                self.write_u8(1);
+                self.write_f64(v);
+            }
         }
     }
+
+    fn finish(&self) -> u64 {
+        self.0
+    }
 }
 
 /// Strategy for selecting which hole to play
@@ -60,6 +436,29 @@ pub enum HoleSelection {
     Weighted(Vec<(u8, f64)>),
     /// Always play the same hole
     Fixed(u8),
+    /// Play each of the 8 holes once per round, cycling when the session is
+    /// longer than one round. Holes go in id order unless `shuffle` is set,
+    /// in which case each round is shuffled independently.
+    RoundRobin { shuffle: bool },
+}
+
+/// How a session sizes its wagers
+///
+/// Progressive strategies size each wager from the previous shot's result
+/// (a loss is a shot paying less than its wager) and ignore the wager range.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum BettingStrategy {
+    /// Draw each wager from [wager_min, wager_max], independent of results
+    #[default]
+    Flat,
+    /// Start at `base`, double after a loss (up to `max`), reset after a win
+    Martingale { base: f64, max: f64 },
+    /// Start at `base`, double after a win (up to `max`), reset after a loss
+    AntiMartingale { base: f64, max: f64 },
+    /// Bet `fraction` of the current bankroll, but at least `wager_min`
+    ///
+    /// Needs `starting_bankroll`; without one, wagers are drawn as for `Flat`.
+    Proportional { fraction: f64 },
 }
 
 /// Developer mode settings for manual testing
@@ -72,16 +471,21 @@ pub struct DeveloperMode {
     /// If set, use this miss distance instead of simulating
     /// ⚠️ CRITICAL: This must never be available in production
     pub manual_miss_distance: Option<f64>,
+    /// If set, shot `n` misses by `sequence[n % len]`, cycling when exhausted;
+    /// takes precedence over `manual_miss_distance`
+    /// ⚠️ CRITICAL: This must never be available in production
+    #[serde(default)]
+    pub manual_miss_sequence: Option<Vec<f64>>,
     /// If true, disable Kalman filter updates (skill stays constant)
     pub disable_kalman: bool,
 }
 
 /// Results from a completed player session
-#[derive(Debug, Clone, Serialize, Deserialize)]
+#[derive(Debug, Clone, Default, Serialize, Deserialize)]
 pub struct SessionResult {
     /// Total amount wagered across all shots
     pub total_wagered: f64,
-    /// Total amount won (payouts) across all shots
+    /// Total amount won (payouts, net of vig) across all shots
     pub total_won: f64,
     /// Net gain or loss (total_won - total_wagered)
     pub net_gain_loss: f64,
@@ -99,9 +503,114 @@ pub struct SessionResult {
     pub cherry_picking_report: Option<AnomalyReport>,
     /// Anti-cheat detection report for sandbagging
     pub sandbagging_report: Option<AnomalyReport>,
+    /// Number of shots in each reporting class
+    pub shot_classes: HashMap<ShotClass, usize>,
+    /// Total vig deducted from winning payouts
+    pub total_vig: f64,
+    /// True if any category's Kalman estimate ran away during the session
+    /// (see `kalman::is_diverging`)
+    pub filter_diverged: bool,
+    /// (shot_index, category, confidence, sigma) captured after each Kalman update
+    ///
+    /// `shot_index` is the 0-based shot that triggered the update; updates from
+    /// the end-of-session batch flush use `shots.len()`. Sigma is the category
+    /// estimate at its reference distance.
+    pub convergence_trajectory: Vec<(usize, ClubCategory, f64, f64)>,
+    /// Loyalty comps accrued (comp_rate × total wagered)
+    pub comps_earned: f64,
+    /// Total subsidy paid to lift onboarding shots to the wager back
+    ///
+    /// Already included in `total_won`.
+    #[serde(default)]
+    pub onboarding_cost: f64,
+    /// Number of shots whose payout was reduced to `max_payout_dollars`
+    #[serde(default)]
+    pub num_capped_shots: usize,
+    /// Jackpot pool left at the end of the session
+    #[serde(default)]
+    pub jackpot_final_pool: f64,
+    /// Number of aces that won the jackpot
+    #[serde(default)]
+    pub num_jackpots_hit: usize,
+    /// Total jackpot winnings
+    ///
+    /// Already included in `total_won`, but not in the per-shot payouts.
+    #[serde(default)]
+    pub jackpot_won: f64,
+    /// Player's balance at the end of the session (0.0 without a starting bankroll)
+    #[serde(default)]
+    pub final_bankroll: f64,
+    /// True if the bankroll ran out: the balance couldn't cover a wager the
+    /// session was about to place, or it ended below `wager_min` (how a live
+    /// `SessionState` session, which places no wagers itself, goes bust).
+    /// Always false without a starting bankroll.
+    #[serde(default)]
+    pub went_bust: bool,
+    /// Shots actually played (fewer than `num_shots` after going bust)
+    #[serde(default)]
+    pub shots_completed: usize,
+    /// Largest peak-to-trough fall in the player's running net, in dollars
+    #[serde(default)]
+    pub max_drawdown: f64,
+    /// Mean factor `config.environment` applied to the player's sigma per shot
+    /// (1.0 without an environment)
+    #[serde(default = "default_sigma_multiplier")]
+    pub sigma_multiplier: f64,
+    /// Skill estimators restarted by drift detection during the session
+    /// (see `SkillProfile::auto_reset_on_drift`)
+    #[serde(default)]
+    pub num_drift_resets: usize,
+    /// `digest()` of the shots as played, for detecting later edits
+    #[serde(default)]
+    pub digest: String,
+}
+
+/// Percentiles reported by `SessionResult::miss_percentiles`
+pub const MISS_PERCENTILES: [u8; 3] = [50, 90, 95];
+
+fn default_sigma_multiplier() -> f64 {
+    1.0
 }
 
 impl SessionResult {
+    /// Miss distances in feet at each of `MISS_PERCENTILES`, keyed by percentile
+    ///
+    /// "Your 90% shot lands within X feet": linearly interpolated over the
+    /// sorted misses of every shot in the session, whichever hole. Compare
+    /// with `rayleigh_quantile(p, sigma)` for the player's expected spread.
+    /// All 0.0 for a session without shots.
+    pub fn miss_percentiles(&self) -> BTreeMap<u8, f64> {
+        let mut misses: Vec<f64> = self.shots.iter().map(|s| s.miss_distance_ft).collect();
+        misses.sort_by(f64::total_cmp);
+        MISS_PERCENTILES
+            .iter()
+            .map(|&p| (p, percentile(&misses, p as f64 / 100.0)))
+            .collect()
+    }
+
+    /// SHA-256 of the shot sequence as lowercase hex
+    ///
+    /// Covers each shot's miss distance, multiplier, payout, wager (as
+    /// little-endian f64 bytes) and hole id, in order, so any edit to a shot
+    /// or to the order changes it. Compare with the stored `digest` field
+    /// after reloading a result; see [`SessionResult::digest_matches`].
+    pub fn digest(&self) -> String {
+        let mut hasher = Sha256::new();
+        for shot in &self.shots {
+            hasher.update(shot.miss_distance_ft.to_le_bytes());
+            hasher.update(shot.multiplier.to_le_bytes());
+            hasher.update(shot.payout.to_le_bytes());
+            hasher.update(shot.wager.to_le_bytes());
+            hasher.update([shot.hole_id]);
+        }
+        hasher.finalize().iter().map(|byte| format!("{:02x}", byte)).collect()
+    }
+
+    /// True if the shots still hash to the stored `digest`
+    pub fn digest_matches(&self) -> bool {
+        self.digest == self.digest()
+    }
+
     /// Calculate session house edge as percentage
     pub fn house_edge_percent(&self) -> f64 {
         if self.total_wagered > 0.0 {
@@ -120,6 +629,11 @@ impl SessionResult {
         }
     }
 
+    /// Number of fat-tail mishits among the shots
+    pub fn fat_tail_count(&self) -> usize {
+        self.shots.iter().filter(|s| s.is_fat_tail).count()
+    }
+
     /// Calculate win rate (percentage of shots with payout > 0)
     pub fn win_rate(&self) -> f64 {
         if self.shots.is_empty() {
@@ -128,6 +642,46 @@ impl SessionResult {
         let wins = self.shots.iter().filter(|s| s.payout > 0.0).count();
         (wins as f64 / self.shots.len() as f64) * 100.0
     }
+
+    /// Sample variance of the per-shot net result (payout - wager)
+    ///
+    /// 0.0 with fewer than two shots. Compares how much holes swing at the
+    /// same wager: the larger a hole's P_max, the rarer and bigger its wins.
+    pub fn net_variance(&self) -> f64 {
+        if self.shots.len() < 2 {
+            return 0.0;
+        }
+        let n = self.shots.len() as f64;
+        let mean = self.shots.iter().map(|s| s.net_result()).sum::<f64>() / n;
+        self.shots.iter().map(|s| (s.net_result() - mean).powi(2)).sum::<f64>() / (n - 1.0)
+    }
+
+    /// Standard deviation of the per-shot net result
+    pub fn net_std_dev(&self) -> f64 {
+        self.net_variance().sqrt()
+    }
+
+    /// Sharpe-like ratio: mean per-shot net / its standard deviation
+    ///
+    /// 0.0 when the net never varies (including sessions under two shots).
+    pub fn risk_adjusted_return(&self) -> f64 {
+        let std_dev = self.net_std_dev();
+        if std_dev > 0.0 {
+            let mean = self.shots.iter().map(|s| s.net_result()).sum::<f64>() / self.shots.len() as f64;
+            mean / std_dev
+        } else {
+            0.0
+        }
+    }
+
+    /// Player RTP counting comps as a rebate: (total_won + comps) / total_wagered
+    pub fn effective_rtp_with_comps(&self) -> f64 {
+        if self.total_wagered > 0.0 {
+            (self.total_won + self.comps_earned) / self.total_wagered
+        } else {
+            0.0
+        }
+    }
 }
 
 /// Run a player gaming session simulation
@@ -139,43 +693,354 @@ impl SessionResult {
 /// # Returns
 /// SessionResult with all shot outcomes and final statistics
 pub fn run_session(player: &mut Player, config: SessionConfig) -> SessionResult {
-    let mut rng = rand::thread_rng();
-    let mut shots = Vec::with_capacity(config.num_shots);
-    let mut total_wagered = 0.0;
-    let mut total_won = 0.0;
-    let mut num_kalman_updates = 0;
-    let mut num_high_stakes_shots = 0;
+    match config.seed {
+        Some(seed) => run_session_with_rng(player, config, &mut StdRng::seed_from_u64(seed)),
+        None => run_session_with_rng(player, config, &mut rand::thread_rng()),
+    }
+}
+
+/// Shots between calls to a [`run_session_with_callback`] progress callback
+pub const PROGRESS_INTERVAL_SHOTS: usize = 100;
 
-    for shot_num in 0..config.num_shots {
-        // Select hole based on strategy
-        let hole = select_hole(&config.hole_selection, &mut rng);
+/// Same as [`run_session`], reporting progress to `on_progress`
+///
+/// The callback receives (shots_done, total) every
+/// `PROGRESS_INTERVAL_SHOTS` shots. It is called once more when the session
+/// ends off an interval, e.g. 250 of 250 or on going bust. With `None` this
+/// is exactly `run_session`, and the only per-shot cost is the `Option`
+/// check.
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::models::player::Player;
+/// use continuum_golf_simulator::simulators::player_session::{run_session_with_callback, SessionConfig};
+///
+/// let mut calls = Vec::new();
+/// let config = SessionConfig { num_shots: 250, seed: Some(7), ..Default::default() };
+/// let on_progress = Box::new(|done: usize, total: usize| calls.push((done, total)));
+/// run_session_with_callback(&mut Player::new("p".to_string(), 15), config, Some(on_progress));
+/// assert_eq!(calls, vec![(100, 250), (200, 250), (250, 250)]);
+/// ```
+pub fn run_session_with_callback(
+    player: &mut Player,
+    config: SessionConfig,
+    mut on_progress: Option<Box<dyn FnMut(usize, usize) + '_>>,
+) -> SessionResult {
+    let on_progress = on_progress.as_deref_mut();
+    match config.seed {
+        Some(seed) => play_session(player, config, &mut StdRng::seed_from_u64(seed), |_| 1.0, None, on_progress),
+        None => play_session(player, config, &mut rand::thread_rng(), |_| 1.0, None, on_progress),
+    }
+}
+
+/// Same as [`run_session`], stopping early once `cancel` is set
+///
+/// The token is checked every `CANCEL_CHECK_INTERVAL` shots; a cancelled
+/// session is finalized with the shots played so far, so `shots_completed`
+/// falls short of `num_shots`.
+pub fn run_session_cancellable(player: &mut Player, config: SessionConfig, cancel: &CancelToken) -> SessionResult {
+    match config.seed {
+        Some(seed) => play_session(player, config, &mut StdRng::seed_from_u64(seed), |_| 1.0, Some(cancel), None),
+        None => play_session(player, config, &mut rand::thread_rng(), |_| 1.0, Some(cancel), None),
+    }
+}
 
-        // Determine wager for this shot
-        let wager = rng.gen_range(config.wager_min..=config.wager_max);
+/// Run a player gaming session drawing all randomness from `rng`
+///
+/// Hole selection, wagers and shot outcomes all come from the supplied
+/// generator, so a seeded `rng` reproduces the session exactly.
+/// `config.seed` is ignored here; the caller's generator takes precedence.
+/// Every skill profile's batch size is set from `config.kalman_batch_size`.
+///
+/// # Panics
+/// If `config.kalman_batch_size` is 0, `config.session_high_stakes_multiplier`
+/// is not positive, or a developer-mode `manual_miss_sequence` is empty
+pub fn run_session_with_rng(
+    player: &mut Player,
+    config: SessionConfig,
+    rng: &mut impl Rng,
+) -> SessionResult {
+    play_session(player, config, rng, |_| 1.0, None, None)
+}
 
-        // Get player's current skill for this hole's category
-        let skill_profile = player.get_skill_for_hole(hole);
-        let current_sigma = skill_profile.kalman_filter.estimate;
+/// The session loop behind every `run_session*` entry point and the venue bays
+///
+/// `wager_scale(i)` scales the wager range and betting-strategy stakes of
+/// the i-th shot (0-based); with a constant 1.0 this is a plain session.
+/// When `cancel` is given it is checked every `CANCEL_CHECK_INTERVAL` shots
+/// and the session ends there with the shots played so far.
+pub(crate) fn play_session(
+    player: &mut Player,
+    config: SessionConfig,
+    rng: &mut impl Rng,
+    wager_scale: impl Fn(usize) -> f64,
+    cancel: Option<&CancelToken>,
+    mut on_progress: Option<&mut (dyn FnMut(usize, usize) + '_)>,
+) -> SessionResult {
+    assert!(config.kalman_batch_size >= 1, "kalman_batch_size must be at least 1");
+    assert!(config.session_high_stakes_multiplier > 0.0, "session_high_stakes_multiplier must be positive");
+    let miss_sequence = config.developer_mode.as_ref().and_then(|dm| dm.manual_miss_sequence.as_ref());
+    assert!(miss_sequence.is_none_or(|seq| !seq.is_empty()), "manual_miss_sequence must not be empty");
+    for skill in player.skill_profiles.values_mut() {
+        skill.batch_size = config.kalman_batch_size;
+    }
+    player.set_miss_model(config.miss_model);
 
-        // Calculate P_max for current skill level
-        let p_max = player.calculate_p_max(hole);
+    let num_shots = config.num_shots;
+    let mut state = SessionState::new(config);
 
-        // Simulate or use manual miss distance
-        let (miss_distance, is_fat_tail) = if let Some(ref dev_mode) = config.developer_mode {
-            if let Some(manual_dist) = dev_mode.manual_miss_distance {
-                (manual_dist, false)
-            } else {
-                simulate_shot(current_sigma, config.fat_tail_prob, config.fat_tail_mult)
+    for shot in 1..=num_shots {
+        if (shot - 1).is_multiple_of(CANCEL_CHECK_INTERVAL) && cancel.is_some_and(CancelToken::is_cancelled) {
+            break;
+        }
+        let hole = state.next_hole_with_rng(rng).clone();
+        let wager = state.next_wager(player, &hole, wager_scale(shot - 1), rng);
+        if !state.can_cover(wager) {
+            state.went_bust = true;
+            break;
+        }
+        state.play_shot_with_rng(player, wager, rng);
+        if let Some(report) = on_progress.as_mut() {
+            if shot.is_multiple_of(PROGRESS_INTERVAL_SHOTS) {
+                report(shot, num_shots);
+            }
+        }
+    }
+
+    let shots_done = state.shots.len();
+    if let Some(report) = on_progress {
+        if !shots_done.is_multiple_of(PROGRESS_INTERVAL_SHOTS) {
+            report(shots_done, num_shots);
+        }
+    }
+
+    state.finalize(player)
+}
+
+/// A session in progress, played one shot at a time
+///
+/// For live play, where shots arrive as they happen rather than from a
+/// pre-set `num_shots` (which is ignored here). Each `play_shot` selects the
+/// hole per `config.hole_selection`, simulates the shot and applies the same
+/// batching, high-stakes detection and Kalman updates as [`run_session`];
+/// `finalize` flushes the remaining batches and builds the `SessionResult`.
+///
+/// `play_shot` and `next_hole` draw from a generator seeded with
+/// `config.seed` when it is set, so a seeded config replays the same
+/// session, and from the thread's generator otherwise. Their `_with_rng`
+/// variants draw from the caller's generator instead.
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::models::player::Player;
+/// use continuum_golf_simulator::simulators::player_session::{SessionConfig, SessionState};
+///
+/// let mut player = Player::new("live".to_string(), 15);
+/// let mut session = SessionState::new(SessionConfig { seed: Some(1), ..Default::default() });
+/// for wager in [5.0, 10.0, 5.0] {
+///     let outcome = session.play_shot(&mut player, wager);
+///     assert_eq!(outcome.wager, wager);
+/// }
+/// let result = session.finalize(&mut player);
+/// assert_eq!(result.shots.len(), 3);
+/// ```
+#[derive(Debug, Clone)]
+pub struct SessionState {
+    config: SessionConfig,
+    rng: Option<StdRng>,
+    shots: Vec<ShotOutcome>,
+    total_wagered: f64,
+    total_won: f64,
+    total_vig: f64,
+    onboarding_cost: f64,
+    num_kalman_updates: usize,
+    num_high_stakes_shots: usize,
+    num_capped_shots: usize,
+    estimate_history: HashMap<ClubCategory, Vec<f64>>,
+    filter_diverged: bool,
+    convergence_trajectory: Vec<(usize, ClubCategory, f64, f64)>,
+    round: Vec<u8>,
+    next_hole: Option<u8>,
+    antithetic_draws: Option<(f64, f64)>,
+    jackpot: JackpotState,
+    num_jackpots_hit: usize,
+    jackpot_won: f64,
+    bankroll: Option<f64>,
+    went_bust: bool,
+    running_net: f64,
+    peak_net: f64,
+    max_drawdown: f64,
+    sigma_multiplier_sum: f64,
+    num_drift_resets: usize,
+}
+
+impl SessionState {
+    /// Start a session
+    pub fn new(config: SessionConfig) -> Self {
+        SessionState {
+            rng: config.seed.map(StdRng::seed_from_u64),
+            jackpot: config.jackpot,
+            bankroll: config.starting_bankroll,
+            config,
+            shots: Vec::new(),
+            total_wagered: 0.0,
+            total_won: 0.0,
+            total_vig: 0.0,
+            onboarding_cost: 0.0,
+            num_kalman_updates: 0,
+            num_high_stakes_shots: 0,
+            num_capped_shots: 0,
+            estimate_history: HashMap::new(),
+            filter_diverged: false,
+            convergence_trajectory: Vec::new(),
+            round: Vec::new(),
+            next_hole: None,
+            antithetic_draws: None,
+            num_jackpots_hit: 0,
+            jackpot_won: 0.0,
+            went_bust: false,
+            running_net: 0.0,
+            peak_net: 0.0,
+            max_drawdown: 0.0,
+            sigma_multiplier_sum: 0.0,
+            num_drift_resets: 0,
+        }
+    }
+
+    /// Session configuration
+    pub fn config(&self) -> &SessionConfig {
+        &self.config
+    }
+
+    /// Shots played so far, in order
+    pub fn shots(&self) -> &[ShotOutcome] {
+        &self.shots
+    }
+
+    /// Player's current balance, if the session tracks a bankroll
+    pub fn bankroll(&self) -> Option<f64> {
+        self.bankroll
+    }
+
+    /// Whether the player's balance covers `wager` (always true without a bankroll)
+    pub fn can_cover(&self, wager: f64) -> bool {
+        self.bankroll.is_none_or(|balance| balance >= wager)
+    }
+
+    /// Hole the next shot will be played on
+    ///
+    /// Selected on first call and kept until the shot is played, so a
+    /// front end can show it before the wager is placed.
+    pub fn next_hole(&mut self) -> &Hole {
+        match self.rng.take() {
+            Some(mut rng) => {
+                self.next_hole_with_rng(&mut rng);
+                self.rng = Some(rng);
+                self.pending_hole()
+            }
+            None => self.next_hole_with_rng(&mut rand::thread_rng()),
+        }
+    }
+
+    /// Same as [`SessionState::next_hole`], selecting from `rng`
+    pub fn next_hole_with_rng(&mut self, rng: &mut impl Rng) -> &Hole {
+        if self.next_hole.is_none() {
+            let hole = select_hole(&self.config.hole_selection, &self.config.holes, &mut self.round, rng);
+            self.next_hole = Some(hole.id);
+        }
+        self.pending_hole()
+    }
+
+    fn pending_hole(&self) -> &Hole {
+        let hole_id = self.next_hole.expect("Hole selected before use");
+        self.config.holes.get_hole_by_id(hole_id).expect("Selected from the set")
+    }
+
+    /// Play one shot with the given wager
+    pub fn play_shot(&mut self, player: &mut Player, wager: f64) -> ShotOutcome {
+        // Take the session's generator out while `play_shot_with_rng` borrows
+        // `self` mutably, then put it back
+        match self.rng.take() {
+            Some(mut rng) => {
+                let outcome = self.play_shot_with_rng(player, wager, &mut rng);
+                self.rng = Some(rng);
+                outcome
             }
+            None => self.play_shot_with_rng(player, wager, &mut rand::thread_rng()),
+        }
+    }
+
+    /// Play one shot with the given wager, drawing all randomness from `rng`
+    pub fn play_shot_with_rng(&mut self, player: &mut Player, wager: f64, rng: &mut impl Rng) -> ShotOutcome {
+        let config = &self.config;
+        let shot_num = self.shots.len();
+        let hole = match self.next_hole.take() {
+            Some(hole_id) => config.holes.get_hole_by_id(hole_id).expect("Selected from the set"),
+            None => select_hole(&config.hole_selection, &config.holes, &mut self.round, rng),
+        };
+
+        // Shots come from the player's true skill, scaled to this hole's distance
+        // and widened by any wind
+        let sigma_multiplier = config.environment.map_or(1.0, |env| env.sigma_multiplier(hole.distance_yds));
+        let true_sigma = player.get_true_sigma(hole) * sigma_multiplier;
+
+        // Calculate P_max from the house's estimate of that skill
+        let p_max = player.calculate_p_max_cached(hole);
+
+        // Simulate or use manual miss distance
+        let manual_miss = config.developer_mode.as_ref().and_then(|dm| match &dm.manual_miss_sequence {
+            Some(sequence) if !sequence.is_empty() => Some(sequence[shot_num % sequence.len()]),
+            _ => dm.manual_miss_distance,
+        });
+        let (miss_distance, is_fat_tail) = if let Some(manual_dist) = manual_miss {
+            (manual_dist, false)
+        } else if let Some((bias_x, bias_y)) = player.get_shot_bias(hole) {
+            // Directional pattern: land in 2D around the bias, same fat-tail odds
+            let is_fat_tail = rng.gen::<f64>() < config.fat_tail_prob;
+            let sigma = if is_fat_tail { true_sigma * config.fat_tail_mult } else { true_sigma };
+            let (_, _, miss) = simulate_shot_2d_with_rng(sigma, sigma, bias_x, bias_y, rng);
+            (config.max_miss_distance.map_or(miss, |max| miss.min(max)), is_fat_tail)
+        } else if config.use_antithetic {
+            // First shot of a pair draws fresh uniforms, the second mirrors them
+            let (roll, u) = match self.antithetic_draws.take() {
+                Some((roll, u)) => (1.0 - roll, 1.0 - u),
+                None => {
+                    let draws = (rng.gen(), rng.gen());
+                    self.antithetic_draws = Some(draws);
+                    draws
+                }
+            };
+            let (miss, is_fat_tail) =
+                fat_tail_shot_from_uniforms(true_sigma, config.fat_tail_prob, config.fat_tail_mult, roll, u);
+            (config.max_miss_distance.map_or(miss, |max| miss.min(max)), is_fat_tail)
         } else {
-            simulate_shot(current_sigma, config.fat_tail_prob, config.fat_tail_mult)
+            let (miss, is_fat_tail) =
+                simulate_shot_with_model(true_sigma, config.fat_tail_prob, config.fat_tail_mult, config.miss_model, rng);
+            (config.max_miss_distance.map_or(miss, |max| miss.min(max)), is_fat_tail)
         };
 
-        // Calculate payout
-        let payout_multiplier = hole.calculate_payout(miss_distance, p_max);
-        let payout_amount = payout_multiplier * wager;
+        // The bay only sees the sensor's measurement of the miss
+        let miss_distance = measure_miss(miss_distance, config.sensor_noise_std, rng);
 
-        // Create shot outcome
+        // Calculate payout, limited by the house's per-shot liability cap
+        let mut payout_multiplier = hole.calculate_payout(miss_distance, p_max);
+        if let Some(cap) = config.max_payout_dollars {
+            let capped = hole.calculate_capped_payout(miss_distance, p_max, wager, cap);
+            if capped < payout_multiplier {
+                self.num_capped_shots += 1;
+                payout_multiplier = capped;
+            }
+        }
+        let (mut payout_amount, vig) = apply_winnings_vig(payout_multiplier * wager, config.winnings_vig);
+
+        // Promotional onboarding: the house tops the payout up to the wager
+        if shot_num < config.onboarding_shots && payout_amount < wager {
+            self.onboarding_cost += wager - payout_amount;
+            payout_amount = wager;
+            payout_multiplier = payout_multiplier.max(1.0);
+        }
+
+        // Create shot outcome (multiplier is the posted odds, floored during onboarding; payout is net of vig)
         let outcome = ShotOutcome {
             miss_distance_ft: miss_distance,
             multiplier: payout_multiplier,
@@ -183,21 +1048,40 @@ pub fn run_session(player: &mut Player, config: SessionConfig) -> SessionResult
             wager,
             hole_id: hole.id,
             is_fat_tail,
+            timestamp_ms: None,
         };
 
-        total_wagered += wager;
-        total_won += payout_amount;
-        shots.push(outcome);
+        self.total_wagered += wager;
+        self.total_won += payout_amount;
+        self.total_vig += vig;
+        self.sigma_multiplier_sum += sigma_multiplier;
+        self.shots.push(outcome.clone());
+
+        // Progressive jackpot: the wager feeds the pool, an ace takes all of it
+        self.jackpot.contribute(wager);
+        let jackpot_win = self.jackpot.settle(miss_distance);
+        if jackpot_win > 0.0 {
+            self.num_jackpots_hit += 1;
+            self.jackpot_won += jackpot_win;
+            self.total_won += jackpot_win;
+        }
+        if let Some(balance) = self.bankroll.as_mut() {
+            *balance += payout_amount + jackpot_win - wager;
+        }
+        self.running_net += payout_amount + jackpot_win - wager;
+        self.peak_net = self.peak_net.max(self.running_net);
+        self.max_drawdown = self.max_drawdown.max(self.peak_net - self.running_net);
 
         // SECURITY FIX: Track wager for lifetime average (cross-session detection)
         player.track_wager(wager);
 
         // Add shot to batch (unless Kalman is disabled)
-        if config.developer_mode.as_ref().map_or(true, |dm| !dm.disable_kalman) {
+        let drift_resets = player.drift_resets();
+        if config.developer_mode.as_ref().is_none_or(|dm| !dm.disable_kalman) {
             // SECURITY FIX: Use lifetime average wager if available, otherwise use session average
             let lifetime_avg = player.get_lifetime_avg_wager();
             let session_avg_wager = if shot_num > 0 {
-                total_wagered / (shot_num + 1) as f64
+                self.total_wagered / (shot_num + 1) as f64
             } else {
                 wager
             };
@@ -209,16 +1093,18 @@ pub fn run_session(player: &mut Player, config: SessionConfig) -> SessionResult
                 session_avg_wager
             };
 
-            // SECURITY FIX: More aggressive high-stakes detection (2x reference average instead of 10x batch average)
-            let is_high_stakes = wager >= 2.0 * reference_avg;
+            // SECURITY FIX: More aggressive high-stakes detection (2x reference average by default instead of 10x batch average)
+            let is_high_stakes = wager >= config.session_high_stakes_multiplier * reference_avg;
 
             if is_high_stakes {
-                num_high_stakes_shots += 1;
+                self.num_high_stakes_shots += 1;
                 // Process existing batch first if it has shots
                 let skill = player.get_skill_for_hole(hole);
                 if !skill.shot_batch.is_empty() {
-                    player.update_skill(hole, p_max);
-                    num_kalman_updates += 1;
+                    player.update_skill(hole);
+                    self.num_kalman_updates += 1;
+                    self.filter_diverged |= record_estimate(&mut self.estimate_history, player, hole);
+                    self.convergence_trajectory.push(skill_snapshot(shot_num, player, hole));
                 }
             }
 
@@ -227,215 +1113,1457 @@ pub fn run_session(player: &mut Player, config: SessionConfig) -> SessionResult
 
             // Update if batch is full or this is a high-stakes shot
             if batch_full || is_high_stakes {
-                player.update_skill(hole, p_max);
-                num_kalman_updates += 1;
+                player.update_skill(hole);
+                self.num_kalman_updates += 1;
+                self.filter_diverged |= record_estimate(&mut self.estimate_history, player, hole);
+                self.convergence_trajectory.push(skill_snapshot(shot_num, player, hole));
             }
         }
+        self.num_drift_resets += player.drift_resets() - drift_resets;
+
+        outcome
     }
 
-    // Process any remaining shots in batches at end of session
-    if config.developer_mode.as_ref().map_or(true, |dm| !dm.disable_kalman) {
-        for hole in HOLE_CONFIGURATIONS.iter() {
-            let skill = player.get_skill_for_hole(hole);
-            if !skill.shot_batch.is_empty() {
-                let p_max = player.calculate_p_max(hole);
-                player.update_skill(hole, p_max);
-                num_kalman_updates += 1;
+    /// End the session and summarize it
+    ///
+    /// Processes the player's remaining partial batches, then runs the
+    /// anti-cheat checks and collects the final statistics.
+    pub fn finalize(self, player: &mut Player) -> SessionResult {
+        let SessionState {
+            config,
+            shots,
+            total_wagered,
+            total_won,
+            total_vig,
+            onboarding_cost,
+            mut num_kalman_updates,
+            num_high_stakes_shots,
+            num_capped_shots,
+            mut estimate_history,
+            mut filter_diverged,
+            mut convergence_trajectory,
+            jackpot,
+            num_jackpots_hit,
+            jackpot_won,
+            bankroll,
+            went_bust,
+            max_drawdown,
+            sigma_multiplier_sum,
+            mut num_drift_resets,
+            ..
+        } = self;
+
+        // Process any remaining shots in batches at end of session
+        let drift_resets = player.drift_resets();
+        if config.developer_mode.as_ref().is_none_or(|dm| !dm.disable_kalman) {
+            for hole in config.holes.iter() {
+                let skill = player.get_skill_for_hole(hole);
+                if !skill.shot_batch.is_empty() {
+                    player.update_skill(hole);
+                    num_kalman_updates += 1;
+                    filter_diverged |= record_estimate(&mut estimate_history, player, hole);
+                    convergence_trajectory.push(skill_snapshot(shots.len(), player, hole));
+                }
             }
         }
-    }
+        num_drift_resets += player.drift_resets() - drift_resets;
 
-    // Collect final skill profiles
-    let final_skill_profiles = player
-        .skill_profiles
-        .iter()
-        .map(|(cat, profile)| {
-            (format!("{:?}", cat), profile.kalman_filter.estimate)
-        })
-        .collect();
+        // Collect final skill profiles
+        let final_skill_profiles: HashMap<String, f64> = player
+            .skill_profiles
+            .iter()
+            .map(|(cat, profile)| {
+                (format!("{:?}", cat), profile.kalman_filter.estimate)
+            })
+            .collect();
 
-    let net_gain_loss = total_won - total_wagered;
-    let session_house_edge = if total_wagered > 0.0 {
-        1.0 - (total_won / total_wagered)
-    } else {
-        0.0
-    };
+        let net_gain_loss = total_won - total_wagered;
+        let session_house_edge = if total_wagered > 0.0 {
+            1.0 - (total_won / total_wagered)
+        } else {
+            0.0
+        };
 
-    // SECURITY FIX: Run anti-cheat detection on session results
-    let cherry_picking_report = if shots.len() >= 10 {
-        Some(detect_cherry_picking(&shots))
-    } else {
-        None
-    };
+        // SECURITY FIX: Run anti-cheat detection on session results
+        let cherry_picking_report = if shots.len() >= config.anti_cheat.cherry_picking_min_shots {
+            Some(detect_cherry_picking_with_config(&shots, &config.anti_cheat))
+        } else {
+            None
+        };
 
-    let sandbagging_report = if shots.len() >= 20 {
-        Some(detect_sandbagging(&shots))
-    } else {
-        None
-    };
+        let sandbagging_report = if shots.len() >= config.anti_cheat.sandbagging_min_shots {
+            Some(detect_sandbagging_with_config(&shots, &config.anti_cheat))
+        } else {
+            None
+        };
 
-    SessionResult {
-        total_wagered,
-        total_won,
-        net_gain_loss,
-        shots,
-        final_skill_profiles,
-        session_house_edge,
-        num_kalman_updates,
-        num_high_stakes_shots,
-        cherry_picking_report,
-        sandbagging_report,
-    }
-}
+        let shot_classes = shot_class_histogram(&shots);
+        let shots_completed = shots.len();
+        let comps_earned = config.comp_rate * total_wagered;
+        let sigma_multiplier = if shots.is_empty() {
+            1.0
+        } else {
+            sigma_multiplier_sum / shots.len() as f64
+        };
 
-/// Select a hole based on the configured strategy
-fn select_hole<'a>(selection: &HoleSelection, rng: &mut impl Rng) -> &'a Hole {
-    match selection {
-        HoleSelection::Random => {
-            let idx = rng.gen_range(0..HOLE_CONFIGURATIONS.len());
-            &HOLE_CONFIGURATIONS[idx]
+        for (field, value) in [
+            ("total_wagered", total_wagered),
+            ("total_won", total_won),
+            ("net_gain_loss", net_gain_loss),
+            ("session_house_edge", session_house_edge),
+            ("total_vig", total_vig),
+            ("comps_earned", comps_earned),
+            ("onboarding_cost", onboarding_cost),
+            ("jackpot_final_pool", jackpot.pool),
+            ("final_bankroll", bankroll.unwrap_or(0.0)),
+            ("max_drawdown", max_drawdown),
+        ] {
+            assert_finite("run_session", field, value);
         }
-        HoleSelection::Weighted(weights) => {
-            let roll: f64 = rng.gen();
-            let mut cumulative = 0.0;
-            for (hole_id, prob) in weights {
-                cumulative += prob;
-                if roll < cumulative {
-                    return get_hole_by_id(*hole_id).expect("Invalid hole_id in weights");
-                }
-            }
-            // Fallback to last hole if rounding errors occur
-            let last_id = weights.last().map(|(id, _)| *id).unwrap_or(1);
-            get_hole_by_id(last_id).expect("Invalid hole_id in weights")
+        for (category, sigma) in &final_skill_profiles {
+            assert_finite("run_session", &format!("{} sigma", category), *sigma);
         }
-        HoleSelection::Fixed(hole_id) => {
-            get_hole_by_id(*hole_id).expect("Invalid hole_id in Fixed selection")
+
+        let mut result = SessionResult {
+            total_wagered,
+            total_won,
+            net_gain_loss,
+            shots,
+            final_skill_profiles,
+            session_house_edge,
+            num_kalman_updates,
+            num_high_stakes_shots,
+            cherry_picking_report,
+            sandbagging_report,
+            shot_classes,
+            total_vig,
+            filter_diverged,
+            convergence_trajectory,
+            comps_earned,
+            onboarding_cost,
+            num_capped_shots,
+            jackpot_final_pool: jackpot.pool,
+            num_jackpots_hit,
+            jackpot_won,
+            final_bankroll: bankroll.unwrap_or(0.0),
+            // Bust if a wager went uncovered or the minimum one is now out of reach
+            went_bust: went_bust || bankroll.is_some_and(|balance| balance < config.wager_min),
+            shots_completed,
+            max_drawdown,
+            sigma_multiplier,
+            num_drift_resets,
+            digest: String::new(),
+        };
+        result.digest = result.digest();
+        result
+    }
+
+    /// Wager for the next shot on `hole` per `config.betting_strategy`
+    ///
+    /// `scale` multiplies the wager range and the strategy's base and cap.
+    /// Only `Flat` (and `Proportional` without a bankroll) draws from `rng`.
+    fn next_wager(&self, player: &Player, hole: &Hole, scale: f64, rng: &mut impl Rng) -> f64 {
+        let last = self.shots.last();
+        match (self.config.betting_strategy, self.bankroll) {
+            (BettingStrategy::Martingale { base, max }, _) => match last {
+                Some(shot) if shot.net_result() < 0.0 => (shot.wager * 2.0).min(max * scale),
+                _ => base * scale,
+            },
+            (BettingStrategy::AntiMartingale { base, max }, _) => match last {
+                Some(shot) if shot.net_result() > 0.0 => (shot.wager * 2.0).min(max * scale),
+                _ => base * scale,
+            },
+            (BettingStrategy::Proportional { fraction }, Some(balance)) => {
+                (fraction * balance).max(self.config.wager_min * scale)
+            }
+            _ => draw_wager(&self.config, player, hole, scale, rng),
         }
     }
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_session_config_default() {
-        let config = SessionConfig::default();
-        assert_eq!(config.num_shots, 100);
-        assert_eq!(config.wager_min, 5.0);
-        assert_eq!(config.wager_max, 10.0);
-        assert_eq!(config.fat_tail_prob, 0.02);
-        assert_eq!(config.fat_tail_mult, 3.0);
+/// Draw a wager for a shot on `hole` per the config's wager range, scaled
+/// by `scale`, and confidence link
+fn draw_wager(config: &SessionConfig, player: &Player, hole: &Hole, scale: f64, rng: &mut impl Rng) -> f64 {
+    let (wager_min, wager_max) = (config.wager_min * scale, config.wager_max * scale);
+    if config.wager_confidence_link > 0.0 {
+        let link = config.wager_confidence_link.min(1.0);
+        let position = link * confidence_rank(player, hole.category) + (1.0 - link) * rng.gen::<f64>();
+        wager_min + position * (wager_max - wager_min)
+    } else {
+        rng.gen_range(wager_min..=wager_max)
     }
+}
 
-    #[test]
-    fn test_hole_selection_fixed() {
-        let selection = HoleSelection::Fixed(3);
-        let mut rng = rand::thread_rng();
+/// Apply the bay sensor's Gaussian measurement error to a true miss distance
+///
+/// Draws nothing from `rng` when `noise_std` is zero, so noiseless sessions
+/// keep their random stream.
+fn measure_miss(miss_distance: f64, noise_std: f64, rng: &mut impl Rng) -> f64 {
+    if noise_std > 0.0 {
+        let error = normal_random_with_rng(0.0, noise_std, NormalSampler::default(), rng);
+        (miss_distance + error).max(0.0)
+    } else {
+        miss_distance
+    }
+}
 
-        for _ in 0..10 {
-            let hole = select_hole(&selection, &mut rng);
-            assert_eq!(hole.id, 3);
-        }
+/// Rank of a category by the player's certainty in its skill estimate
+///
+/// Categories are ordered by Kalman error covariance, the quantity behind
+/// skill confidence (which saturates at 100% after a few updates). Returns
+/// 1.0 for the most certain category and 0.0 for the least; ties share the
+/// middle rank, so a fresh player ranks every category at 0.5.
+fn confidence_rank(player: &Player, category: ClubCategory) -> f64 {
+    let Some(profile) = player.skill_profiles.get(&category) else {
+        return 0.5;
+    };
+    let covariance = profile.kalman_filter.error_covariance;
+    let others: Vec<f64> = player
+        .skill_profiles
+        .iter()
+        .filter(|(other, _)| **other != category)
+        .map(|(_, other)| other.kalman_filter.error_covariance)
+        .collect();
+    if others.is_empty() {
+        return 0.5;
     }
+    let less_certain = others.iter().filter(|&&p| p > covariance).count() as f64;
+    let tied = others.iter().filter(|&&p| p == covariance).count() as f64;
+    (less_certain + 0.5 * tied) / others.len() as f64
+}
 
-    #[test]
-    fn test_hole_selection_random() {
-        let selection = HoleSelection::Random;
-        let mut rng = rand::thread_rng();
-        let mut seen_holes = std::collections::HashSet::new();
+/// Record a category's estimate after a Kalman update
+///
+/// # Returns
+/// True if the category's estimate history now shows divergence
+fn record_estimate(history: &mut HashMap<ClubCategory, Vec<f64>>, player: &Player, hole: &Hole) -> bool {
+    let category_history = history.entry(hole.category).or_default();
+    category_history.push(player.get_skill_for_hole(hole).kalman_filter.estimate);
+    is_diverging(category_history)
+}
 
-        // Should see multiple different holes over 100 selections
-        for _ in 0..100 {
-            let hole = select_hole(&selection, &mut rng);
+/// Snapshot a category's skill estimate for the convergence trajectory
+fn skill_snapshot(shot_index: usize, player: &Player, hole: &Hole) -> (usize, ClubCategory, f64, f64) {
+    (
+        shot_index,
+        hole.category,
+        player.get_skill_confidence(hole),
+        player.get_skill_for_hole(hole).kalman_filter.estimate,
+    )
+}
+
+/// Deduct a winnings vig from a payout
+///
+/// # Returns
+/// (payout after vig, vig retained); zero payouts are untouched
+pub fn apply_winnings_vig(payout: f64, vig_rate: f64) -> (f64, f64) {
+    if payout > 0.0 {
+        let vig = payout * vig_rate;
+        (payout - vig, vig)
+    } else {
+        (payout, 0.0)
+    }
+}
+
+/// Run independent sessions for a cohort of players in parallel
+///
+/// Each player gets their own `StdRng` seeded with
+/// [`cohort_session_seed`]`(seed, index)`, so results are reproducible and
+/// independent of thread scheduling. Results are returned in player order.
+///
+/// # Arguments
+/// * `players` - Players to run (each plays one session)
+/// * `config` - Session configuration shared by all players
+/// * `seed` - Cohort seed from which per-player seeds are derived
+pub fn run_session_cohort(players: Vec<Player>, config: SessionConfig, seed: u64) -> Vec<SessionResult> {
+    players
+        .into_par_iter()
+        .enumerate()
+        .map(|(index, mut player)| {
+            let mut rng = StdRng::seed_from_u64(cohort_session_seed(seed, index));
+            run_session_with_rng(&mut player, config.clone(), &mut rng)
+        })
+        .collect()
+}
+
+/// Run independent (player, config) sessions in parallel
+///
+/// Each session is exactly `run_session(&mut player, config)`: it draws from
+/// its own generator seeded with `config.seed` (entropy if unset), never
+/// from one shared across threads, so seeded sessions give the same results
+/// as running them one by one. Results are returned in input order; the
+/// updated players are dropped.
+pub fn run_sessions_parallel(configs: Vec<(Player, SessionConfig)>) -> Vec<SessionResult> {
+    configs
+        .into_par_iter()
+        .map(|(mut player, config)| run_session(&mut player, config))
+        .collect()
+}
+
+/// Combine a player's sessions, in play order, into lifetime totals
+///
+/// Money, shot and update counts are summed and shots are concatenated.
+/// House edge, shot classes, the fat-tail count and the sigma multiplier
+/// are recomputed over all shots. Trajectory shot indices are offset into
+/// the combined shot list. Each category in `final_skill_profiles` takes
+/// the last session's value for it. The final bankroll and jackpot pool
+/// come from the last session.
+/// `went_bust` and `filter_diverged` are true if any session's was.
+/// `max_drawdown` is the largest drawdown within a single session.
+/// Anti-cheat detection re-runs on the combined shots with the default
+/// [`AntiCheatConfig`], so cherry-picking spread across visits is caught.
+pub fn accumulate_sessions(results: &[SessionResult]) -> SessionResult {
+    let mut lifetime = SessionResult { sigma_multiplier: 1.0, ..Default::default() };
+    let mut sigma_multiplier_sum = 0.0;
+
+    for result in results {
+        let offset = lifetime.shots.len();
+        lifetime.convergence_trajectory.extend(
+            result
+                .convergence_trajectory
+                .iter()
+                .map(|&(shot, category, confidence, sigma)| (offset + shot, category, confidence, sigma)),
+        );
+        lifetime.shots.extend(result.shots.iter().cloned());
+        sigma_multiplier_sum += result.sigma_multiplier * result.shots.len() as f64;
+
+        for (category, sigma) in &result.final_skill_profiles {
+            lifetime.final_skill_profiles.insert(category.clone(), *sigma);
+        }
+
+        lifetime.total_wagered += result.total_wagered;
+        lifetime.total_won += result.total_won;
+        lifetime.total_vig += result.total_vig;
+        lifetime.comps_earned += result.comps_earned;
+        lifetime.onboarding_cost += result.onboarding_cost;
+        lifetime.jackpot_won += result.jackpot_won;
+        lifetime.num_kalman_updates += result.num_kalman_updates;
+        lifetime.num_high_stakes_shots += result.num_high_stakes_shots;
+        lifetime.num_capped_shots += result.num_capped_shots;
+        lifetime.num_jackpots_hit += result.num_jackpots_hit;
+        lifetime.num_drift_resets += result.num_drift_resets;
+        lifetime.shots_completed += result.shots_completed;
+        lifetime.filter_diverged |= result.filter_diverged;
+        lifetime.went_bust |= result.went_bust;
+        lifetime.max_drawdown = lifetime.max_drawdown.max(result.max_drawdown);
+        lifetime.final_bankroll = result.final_bankroll;
+        lifetime.jackpot_final_pool = result.jackpot_final_pool;
+    }
+
+    lifetime.net_gain_loss = lifetime.total_won - lifetime.total_wagered;
+    if lifetime.total_wagered > 0.0 {
+        lifetime.session_house_edge = 1.0 - lifetime.total_won / lifetime.total_wagered;
+    }
+    if !lifetime.shots.is_empty() {
+        lifetime.sigma_multiplier = sigma_multiplier_sum / lifetime.shots.len() as f64;
+    }
+    lifetime.shot_classes = shot_class_histogram(&lifetime.shots);
+
+    let anti_cheat = AntiCheatConfig::default();
+    if lifetime.shots.len() >= anti_cheat.cherry_picking_min_shots {
+        lifetime.cherry_picking_report = Some(detect_cherry_picking_with_config(&lifetime.shots, &anti_cheat));
+    }
+    if lifetime.shots.len() >= anti_cheat.sandbagging_min_shots {
+        lifetime.sandbagging_report = Some(detect_sandbagging_with_config(&lifetime.shots, &anti_cheat));
+    }
+    lifetime.digest = lifetime.digest();
+
+    lifetime
+}
+
+/// Seed used for the player at `index` in [`run_session_cohort`]
+pub fn cohort_session_seed(seed: u64, index: usize) -> u64 {
+    seed.wrapping_add(index as u64)
+}
+
+/// Everything needed to re-run a session exactly
+///
+/// Capture it before the session is played: `player_snapshot` is the player
+/// as they started, and the session's generator is seeded with `seed`
+/// (overriding `config.seed`). Archived replays reproduce interesting
+/// sessions, such as big wins or suspected cheating, for investigation.
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct SessionReplay {
+    /// Session configuration
+    pub config: SessionConfig,
+    /// Seed for the session's generator
+    pub seed: u64,
+    /// The player before the session
+    pub player_snapshot: Player,
+}
+
+impl SessionReplay {
+    /// Snapshot `player` before running `config` seeded with `seed`
+    pub fn new(player: &Player, config: SessionConfig, seed: u64) -> Self {
+        Self { config, seed, player_snapshot: player.clone() }
+    }
+
+    /// Save the replay as JSON
+    pub fn save_replay(&self, path: &str) -> Result<(), Box<dyn Error>> {
+        std::fs::write(path, serde_json::to_string_pretty(self)?)?;
+        Ok(())
+    }
+
+    /// Load a replay saved by `save_replay`
+    pub fn load_replay(path: &str) -> Result<Self, Box<dyn Error>> {
+        Ok(serde_json::from_str(&std::fs::read_to_string(path)?)?)
+    }
+}
+
+/// Re-run a recorded session
+///
+/// Plays `replay.config` with a fresh copy of the player snapshot, seeded
+/// with `replay.seed`, so it reproduces the original [`run_session`] call
+/// shot for shot.
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::models::player::Player;
+/// use continuum_golf_simulator::simulators::player_session::{replay, run_session, SessionConfig, SessionReplay};
+///
+/// let mut player = Player::new("player_1".to_string(), 15);
+/// let config = SessionConfig { num_shots: 50, seed: Some(7), ..Default::default() };
+/// let recording = SessionReplay::new(&player, config.clone(), 7);
+/// let original = run_session(&mut player, config);
+/// assert_eq!(replay(&recording).total_won, original.total_won);
+/// ```
+pub fn replay(replay: &SessionReplay) -> SessionResult {
+    let mut player = replay.player_snapshot.clone();
+    let config = SessionConfig { seed: Some(replay.seed), ..replay.config.clone() };
+    run_session(&mut player, config)
+}
+
+/// Select a hole from `holes` based on the configured strategy
+///
+/// `round` holds the hole ids still to be played in the current round-robin
+/// round; it is refilled once exhausted and unused by other strategies.
+fn select_hole<'a>(
+    selection: &HoleSelection,
+    holes: &'a HoleSet,
+    round: &mut Vec<u8>,
+    rng: &mut impl Rng,
+) -> &'a Hole {
+    match selection {
+        HoleSelection::Random => {
+            let idx = rng.gen_range(0..holes.len());
+            &holes.holes()[idx]
+        }
+        HoleSelection::Weighted(weights) => {
+            let roll: f64 = rng.gen();
+            let mut cumulative = 0.0;
+            for (hole_id, prob) in weights {
+                cumulative += prob;
+                if roll < cumulative {
+                    return holes.get_hole_by_id(*hole_id).expect("Invalid hole_id in weights");
+                }
+            }
+            // Fallback to last hole if rounding errors occur
+            let last_id = weights.last().map(|(id, _)| *id).unwrap_or(1);
+            holes.get_hole_by_id(last_id).expect("Invalid hole_id in weights")
+        }
+        HoleSelection::Fixed(hole_id) => {
+            holes.get_hole_by_id(*hole_id).expect("Invalid hole_id in Fixed selection")
+        }
+        HoleSelection::RoundRobin { shuffle } => {
+            if round.is_empty() {
+                round.extend(holes.iter().rev().map(|h| h.id));
+                if *shuffle {
+                    round.shuffle(rng);
+                }
+            }
+            let hole_id = round.pop().expect("Round refilled above");
+            holes.get_hole_by_id(hole_id).expect("Invalid hole_id in round")
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::models::hole::{get_hole_by_id, ACE_DISTANCE_FT};
+
+    #[test]
+    fn test_session_config_default() {
+        let config = SessionConfig::default();
+        assert_eq!(config.num_shots, 100);
+        assert_eq!(config.wager_min, 5.0);
+        assert_eq!(config.wager_max, 10.0);
+        assert_eq!(config.fat_tail_prob, 0.02);
+        assert_eq!(config.fat_tail_mult, 3.0);
+    }
+
+    #[test]
+    fn test_session_cohort_matches_serial_seeded_runs() {
+        let config = SessionConfig {
+            num_shots: 30,
+            ..Default::default()
+        };
+        let handicaps = [0, 5, 10, 15, 20, 25, 30];
+        let players: Vec<Player> = handicaps
+            .iter()
+            .map(|&h| Player::new(format!("player_{}", h), h))
+            .collect();
+
+        let cohort = run_session_cohort(players.clone(), config.clone(), 7);
+        assert_eq!(cohort.len(), players.len());
+
+        for (index, (mut player, result)) in players.into_iter().zip(&cohort).enumerate() {
+            let mut rng = StdRng::seed_from_u64(cohort_session_seed(7, index));
+            let serial = run_session_with_rng(&mut player, config.clone(), &mut rng);
+            assert_eq!(serial.shots, result.shots);
+            assert_eq!(serial.total_won.to_bits(), result.total_won.to_bits());
+        }
+    }
+
+    #[test]
+    fn test_session_cohort_scales() {
+        let config = SessionConfig {
+            num_shots: 10,
+            ..Default::default()
+        };
+        let players: Vec<Player> = (0..3000)
+            .map(|i| Player::new(format!("player_{}", i), (i % 31) as u8))
+            .collect();
+
+        let results = run_session_cohort(players, config, 42);
+
+        assert_eq!(results.len(), 3000);
+        assert!(results.iter().all(|r| r.shots.len() == 10));
+    }
+
+    #[test]
+    fn test_winnings_vig_reduces_fair_odds_rtp() {
+        use crate::models::hole::{Hole, HoleSet};
+
+        // Fair posted odds: P_max priced for RTP = 1.0, and kept there by
+        // leaving the skill estimate (which shots are drawn from) untouched
+        let vig_rate = 0.05;
+        let config = SessionConfig {
+            num_shots: 100_000,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            holes: HoleSet::new(vec![Hole::new(1, 150, 47.58, 1.0, 6.0)]).unwrap(),
+            hole_selection: HoleSelection::Fixed(1),
+            winnings_vig: vig_rate,
+            developer_mode: Some(DeveloperMode {
+                manual_miss_distance: None,
+                manual_miss_sequence: None,
+                disable_kalman: true,
+            }),
+            seed: Some(214),
+            ..Default::default()
+        };
+        let mut player = Player::new("test_player".to_string(), 15);
+        let result = run_session(&mut player, config);
+
+        let n = result.shots.len() as f64;
+        let multipliers: Vec<f64> = result.shots.iter().map(|s| s.multiplier).collect();
+        let gross_rtp = multipliers.iter().sum::<f64>() / n;
+        let std_error = (multipliers.iter().map(|r| (r - gross_rtp).powi(2)).sum::<f64>() / (n - 1.0) / n).sqrt();
+        let realized_rtp = result.total_won / result.total_wagered;
+
+        // Fair odds pay back ~100% before the vig
+        assert!((gross_rtp - 1.0).abs() < 4.0 * std_error, "Gross RTP {} ± {}", gross_rtp, std_error);
+        // Vig takes exactly 5% of winnings, so realized RTP is 0.95 × gross ≈ 0.95
+        let gross = result.total_won + result.total_vig;
+        assert!((result.total_vig - vig_rate * gross).abs() < 1e-6 * gross);
+        assert!((realized_rtp - (1.0 - vig_rate) * gross_rtp).abs() < 1e-9);
+        assert!((realized_rtp - (1.0 - vig_rate)).abs() < 4.0 * std_error,
+            "Realized RTP {} vs {}", realized_rtp, 1.0 - vig_rate);
+    }
+
+    #[test]
+    fn test_session_tracks_total_vig() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 200,
+            winnings_vig: 0.05,
+            ..Default::default()
+        };
+
+        let result = run_session(&mut player, config);
+        let gross: f64 = result.shots.iter().map(|s| s.multiplier * s.wager).sum();
+
+        assert!((result.total_vig - 0.05 * gross).abs() < 1e-6);
+        assert!((result.total_won + result.total_vig - gross).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_session_flags_filter_divergence() {
+        // Every shot misses by 1000ft, so the estimate climbs on every update
+        let mut player = Player::new("test_player".to_string(), 15);
+        let runaway = SessionConfig {
+            num_shots: 150,
+            hole_selection: HoleSelection::Fixed(4),
+            developer_mode: Some(DeveloperMode {
+                manual_miss_distance: Some(1000.0),
+                manual_miss_sequence: None,
+                disable_kalman: false,
+            }),
+            ..Default::default()
+        };
+        assert!(run_session(&mut player, runaway).filter_diverged);
+
+        // Misses ramp up steadily but stay physical, so only the run of
+        // same-direction updates can flag it
+        let mut player = Player::new("test_player".to_string(), 15);
+        let ramp = SessionConfig {
+            num_shots: 150,
+            hole_selection: HoleSelection::Fixed(4),
+            seed: Some(215),
+            developer_mode: Some(DeveloperMode {
+                manual_miss_distance: None,
+                manual_miss_sequence: Some((0..150).map(|i| 20.0 + 1.5 * i as f64).collect()),
+                disable_kalman: false,
+            }),
+            ..Default::default()
+        };
+        let result = run_session(&mut player, ramp);
+        let peak = result.convergence_trajectory.iter().map(|entry| entry.3).fold(0.0, f64::max);
+        assert!(peak < crate::math::kalman::MAX_PHYSICAL_SIGMA_FT, "peak estimate {}", peak);
+        assert!(result.filter_diverged);
+
+        let mut player = Player::new("test_player".to_string(), 15);
+        let stable = SessionConfig {
+            num_shots: 100,
+            hole_selection: HoleSelection::Fixed(4),
+            seed: Some(3),
+            ..Default::default()
+        };
+        assert!(!run_session(&mut player, stable).filter_diverged);
+
+        // A player far better than their handicap: the estimate falls on
+        // every update for a long run, but slows as it converges
+        let hole = get_hole_by_id(4).unwrap();
+        for seed in 0..10 {
+            let mut player = Player::new("test_player".to_string(), 15);
+            player.set_true_sigma(hole.category, 3.0);
+            let converging = SessionConfig {
+                num_shots: 300,
+                hole_selection: HoleSelection::Fixed(4),
+                seed: Some(seed),
+                ..Default::default()
+            };
+            assert!(!run_session(&mut player, converging).filter_diverged, "seed {}", seed);
+        }
+    }
+
+    #[test]
+    fn test_comps_raise_effective_rtp() {
+        let comp_rate = 0.02;
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 100,
+            comp_rate,
+            ..Default::default()
+        };
+        let result = run_session(&mut player, config);
+
+        let rtp = result.total_won / result.total_wagered;
+        assert!((result.effective_rtp_with_comps() - (rtp + comp_rate)).abs() < 1e-12);
+
+        // Comps scale linearly with the amount wagered
+        let mut player = Player::new("test_player".to_string(), 15);
+        let longer = SessionConfig {
+            num_shots: 300,
+            comp_rate,
+            ..Default::default()
+        };
+        let longer_result = run_session(&mut player, longer);
+        assert!((result.comps_earned / result.total_wagered
+            - longer_result.comps_earned / longer_result.total_wagered).abs() < 1e-12);
+        assert!(longer_result.comps_earned > result.comps_earned);
+    }
+
+    #[test]
+    fn test_session_state_matches_run_session() {
+        let config = SessionConfig {
+            num_shots: 120,
+            seed: Some(258),
+            ..Default::default()
+        };
+        let mut batch_player = Player::new("batch".to_string(), 12);
+        let batch = run_session(&mut batch_player, config.clone());
+
+        // Same generator stream: hole, then wager, then the shot
+        let mut rng = StdRng::seed_from_u64(258);
+        let mut live_player = Player::new("batch".to_string(), 12);
+        let mut state = SessionState::new(config.clone());
+        for _ in 0..config.num_shots {
+            state.next_hole_with_rng(&mut rng);
+            let wager = rng.gen_range(config.wager_min..=config.wager_max);
+            state.play_shot_with_rng(&mut live_player, wager, &mut rng);
+        }
+        assert_eq!(state.shots().len(), 120);
+        let live = state.finalize(&mut live_player);
+
+        assert_eq!(serde_json::to_vec(&live.shots).unwrap(), serde_json::to_vec(&batch.shots).unwrap());
+        assert_eq!(live.total_won, batch.total_won);
+        assert_eq!(live.num_kalman_updates, batch.num_kalman_updates);
+        assert_eq!(live.convergence_trajectory, batch.convergence_trajectory);
+        assert_eq!(live.final_skill_profiles, batch.final_skill_profiles);
+    }
+
+    #[test]
+    fn test_session_state_plays_announced_hole() {
+        let mut player = Player::new("live".to_string(), 15);
+        let mut state = SessionState::new(SessionConfig {
+            hole_selection: HoleSelection::RoundRobin { shuffle: true },
+            ..Default::default()
+        });
+
+        for wager in [5.0, 5.0, 5.0, 40.0] {
+            let announced = state.next_hole().id;
+            assert_eq!(state.next_hole().id, announced);
+            let outcome = state.play_shot(&mut player, wager);
+            assert_eq!(outcome.hole_id, announced);
+            assert_eq!(outcome.wager, wager);
+        }
+
+        let result = state.finalize(&mut player);
+        assert_eq!(result.total_wagered, 55.0);
+        assert_eq!(result.num_high_stakes_shots, 1);
+        // The partial batches are flushed at the end
+        assert!(player.skill_profiles.values().all(|p| p.shot_batch.is_empty()));
+    }
+
+    #[test]
+    fn test_seeded_session_state_replays() {
+        let config = SessionConfig {
+            hole_selection: HoleSelection::RoundRobin { shuffle: true },
+            seed: Some(258),
+            ..Default::default()
+        };
+        let play = || {
+            let mut player = Player::new("live".to_string(), 15);
+            let mut state = SessionState::new(config.clone());
+            for wager in [5.0, 10.0, 5.0, 20.0, 5.0, 10.0, 5.0, 5.0, 10.0] {
+                state.next_hole();
+                state.play_shot(&mut player, wager);
+            }
+            state.finalize(&mut player).digest
+        };
+
+        assert_eq!(play(), play());
+    }
+
+    #[test]
+    fn test_cancelled_session_stops_early() {
+        let config = SessionConfig {
+            num_shots: 3 * CANCEL_CHECK_INTERVAL,
+            seed: Some(304),
+            ..Default::default()
+        };
+
+        let cancel = CancelToken::new();
+        let full = run_session_cancellable(&mut Player::new("p".to_string(), 15), config.clone(), &cancel);
+        let plain = run_session(&mut Player::new("p".to_string(), 15), config.clone());
+        assert_eq!(full.digest, plain.digest);
+
+        cancel.cancel();
+        let cancelled = run_session_cancellable(&mut Player::new("p".to_string(), 15), config, &cancel);
+        assert_eq!(cancelled.shots_completed, 0);
+        assert_eq!(cancelled.total_wagered, 0.0);
+    }
+
+    #[test]
+    fn test_seeded_sessions_are_byte_identical() {
+        let config = SessionConfig {
+            num_shots: 200,
+            seed: Some(251),
+            ..Default::default()
+        };
+        let run = |config: SessionConfig| {
+            let result = run_session(&mut Player::new("qa".to_string(), 12), config);
+            serde_json::to_vec(&result.shots).unwrap()
+        };
+
+        assert_eq!(run(config.clone()), run(config.clone()));
+        assert_ne!(run(config.clone()), run(SessionConfig { seed: Some(252), ..config.clone() }));
+
+        // Unseeded sessions keep drawing from the thread RNG
+        let unseeded = SessionConfig { seed: None, ..config };
+        assert_ne!(run(unseeded.clone()), run(unseeded));
+    }
+
+    #[test]
+    fn test_fingerprint_is_deterministic() {
+        let config = SessionConfig {
+            seed: Some(42),
+            hole_selection: HoleSelection::Weighted(vec![(1, 0.5), (4, 0.5)]),
+            ..Default::default()
+        };
+
+        assert_eq!(config.fingerprint(), config.clone().fingerprint());
+
+        let reseeded = SessionConfig { seed: Some(43), ..config.clone() };
+        assert_ne!(config.fingerprint(), reseeded.fingerprint());
+
+        let unseeded = SessionConfig { seed: None, ..config.clone() };
+        assert_ne!(config.fingerprint(), unseeded.fingerprint());
+
+        let different_wager = SessionConfig { wager_max: 10.000001, ..config.clone() };
+        assert_ne!(config.fingerprint(), different_wager.fingerprint());
+
+        // Fixed value: must not change between processes or Rust releases.
+        // Update only when SessionConfig gains or changes fields.
+        assert_eq!(SessionConfig::default().fingerprint(), 13983816540940832677);
+    }
+
+    #[test]
+    fn test_hole_selection_fixed() {
+        let holes = HoleSet::standard();
+        let selection = HoleSelection::Fixed(3);
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..10 {
+            let hole = select_hole(&selection, &holes, &mut Vec::new(), &mut rng);
+            assert_eq!(hole.id, 3);
+        }
+    }
+
+    #[test]
+    fn test_hole_selection_random() {
+        let holes = HoleSet::standard();
+        let selection = HoleSelection::Random;
+        let mut rng = rand::thread_rng();
+        let mut seen_holes = std::collections::HashSet::new();
+
+        // Should see multiple different holes over 100 selections
+        for _ in 0..100 {
+            let hole = select_hole(&selection, &holes, &mut Vec::new(), &mut rng);
             seen_holes.insert(hole.id);
         }
 
-        assert!(seen_holes.len() > 1, "Random selection should pick different holes");
+        assert!(seen_holes.len() > 1, "Random selection should pick different holes");
+    }
+
+    #[test]
+    fn test_hole_selection_round_robin_in_order() {
+        let holes = HoleSet::standard();
+        let selection = HoleSelection::RoundRobin { shuffle: false };
+        let mut rng = rand::thread_rng();
+        let mut round = Vec::new();
+
+        let ids: Vec<u8> = (0..10)
+            .map(|_| select_hole(&selection, &holes, &mut round, &mut rng).id)
+            .collect();
+        assert_eq!(ids, vec![1, 2, 3, 4, 5, 6, 7, 8, 1, 2]);
+    }
+
+    #[test]
+    fn test_session_round_robin_plays_each_hole_per_round() {
+        for (num_shots, expected) in [(8, 1), (16, 2)] {
+            let mut player = Player::new("test_player".to_string(), 15);
+            let config = SessionConfig {
+                num_shots,
+                hole_selection: HoleSelection::RoundRobin { shuffle: true },
+                ..Default::default()
+            };
+
+            let result = run_session(&mut player, config);
+
+            let mut counts: HashMap<u8, usize> = HashMap::new();
+            for shot in &result.shots {
+                *counts.entry(shot.hole_id).or_insert(0) += 1;
+            }
+            assert_eq!(counts.len(), 8);
+            assert!(counts.values().all(|&c| c == expected), "{:?}", counts);
+
+            // Every round (consecutive block of 8) covers all holes
+            for block in result.shots.chunks(8) {
+                let mut ids: Vec<u8> = block.iter().map(|s| s.hole_id).collect();
+                ids.sort();
+                assert_eq!(ids, vec![1, 2, 3, 4, 5, 6, 7, 8]);
+            }
+        }
+    }
+
+    #[test]
+    fn test_hole_selection_weighted() {
+        let holes = HoleSet::standard();
+        // 100% weight on hole 5
+        let selection = HoleSelection::Weighted(vec![(5, 1.0)]);
+        let mut rng = rand::thread_rng();
+
+        for _ in 0..10 {
+            let hole = select_hole(&selection, &holes, &mut Vec::new(), &mut rng);
+            assert_eq!(hole.id, 5);
+        }
+    }
+
+    #[test]
+    fn test_run_session_basic() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 10,
+            wager_min: 5.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Fixed(4),
+            developer_mode: None,
+            ..Default::default()
+        };
+
+        let result = run_session(&mut player, config);
+
+        assert_eq!(result.shots.len(), 10);
+        assert!(result.total_wagered >= 50.0 && result.total_wagered <= 100.0);
+        assert_eq!(result.net_gain_loss, result.total_won - result.total_wagered);
+        // House edge can be negative in individual sessions (player wins)
+        // Typically should be between -5.0 and 1.0 for small sample sizes
+        assert!(result.session_house_edge >= -5.0 && result.session_house_edge <= 1.0);
+    }
+
+    #[test]
+    fn test_run_session_developer_mode_manual_miss() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 5,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Fixed(4),
+            developer_mode: Some(DeveloperMode {
+                manual_miss_distance: Some(5.0), // Always miss by 5ft
+                manual_miss_sequence: None,
+                disable_kalman: false,
+            }),
+            ..Default::default()
+        };
+
+        let result = run_session(&mut player, config);
+
+        // All shots should have exactly 5ft miss distance
+        for shot in &result.shots {
+            assert_eq!(shot.miss_distance_ft, 5.0);
+        }
+    }
+
+    #[test]
+    fn test_run_session_developer_mode_miss_sequence() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 7,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Fixed(4),
+            developer_mode: Some(DeveloperMode {
+                manual_miss_distance: Some(1.0), // Overridden by the sequence
+                manual_miss_sequence: Some(vec![5.0, 10.0, 30.0]),
+                disable_kalman: false,
+            }),
+            ..Default::default()
+        };
+
+        let result = run_session(&mut player, config);
+
+        // Shots follow the sequence in order, cycling once it runs out
+        let misses: Vec<f64> = result.shots.iter().map(|shot| shot.miss_distance_ft).collect();
+        assert_eq!(misses, vec![5.0, 10.0, 30.0, 5.0, 10.0, 30.0, 5.0]);
+    }
+
+    #[test]
+    fn test_run_session_developer_mode_disable_kalman() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let hole = get_hole_by_id(4).unwrap();
+        let initial_sigma = player.get_skill_for_hole(hole).kalman_filter.estimate;
+
+        let config = SessionConfig {
+            num_shots: 20,
+            wager_min: 5.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Fixed(4),
+            developer_mode: Some(DeveloperMode {
+                manual_miss_distance: None,
+                manual_miss_sequence: None,
+                disable_kalman: true, // No updates
+            }),
+            ..Default::default()
+        };
+
+        let result = run_session(&mut player, config);
+
+        assert_eq!(result.num_kalman_updates, 0);
+
+        // Skill should not have changed
+        let final_sigma = player.get_skill_for_hole(hole).kalman_filter.estimate;
+        assert_eq!(initial_sigma, final_sigma);
+    }
+
+    #[test]
+    fn test_max_miss_distance_clamp() {
+        const CAP: f64 = 120.0; // Beyond hole 8's d_max of 101.14 ft
+
+        let config = SessionConfig {
+            num_shots: 500,
+            hole_selection: HoleSelection::Fixed(8),
+            developer_mode: Some(DeveloperMode {
+                manual_miss_distance: None,
+                manual_miss_sequence: None,
+                disable_kalman: true, // Same sigma every shot, so both runs share one RNG stream
+            }),
+            fat_tail_prob: 0.2,
+            seed: Some(9),
+            ..Default::default()
+        };
+        let capped = SessionConfig { max_miss_distance: Some(CAP), ..config.clone() };
+
+        let unbounded_result = run_session(&mut Player::new("beginner".to_string(), 30), config);
+        let capped_result = run_session(&mut Player::new("beginner".to_string(), 30), capped);
+
+        assert!(unbounded_result.shots.iter().any(|s| s.miss_distance_ft > CAP));
+        assert!(capped_result.shots.iter().all(|s| s.miss_distance_ft <= CAP));
+
+        // Clamped misses were already zero-payout, so realized RTP is unchanged
+        assert_eq!(capped_result.total_wagered, unbounded_result.total_wagered);
+        assert_eq!(capped_result.total_won, unbounded_result.total_won);
+    }
+
+    #[test]
+    fn test_sensor_noise() {
+        use crate::test_support::mean_and_std_error;
+
+        let config = SessionConfig {
+            num_shots: 300,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Fixed(4),
+            ..Default::default()
+        };
+        // Shots come from a fixed true skill, so every session's estimate
+        // converges on the same target
+        let player = || {
+            let mut player = Player::new("player".to_string(), 10);
+            let sigma = player.skill_profiles[&ClubCategory::MidIron].kalman_filter.estimate;
+            player.set_true_sigma(ClubCategory::MidIron, sigma);
+            player
+        };
+        let run = |noise: f64, seed: u64| {
+            let noisy = SessionConfig { sensor_noise_std: noise, seed: Some(seed), ..config.clone() };
+            run_session(&mut player(), noisy)
+        };
+
+        // Zero noise reproduces the noiseless session exactly
+        let baseline = run_session(&mut player(), SessionConfig { seed: Some(1), ..config.clone() });
+        let zero_noise = run(0.0, 1);
+        assert_eq!(zero_noise.total_won, baseline.total_won);
+        assert_eq!(zero_noise.final_skill_profiles, baseline.final_skill_profiles);
+
+        // (variance of the converged estimate across sessions, realized RTP, its std error)
+        let summarize = |noise: f64| {
+            let results: Vec<SessionResult> = (0..60).map(|seed| run(noise, seed)).collect();
+            let estimates: Vec<f64> = results.iter().map(|r| r.final_skill_profiles["MidIron"]).collect();
+            let returns: Vec<f64> = results
+                .iter()
+                .flat_map(|r| r.shots.iter().map(|s| s.payout / s.wager))
+                .collect();
+            let (estimate, _) = mean_and_std_error(&estimates);
+            let variance = estimates.iter().map(|e| (e - estimate).powi(2)).sum::<f64>() / (estimates.len() - 1) as f64;
+            let (rtp, rtp_se) = mean_and_std_error(&returns);
+            (variance, rtp, rtp_se)
+        };
+        let (clean_variance, clean_rtp, clean_rtp_se) = summarize(0.0);
+        let (noisy_variance, noisy_rtp, noisy_rtp_se) = summarize(25.0);
+
+        // Each noisy batch mean is further from the truth, so sessions end
+        // further apart. With 60 sessions a variance is known to within ~20%.
+        assert!(noisy_variance > 1.5 * clean_variance,
+            "Estimate variance {:.3} vs noiseless {:.3}", noisy_variance, clean_variance);
+
+        // Errors that push a measurement below zero are clamped to a dead-center
+        // shot paying P_max, which the payout model never priced in
+        let rtp_se = (clean_rtp_se.powi(2) + noisy_rtp_se.powi(2)).sqrt();
+        assert!(noisy_rtp - clean_rtp > 3.0 * rtp_se,
+            "RTP {:.4} vs noiseless {:.4} (std error {:.4})", noisy_rtp, clean_rtp, rtp_se);
+    }
+
+    #[test]
+    fn test_estimate_lags_hidden_true_skill() {
+        use crate::models::player::calculate_initial_dispersion;
+        use crate::test_support::mean_and_std_error;
+
+        // A handicap-25 player who actually plays like a 5 on mid irons
+        let reference = ClubCategory::MidIron.reference_distance_yds();
+        let true_sigma = calculate_initial_dispersion(5, reference);
+        let config = SessionConfig {
+            num_shots: 300,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Fixed(4),
+            ..Default::default()
+        };
+
+        let mut early_returns = Vec::new();
+        let mut late_returns = Vec::new();
+        let mut catch_up_shots = Vec::new();
+        for seed in 0..30 {
+            let mut player = Player::new("ringer".to_string(), 25);
+            player.set_true_sigma(ClubCategory::MidIron, true_sigma);
+            assert!(player.skill_profiles[&ClubCategory::MidIron].kalman_filter.estimate > 2.0 * true_sigma);
+
+            let result = run_session(&mut player, SessionConfig { seed: Some(seed), ..config.clone() });
+            let returns: Vec<f64> = result.shots.iter().map(|s| s.payout / s.wager).collect();
+            early_returns.extend_from_slice(&returns[..20]);
+            late_returns.extend_from_slice(&returns[200..]);
+
+            // First update that brought the estimate within 10% of the truth
+            let caught_up = result
+                .convergence_trajectory
+                .iter()
+                .find(|(_, _, _, sigma)| (sigma - true_sigma).abs() < 0.1 * true_sigma)
+                .map(|(shot, _, _, _)| *shot)
+                .expect("Estimate never caught up with true skill");
+            catch_up_shots.push(caught_up as f64);
+        }
+
+        let (early_rtp, early_se) = mean_and_std_error(&early_returns);
+        let (late_rtp, late_se) = mean_and_std_error(&late_returns);
+        let (catch_up, _) = mean_and_std_error(&catch_up_shots);
+        println!("Early RTP {:.3} ± {:.3}, late RTP {:.3} ± {:.3}, caught up after {:.0} shots",
+            early_rtp, early_se, late_rtp, late_se, catch_up);
+
+        // Odds priced for a 25 handicap hand the better player an edge...
+        assert!(early_rtp - 3.0 * early_se > 1.0, "Early RTP {:.3} ± {:.3}", early_rtp, early_se);
+        // ...until the filter catches up and the house edge returns
+        assert!(late_rtp + 3.0 * late_se < 1.0, "Late RTP {:.3} ± {:.3}", late_rtp, late_se);
+        assert!(catch_up < 200.0);
+    }
+
+    #[test]
+    fn test_skill_transfer_speeds_rare_category() {
+        use crate::models::player::calculate_initial_dispersion;
+        use crate::test_support::{assert_within_ci, mean_and_std_error};
+
+        // Priced as a 25 handicap, plays like a 10; mostly mid irons, few long irons
+        let config = SessionConfig {
+            num_shots: 150,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Weighted(vec![(4, 0.9), (7, 0.1)]),
+            ..Default::default()
+        };
+        let long_iron_truth = calculate_initial_dispersion(10, ClubCategory::LongIron.reference_distance_yds());
+        let long_iron_error = |transfer: f64| {
+            let errors: Vec<f64> = (0..40)
+                .map(|seed| {
+                    let mut player = Player::new("improver".to_string(), 25);
+                    player.skill_transfer = transfer;
+                    for category in [ClubCategory::Wedge, ClubCategory::MidIron, ClubCategory::LongIron] {
+                        player.set_true_sigma(category, calculate_initial_dispersion(10, category.reference_distance_yds()));
+                    }
+                    run_session(&mut player, SessionConfig { seed: Some(seed), ..config.clone() });
+                    let estimate = player.skill_profiles[&ClubCategory::LongIron].kalman_filter.estimate;
+                    (estimate - long_iron_truth).abs() / long_iron_truth
+                })
+                .collect();
+            mean_and_std_error(&errors).0
+        };
+
+        let independent = long_iron_error(0.0);
+        let transferred = long_iron_error(0.5);
+        println!("LongIron relative error: independent {:.3}, with transfer {:.3}", independent, transferred);
+        assert!(transferred < 0.75 * independent, "independent {:.3} vs transfer {:.3}", independent, transferred);
+
+        // Transfer only moves estimates; odds still price each shot at the target RTP
+        let returns: Vec<f64> = (0..40)
+            .flat_map(|seed| {
+                let mut player = Player::new("regular".to_string(), 15);
+                player.skill_transfer = 0.5;
+                let result = run_session(&mut player, SessionConfig {
+                    num_shots: 200,
+                    seed: Some(seed),
+                    ..Default::default()
+                });
+                result.shots.iter().map(|s| s.payout / s.wager).collect::<Vec<_>>()
+            })
+            .collect();
+        let (rtp, std_error) = mean_and_std_error(&returns);
+        assert_within_ci(rtp, 0.85, std_error, 4.0);
     }
 
     #[test]
-    fn test_hole_selection_weighted() {
-        // 100% weight on hole 5
-        let selection = HoleSelection::Weighted(vec![(5, 1.0)]);
-        let mut rng = rand::thread_rng();
+    fn test_max_payout_cap_limits_high_roller_exposure() {
+        let config = SessionConfig {
+            num_shots: 300,
+            wager_min: 10_000.0,
+            wager_max: 10_000.0,
+            seed: Some(259),
+            ..Default::default()
+        };
+        let uncapped = run_session(&mut Player::new("whale".to_string(), 25), config.clone());
+        let capped = run_session(
+            &mut Player::new("whale".to_string(), 25),
+            SessionConfig { max_payout_dollars: Some(100_000.0), ..config },
+        );
 
-        for _ in 0..10 {
-            let hole = select_hole(&selection, &mut rng);
-            assert_eq!(hole.id, 5);
+        assert_eq!(uncapped.num_capped_shots, 0);
+        assert!(capped.num_capped_shots > 0);
+        assert!(capped.total_won < uncapped.total_won,
+            "capped {} vs uncapped {}", capped.total_won, uncapped.total_won);
+        assert!(capped.shots.iter().all(|shot| shot.payout <= 100_000.0));
+
+        // The cap only lowers payouts, shot for shot
+        let over_cap = uncapped.shots.iter().filter(|shot| shot.payout > 100_000.0).count();
+        assert_eq!(capped.num_capped_shots, over_cap);
+        for (capped_shot, shot) in capped.shots.iter().zip(&uncapped.shots) {
+            assert_eq!(capped_shot.payout, shot.payout.min(100_000.0));
         }
     }
 
     #[test]
-    fn test_run_session_basic() {
-        let mut player = Player::new("test_player".to_string(), 15);
+    fn test_jackpot_accumulates_and_pays_on_ace() {
+        let jackpot = JackpotState { pool: 1000.0, contribution_rate: 0.05 };
         let config = SessionConfig {
-            num_shots: 10,
-            wager_min: 5.0,
+            num_shots: 20,
+            wager_min: 10.0,
             wager_max: 10.0,
-            hole_selection: HoleSelection::Fixed(4),
-            developer_mode: None,
+            seed: Some(267),
+            jackpot,
             ..Default::default()
         };
 
-        let result = run_session(&mut player, config);
+        // Without aces the pool only grows
+        let result = run_session(&mut Player::new("test".to_string(), 15), config.clone());
+        let without = run_session(
+            &mut Player::new("test".to_string(), 15),
+            SessionConfig { jackpot: JackpotState::default(), ..config.clone() },
+        );
+        let aces = result.shots.iter().filter(|shot| shot.miss_distance_ft < ACE_DISTANCE_FT).count();
+        assert_eq!(aces, 0);
+        assert_eq!(result.num_jackpots_hit, 0);
+        assert!((result.jackpot_final_pool - (1000.0 + 0.05 * result.total_wagered)).abs() < 1e-9);
+        assert_eq!(result.total_won, without.total_won);
 
-        assert_eq!(result.shots.len(), 10);
-        assert!(result.total_wagered >= 50.0 && result.total_wagered <= 100.0);
-        assert_eq!(result.net_gain_loss, result.total_won - result.total_wagered);
-        // House edge can be negative in individual sessions (player wins)
-        // Typically should be between -5.0 and 1.0 for small sample sizes
-        assert!(result.session_house_edge >= -5.0 && result.session_house_edge <= 1.0);
+        // Every shot an ace: the first takes the seeded pool, later ones their own contribution
+        let aces_only = run_session(
+            &mut Player::new("test".to_string(), 15),
+            SessionConfig {
+                developer_mode: Some(DeveloperMode { manual_miss_distance: Some(0.05), manual_miss_sequence: None, disable_kalman: false }),
+                ..config
+            },
+        );
+        assert_eq!(aces_only.num_jackpots_hit, 20);
+        assert_eq!(aces_only.jackpot_final_pool, 0.0);
+        assert!((aces_only.jackpot_won - (1000.0 + 0.05 * 200.0)).abs() < 1e-9);
+        let shot_payouts: f64 = aces_only.shots.iter().map(|shot| shot.payout).sum();
+        assert!((aces_only.total_won - shot_payouts - aces_only.jackpot_won).abs() < 1e-9);
     }
 
     #[test]
-    fn test_run_session_developer_mode_manual_miss() {
-        let mut player = Player::new("test_player".to_string(), 15);
+    fn test_small_bankroll_goes_bust() {
         let config = SessionConfig {
-            num_shots: 5,
+            num_shots: 100,
             wager_min: 10.0,
             wager_max: 10.0,
-            hole_selection: HoleSelection::Fixed(4),
+            seed: Some(268),
+            starting_bankroll: Some(50.0),
+            ..Default::default()
+        };
+        let result = run_session(&mut Player::new("hacker".to_string(), 30), config.clone());
+
+        assert!(result.went_bust);
+        assert!(result.shots_completed < 100);
+        assert_eq!(result.shots_completed, result.shots.len());
+        assert!(result.final_bankroll < 10.0);
+        assert!((result.final_bankroll - (50.0 + result.net_gain_loss)).abs() < 1e-9);
+
+        // Without a bankroll the same session plays out in full
+        let unlimited = run_session(
+            &mut Player::new("hacker".to_string(), 30),
+            SessionConfig { starting_bankroll: None, ..config.clone() },
+        );
+        assert!(!unlimited.went_bust);
+        assert_eq!(unlimited.shots_completed, 100);
+        assert_eq!(unlimited.final_bankroll, 0.0);
+
+        // Losing the last of the bankroll on the final shot is bust too
+        let all_misses = |bankroll: f64| SessionConfig {
+            num_shots: 3,
+            starting_bankroll: Some(bankroll),
             developer_mode: Some(DeveloperMode {
-                manual_miss_distance: Some(5.0), // Always miss by 5ft
-                disable_kalman: false,
+                manual_miss_distance: Some(500.0),
+                manual_miss_sequence: None,
+                disable_kalman: true,
             }),
+            ..config.clone()
+        };
+        let drained = run_session(&mut Player::new("hacker".to_string(), 30), all_misses(30.0));
+        assert_eq!(drained.shots_completed, 3);
+        assert!(drained.went_bust);
+        let spare = run_session(&mut Player::new("hacker".to_string(), 30), all_misses(40.0));
+        assert_eq!(spare.shots_completed, 3);
+        assert!(!spare.went_bust);
+    }
+
+    #[test]
+    fn test_antithetic_shots_reduce_rtp_standard_error() {
+        use crate::test_support::{assert_within_ci, mean_and_std_error};
+
+        let hole = get_hole_by_id(4).unwrap();
+        let config = SessionConfig {
+            num_shots: 5000,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Fixed(4),
+            // Constant P_max, so every shot has the same payout distribution
+            developer_mode: Some(DeveloperMode { manual_miss_distance: None, manual_miss_sequence: None, disable_kalman: true }),
+            seed: Some(260),
+            ..Default::default()
+        };
+        let multipliers = |use_antithetic: bool| -> Vec<f64> {
+            let result = run_session(
+                &mut Player::new("test".to_string(), 5),
+                SessionConfig { use_antithetic, ..config.clone() },
+            );
+            result.shots.iter().map(|shot| shot.multiplier).collect()
+        };
+
+        let standard = multipliers(false);
+        let (standard_rtp, standard_se) = mean_and_std_error(&standard);
+
+        // Antithetic pairs are independent of each other, not within a pair
+        let pair_means: Vec<f64> = multipliers(true).chunks(2).map(|pair| (pair[0] + pair[1]) / 2.0).collect();
+        let (antithetic_rtp, antithetic_se) = mean_and_std_error(&pair_means);
+
+        assert_within_ci(standard_rtp, hole.rtp, standard_se, 4.0);
+        assert_within_ci(antithetic_rtp, hole.rtp, antithetic_se, 4.0);
+        // Same shot count; the gain is largest for tight players, whose
+        // payouts depend most on U (about 0.7× the standard error here)
+        assert!(antithetic_se < 0.85 * standard_se,
+            "antithetic SE {:.4} vs standard SE {:.4}", antithetic_se, standard_se);
+    }
+
+    #[test]
+    fn test_directional_bias_raises_skill_estimate() {
+        use crate::models::shot::equivalent_rayleigh_sigma;
+
+        // H2 is at the wedge reference distance, so the bias applies unscaled
+        let hole = get_hole_by_id(2).unwrap();
+        let config = SessionConfig {
+            num_shots: 400,
+            hole_selection: HoleSelection::Fixed(2),
+            seed: Some(261),
             ..Default::default()
         };
+        let mut player = Player::new("slicer".to_string(), 15);
+        let sigma = player.get_skill_for_hole(hole).kalman_filter.estimate;
+        player.set_true_sigma(ClubCategory::Wedge, sigma);
+        player.set_shot_bias(ClubCategory::Wedge, sigma, 0.0);
 
         let result = run_session(&mut player, config);
 
-        // All shots should have exactly 5ft miss distance
-        for shot in &result.shots {
-            assert_eq!(shot.miss_distance_ft, 5.0);
+        // Misses land right of the pin, and the filter learns a wider scalar sigma
+        let estimate = player.get_skill_for_hole(hole).kalman_filter.estimate;
+        let equivalent = equivalent_rayleigh_sigma(sigma, sigma, sigma, 0.0);
+        assert!(estimate > 1.15 * sigma, "estimate {:.2} vs sigma {:.2}", estimate, sigma);
+        assert!((estimate / equivalent - 1.0).abs() < 0.15,
+            "estimate {:.2} vs equivalent {:.2}", estimate, equivalent);
+        assert!(result.shots.iter().all(|shot| shot.miss_distance_ft.is_finite()));
+    }
+
+    #[test]
+    fn test_onboarding_shots() {
+        const ONBOARDING: usize = 10;
+        let config = SessionConfig {
+            num_shots: 60,
+            wager_min: 5.0,
+            wager_max: 20.0,
+            hole_selection: HoleSelection::Fixed(3),
+            seed: Some(236),
+            ..Default::default()
+        };
+        let promo = SessionConfig { onboarding_shots: ONBOARDING, ..config.clone() };
+
+        let regular = run_session(&mut Player::new("newcomer".to_string(), 25), config);
+        let onboarded = run_session(&mut Player::new("newcomer".to_string(), 25), promo);
+
+        // The promotion never loses the player money on the first K shots
+        for shot in &onboarded.shots[..ONBOARDING] {
+            assert!(shot.payout >= shot.wager, "{:?}", shot);
+            assert!(shot.multiplier >= 1.0, "{:?}", shot);
         }
+
+        // Onboarding draws no randomness and misses still feed the filter,
+        // so every later shot is identical to the regular session
+        assert_eq!(onboarded.shots[ONBOARDING..], regular.shots[ONBOARDING..]);
+        assert_eq!(onboarded.final_skill_profiles, regular.final_skill_profiles);
+
+        let subsidy: f64 = onboarded.shots[..ONBOARDING]
+            .iter()
+            .zip(&regular.shots[..ONBOARDING])
+            .map(|(promo_shot, shot)| promo_shot.payout - shot.payout)
+            .sum();
+        assert!(subsidy > 0.0, "A handicap-25 player should miss at least one onboarding shot");
+        assert!((onboarded.onboarding_cost - subsidy).abs() < 1e-9);
+        assert!((onboarded.total_won - regular.total_won - subsidy).abs() < 1e-9);
+        assert_eq!(regular.onboarding_cost, 0.0);
     }
 
     #[test]
-    fn test_run_session_developer_mode_disable_kalman() {
-        let mut player = Player::new("test_player".to_string(), 15);
-        let hole = get_hole_by_id(4).unwrap();
-        let initial_sigma = player.get_skill_for_hole(hole).kalman_filter.estimate;
+    fn test_wager_confidence_link() {
+        use crate::test_support::{assert_within_ci, mean_and_std_error};
 
+        // MidIron is played most often, so its estimate becomes the most certain;
+        // LongIron is played least and stays the least certain
         let config = SessionConfig {
-            num_shots: 20,
+            num_shots: 200,
             wager_min: 5.0,
-            wager_max: 10.0,
-            hole_selection: HoleSelection::Fixed(4),
-            developer_mode: Some(DeveloperMode {
-                manual_miss_distance: None,
-                disable_kalman: true, // No updates
-            }),
+            wager_max: 50.0,
+            hole_selection: HoleSelection::Weighted(vec![(4, 0.7), (2, 0.2), (7, 0.1)]),
+            wager_confidence_link: 0.8,
             ..Default::default()
         };
 
-        let result = run_session(&mut player, config);
+        let mut wagers: HashMap<ClubCategory, Vec<f64>> = HashMap::new();
+        let mut returns: HashMap<ClubCategory, Vec<f64>> = HashMap::new();
+        for seed in 0..40 {
+            let mut player = Player::new("confident".to_string(), 12);
+            let result = run_session(&mut player, SessionConfig { seed: Some(seed), ..config.clone() });
+            for shot in &result.shots {
+                let category = get_hole_by_id(shot.hole_id).unwrap().category;
+                wagers.entry(category).or_default().push(shot.wager);
+                returns.entry(category).or_default().push(shot.payout / shot.wager);
+            }
+        }
 
-        assert_eq!(result.num_kalman_updates, 0);
+        let mean_wager = |category| mean_and_std_error(&wagers[&category]).0;
+        assert!(mean_wager(ClubCategory::MidIron) > mean_wager(ClubCategory::LongIron),
+            "MidIron {:.2} vs LongIron {:.2}", mean_wager(ClubCategory::MidIron), mean_wager(ClubCategory::LongIron));
 
-        // Skill should not have changed
-        let final_sigma = player.get_skill_for_hole(hole).kalman_filter.estimate;
-        assert_eq!(initial_sigma, final_sigma);
+        // Wager sizing doesn't change how shots are priced
+        for (category, category_returns) in &returns {
+            let (rtp, std_error) = mean_and_std_error(category_returns);
+            println!("{:?}: RTP {:.4} ± {:.4}", category, rtp, std_error);
+            assert_within_ci(rtp, 0.85, std_error, 4.0);
+        }
+    }
+
+    #[test]
+    fn test_zero_wager_and_zero_shot_sessions_are_finite() {
+        let free_play = run_session(
+            &mut Player::new("player".to_string(), 15),
+            SessionConfig { num_shots: 50, wager_min: 0.0, wager_max: 0.0, seed: Some(235), ..Default::default() },
+        );
+        assert_eq!(free_play.total_wagered, 0.0);
+        assert_eq!(free_play.session_house_edge, 0.0);
+        assert_eq!(free_play.house_edge_percent(), 0.0);
+        assert_eq!(free_play.effective_rtp_with_comps(), 0.0);
+        // Free-play shots still measure skill rather than dragging sigma to zero
+        let prior = Player::new("player".to_string(), 15);
+        for (category, profile) in &prior.skill_profiles {
+            let sigma = free_play.final_skill_profiles[&format!("{:?}", category)];
+            let prior_sigma = profile.kalman_filter.estimate;
+            assert!(sigma > 0.5 * prior_sigma, "{:?} sigma {:.2} vs prior {:.2}", category, sigma, prior_sigma);
+        }
+
+        let empty = run_session(
+            &mut Player::new("player".to_string(), 15),
+            SessionConfig { num_shots: 0, ..Default::default() },
+        );
+        assert!(empty.shots.is_empty());
+        assert_eq!(empty.net_gain_loss, 0.0);
+        assert_eq!(empty.session_house_edge, 0.0);
+        assert_eq!(empty.avg_wager(), 0.0);
+        assert_eq!(empty.win_rate(), 0.0);
     }
 
     #[test]
@@ -452,6 +2580,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 30.0,
@@ -460,6 +2589,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 15.0,
@@ -468,6 +2598,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 8.0,
@@ -476,6 +2607,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 25.0,
@@ -484,6 +2616,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 12.0,
@@ -492,6 +2625,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 20.0,
@@ -500,6 +2634,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 9.0,
@@ -508,6 +2643,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 30.0,
@@ -516,6 +2652,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
                 ShotOutcome {
                     miss_distance_ft: 11.0,
@@ -524,6 +2661,7 @@ mod tests {
                     wager: 10.0,
                     hole_id: 1,
                     is_fat_tail: false,
+                    timestamp_ms: None,
                 },
             ],
             final_skill_profiles: HashMap::new(),
@@ -532,6 +2670,7 @@ mod tests {
             num_high_stakes_shots: 0,
             cherry_picking_report: None,
             sandbagging_report: None,
+            ..Default::default()
         };
 
         assert_eq!(result.house_edge_percent(), 12.0);
@@ -558,4 +2697,466 @@ mod tests {
         assert!(result.num_kalman_updates > 0,
             "Expected Kalman updates, got {}", result.num_kalman_updates);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_session_shot_class_histogram() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let config = SessionConfig {
+            num_shots: 50,
+            hole_selection: HoleSelection::Fixed(4),
+            ..Default::default()
+        };
+
+        let result = run_session(&mut player, config);
+
+        let total: usize = result.shot_classes.values().sum();
+        assert_eq!(total, result.shots.len());
+        assert_eq!(result.shot_classes, shot_class_histogram(&result.shots));
+    }
+
+    #[test]
+    fn test_replay_reproduces_saved_session() {
+        // A player with history, so the snapshot carries a trained Kalman state
+        let mut player = Player::new("test_player".to_string(), 15);
+        run_session(&mut player, SessionConfig { num_shots: 60, seed: Some(1), ..Default::default() });
+
+        let config = SessionConfig { num_shots: 120, hole_selection: HoleSelection::Random, ..Default::default() };
+        let recording = SessionReplay::new(&player, config.clone(), 277);
+        let original = run_session(&mut player, SessionConfig { seed: Some(277), ..config });
+
+        let path = "test_session_replay.json";
+        recording.save_replay(path).unwrap();
+        let loaded = SessionReplay::load_replay(path).unwrap();
+        std::fs::remove_file(path).ok();
+
+        let replayed = replay(&loaded);
+        assert_eq!(replayed.total_won, original.total_won);
+        assert_eq!(replayed.shots, original.shots);
+        assert_eq!(replayed.final_skill_profiles, original.final_skill_profiles);
+    }
+
+    #[test]
+    fn test_martingale_wagers_vary_more_than_flat() {
+        let wager_variance = |result: &SessionResult| {
+            let mean = result.avg_wager();
+            result.shots.iter().map(|shot| (shot.wager - mean).powi(2)).sum::<f64>() / result.shots.len() as f64
+        };
+        let config = SessionConfig { num_shots: 300, seed: Some(280), ..Default::default() };
+
+        let flat = run_session(&mut Player::new("flat".to_string(), 15), config.clone());
+        let martingale = run_session(
+            &mut Player::new("martingale".to_string(), 15),
+            SessionConfig { betting_strategy: BettingStrategy::Martingale { base: 5.0, max: 640.0 }, ..config.clone() },
+        );
+        assert!(wager_variance(&martingale) > wager_variance(&flat));
+
+        // Doubling after every loss, resetting after every win
+        for pair in martingale.shots.windows(2) {
+            let expected = if pair[0].net_result() < 0.0 { (pair[0].wager * 2.0).min(640.0) } else { 5.0 };
+            assert_eq!(pair[1].wager, expected);
+        }
+
+        // Drawdown is the deepest fall of the running net from its peak
+        let mut net: f64 = 0.0;
+        let mut peak: f64 = 0.0;
+        let mut drawdown: f64 = 0.0;
+        for shot in &martingale.shots {
+            net += shot.net_result();
+            peak = peak.max(net);
+            drawdown = drawdown.max(peak - net);
+        }
+        assert!(martingale.jackpot_won == 0.0 && (martingale.max_drawdown - drawdown).abs() < 1e-9);
+        assert!(martingale.max_drawdown > flat.max_drawdown);
+    }
+
+    #[test]
+    fn test_proportional_betting_tracks_bankroll() {
+        let config = SessionConfig {
+            num_shots: 50,
+            seed: Some(281),
+            starting_bankroll: Some(1000.0),
+            betting_strategy: BettingStrategy::Proportional { fraction: 0.05 },
+            ..Default::default()
+        };
+        let result = run_session(&mut Player::new("kelly".to_string(), 15), config);
+
+        let mut balance: f64 = 1000.0;
+        for shot in &result.shots {
+            assert!((shot.wager - (0.05 * balance).max(5.0)).abs() < 1e-9);
+            balance += shot.net_result();
+        }
+        assert!((result.final_bankroll - balance).abs() < 1e-9);
+    }
+
+    #[test]
+    fn test_wind_widens_long_holes_more() {
+        let avg_miss = |hole_id: u8, environment: Option<Environment>| {
+            let config = SessionConfig {
+                num_shots: 500,
+                hole_selection: HoleSelection::Fixed(hole_id),
+                seed: Some(284),
+                environment,
+                ..Default::default()
+            };
+            let result = run_session(&mut Player::new("test_player".to_string(), 15), config);
+            let avg = result.shots.iter().map(|s| s.miss_distance_ft).sum::<f64>() / result.shots.len() as f64;
+            (avg, result.sigma_multiplier)
+        };
+        let wind = Some(Environment { wind_mph: 20.0, ..Default::default() });
+
+        let (calm_1, calm_multiplier) = avg_miss(1, None);
+        let (windy_1, _) = avg_miss(1, wind);
+        let (calm_8, _) = avg_miss(8, None);
+        let (windy_8, windy_multiplier) = avg_miss(8, wind);
+
+        assert_eq!(calm_multiplier, 1.0);
+        assert!((windy_multiplier - 1.5).abs() < 1e-12);
+        assert!(windy_1 > calm_1);
+        assert!(windy_8 / calm_8 > windy_1 / calm_1,
+            "H8 {:.2} -> {:.2}, H1 {:.2} -> {:.2}", calm_8, windy_8, calm_1, windy_1);
+    }
+
+    #[test]
+    fn test_kalman_batch_size_sets_update_cadence() {
+        let updates = |kalman_batch_size: usize| {
+            let config = SessionConfig {
+                num_shots: 100,
+                wager_min: 10.0,
+                wager_max: 10.0,
+                hole_selection: HoleSelection::Fixed(4),
+                seed: Some(286),
+                kalman_batch_size,
+                ..Default::default()
+            };
+            let mut player = Player::new("test_player".to_string(), 15);
+            let result = run_session(&mut player, config);
+            assert!(player.skill_profiles.values().all(|p| p.batch_size == kalman_batch_size));
+            result.num_kalman_updates
+        };
+
+        assert_eq!(updates(1), 100);
+        assert_eq!(updates(10), 10);
+    }
+
+    #[test]
+    fn test_session_high_stakes_multiplier_sets_session_trigger() {
+        // Every shot loses, so a Martingale bettor doubles each wager
+        let high_stakes = |session_high_stakes_multiplier: f64| {
+            let config = SessionConfig {
+                num_shots: 20,
+                hole_selection: HoleSelection::Fixed(4),
+                developer_mode: Some(DeveloperMode {
+                    manual_miss_distance: Some(1000.0),
+                    manual_miss_sequence: None,
+                    disable_kalman: false,
+                }),
+                betting_strategy: BettingStrategy::Martingale { base: 1.0, max: 1e9 },
+                session_high_stakes_multiplier,
+                ..Default::default()
+            };
+            let mut player = Player::new("test_player".to_string(), 15);
+            let result = run_session(&mut player, config);
+            (result.num_high_stakes_shots, result.num_kalman_updates)
+        };
+
+        let (flagged_5x, updates_5x) = high_stakes(5.0);
+        let (flagged_10x, updates_10x) = high_stakes(10.0);
+        assert!(flagged_5x > flagged_10x, "{} vs {}", flagged_5x, flagged_10x);
+        assert!(updates_5x > updates_10x, "{} vs {}", updates_5x, updates_10x);
+    }
+
+    #[test]
+    fn test_session_leaves_profile_high_stakes_multiplier() {
+        use crate::models::shot::DEFAULT_HIGH_STAKES_MULTIPLIER;
+
+        let mut player = Player::new("test_player".to_string(), 15);
+        run_session(&mut player, SessionConfig { num_shots: 50, seed: Some(306), ..Default::default() });
+
+        for skill in player.skill_profiles.values() {
+            assert_eq!(skill.high_stakes_multiplier, DEFAULT_HIGH_STAKES_MULTIPLIER);
+        }
+
+        // The profile trigger is still 10× the batch average, not the session's 2×
+        let hole = get_hole_by_id(4).unwrap();
+        player.add_shot_to_batch(hole, 20.0, 10.0);
+        assert!(!player.is_high_stakes_shot(hole, 50.0));
+        assert!(player.is_high_stakes_shot(hole, 100.0));
+    }
+
+    #[test]
+    fn test_miss_model_drives_session_shots() {
+        use approx::assert_relative_eq;
+
+        let play = |miss_model: MissModel| {
+            let config = SessionConfig {
+                num_shots: 200,
+                wager_min: 10.0,
+                wager_max: 10.0,
+                hole_selection: HoleSelection::Fixed(4),
+                seed: Some(299),
+                miss_model,
+                ..Default::default()
+            };
+            let mut player = Player::new("test_player".to_string(), 15);
+            let result = run_session(&mut player, config);
+            assert_eq!(player.miss_model, miss_model);
+            result.shots.iter().map(|shot| shot.miss_distance_ft).collect::<Vec<f64>>()
+        };
+
+        // Weibull with shape 2 draws the Rayleigh misses from the same uniforms
+        let rayleigh = play(MissModel::Rayleigh);
+        for (weibull, rayleigh) in play(MissModel::Weibull { shape: 2.0 }).iter().zip(&rayleigh) {
+            assert_relative_eq!(*weibull, *rayleigh, max_relative = 1e-9);
+        }
+
+        let gamma = play(MissModel::Gamma { shape: 1.2 });
+        assert!(gamma.iter().zip(&rayleigh).any(|(g, r)| (g - r).abs() > 1e-6));
+    }
+
+    #[test]
+    #[should_panic(expected = "kalman_batch_size must be at least 1")]
+    fn test_kalman_batch_size_zero_rejected() {
+        let config = SessionConfig { kalman_batch_size: 0, ..Default::default() };
+        run_session(&mut Player::new("test_player".to_string(), 15), config);
+    }
+
+    #[test]
+    fn test_drift_reset_after_skill_halves() {
+        let hole_id = 4;
+        let config = SessionConfig {
+            wager_min: 10.0,
+            wager_max: 10.0,
+            hole_selection: HoleSelection::Fixed(hole_id),
+            seed: Some(288),
+            ..Default::default()
+        };
+        let mut player = Player::new("improver".to_string(), 15);
+        let category = get_hole_by_id(hole_id).unwrap().category;
+        let skill = player.skill_profiles.get_mut(&category).unwrap();
+        skill.auto_reset_on_drift = true;
+        let sigma = skill.kalman_filter.estimate;
+        skill.true_sigma = Some(sigma);
+
+        let mut session = SessionState::new(config.clone());
+        let mut rng = StdRng::seed_from_u64(288);
+        let mut first_reset = None;
+        for shot in 0..200 {
+            if shot == 100 {
+                // Lessons pay off: dispersion halves overnight
+                player.skill_profiles.get_mut(&category).unwrap().true_sigma = Some(sigma / 2.0);
+            }
+            session.play_shot_with_rng(&mut player, 10.0, &mut rng);
+            if first_reset.is_none() && player.drift_resets() > 0 {
+                first_reset = Some(shot);
+            }
+        }
+        let result = session.finalize(&mut player);
+
+        // No false alarm while skill is steady, then a reset within 8 batches of 5
+        let first_reset = first_reset.expect("drift should be detected");
+        assert!((100..140).contains(&first_reset), "first reset at shot {}", first_reset);
+        assert!(result.num_drift_resets >= 1);
+        assert_eq!(result.num_drift_resets, player.drift_resets());
+
+        // Getting worse is left to the filter, however sharp the change
+        let mut player = Player::new("sandbagger".to_string(), 15);
+        let skill = player.skill_profiles.get_mut(&category).unwrap();
+        skill.auto_reset_on_drift = true;
+        skill.true_sigma = Some(sigma * 2.0);
+        let worse = run_session(&mut player, SessionConfig { num_shots: 200, ..config });
+        assert_eq!(worse.num_drift_resets, 0);
+    }
+
+    #[test]
+    fn test_miss_percentiles_match_rayleigh_quantiles() {
+        use crate::math::distributions::rayleigh_quantile;
+
+        let config = SessionConfig {
+            num_shots: 5000,
+            hole_selection: HoleSelection::Fixed(4),
+            fat_tail_prob: 0.0,
+            seed: Some(289),
+            developer_mode: Some(DeveloperMode { manual_miss_distance: None, manual_miss_sequence: None, disable_kalman: true }),
+            ..Default::default()
+        };
+        let mut player = Player::new("test_player".to_string(), 15);
+        let sigma = player.get_true_sigma(get_hole_by_id(4).unwrap());
+        let percentiles = run_session(&mut player, config).miss_percentiles();
+
+        assert_eq!(percentiles.keys().copied().collect::<Vec<_>>(), MISS_PERCENTILES);
+        for (&p, &miss) in &percentiles {
+            let expected = rayleigh_quantile(p as f64 / 100.0, sigma);
+            assert!((miss - expected).abs() < 0.05 * expected, "p{}: {:.2} vs {:.2}", p, miss, expected);
+        }
+        assert!(SessionResult::default().miss_percentiles().values().all(|&m| m == 0.0));
+    }
+
+    #[test]
+    fn test_run_sessions_parallel_matches_sequential() {
+        let configs: Vec<(Player, SessionConfig)> = (1..=8u8)
+            .flat_map(|hole_id| [0u8, 10, 20, 30].map(move |handicap| (hole_id, handicap)))
+            .map(|(hole_id, handicap)| {
+                let config = SessionConfig {
+                    num_shots: 50,
+                    hole_selection: HoleSelection::Fixed(hole_id),
+                    seed: Some(291 + hole_id as u64 * 100 + handicap as u64),
+                    ..Default::default()
+                };
+                (Player::new(format!("player_{}", handicap), handicap), config)
+            })
+            .collect();
+        let rtp = |results: &[SessionResult]| {
+            results.iter().map(|r| r.total_won).sum::<f64>() / results.iter().map(|r| r.total_wagered).sum::<f64>()
+        };
+
+        let sequential: Vec<SessionResult> = configs
+            .iter()
+            .cloned()
+            .map(|(mut player, config)| run_session(&mut player, config))
+            .collect();
+        let parallel = run_sessions_parallel(configs);
+
+        assert_eq!(parallel.len(), 32);
+        assert_eq!(rtp(&parallel), rtp(&sequential));
+        for (p, s) in parallel.iter().zip(&sequential) {
+            assert_eq!(p.net_gain_loss, s.net_gain_loss);
+        }
+    }
+
+    #[test]
+    fn test_accumulate_sessions_sums_two_visits() {
+        let mut player = Player::new("test_player".to_string(), 15);
+        let visit = |seed: u64| SessionConfig {
+            num_shots: 10,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            seed: Some(seed),
+            ..Default::default()
+        };
+        let first = run_session(&mut player, visit(301));
+        let second = run_session(&mut player, visit(302));
+
+        let lifetime = accumulate_sessions(&[first.clone(), second.clone()]);
+
+        assert_eq!(lifetime.shots.len(), 20);
+        assert_eq!(lifetime.shots_completed, 20);
+        assert_eq!(lifetime.shots[..10], first.shots[..]);
+        assert_eq!(lifetime.shots[10..], second.shots[..]);
+        assert!((lifetime.total_wagered - 200.0).abs() < 1e-9);
+        assert!((lifetime.total_won - (first.total_won + second.total_won)).abs() < 1e-9);
+        assert!((lifetime.net_gain_loss - (first.net_gain_loss + second.net_gain_loss)).abs() < 1e-9);
+        assert!((lifetime.session_house_edge - (1.0 - lifetime.total_won / 200.0)).abs() < 1e-12);
+        assert_eq!(lifetime.num_kalman_updates, first.num_kalman_updates + second.num_kalman_updates);
+        assert_eq!(lifetime.fat_tail_count(), first.fat_tail_count() + second.fat_tail_count());
+        assert_eq!(lifetime.shot_classes.values().sum::<usize>(), 20);
+
+        let wins = first.shots.iter().chain(&second.shots).filter(|s| s.payout > 0.0).count();
+        assert!((lifetime.win_rate() - wins as f64 * 5.0).abs() < 1e-9);
+
+        // Skill comes from the most recent visit
+        assert_eq!(lifetime.final_skill_profiles, second.final_skill_profiles);
+
+        // The second visit's updates point past the first visit's shots
+        let later: Vec<usize> =
+            lifetime.convergence_trajectory[first.convergence_trajectory.len()..].iter().map(|t| t.0).collect();
+        let expected: Vec<usize> = second.convergence_trajectory.iter().map(|t| t.0 + 10).collect();
+        assert!(!expected.is_empty());
+        assert_eq!(later, expected);
+    }
+
+    #[test]
+    fn test_digest_detects_edited_shot() {
+        let config = SessionConfig { num_shots: 20, seed: Some(311), ..Default::default() };
+        let result = run_session(&mut Player::new("test_player".to_string(), 15), config.clone());
+        assert_eq!(result.digest.len(), 64);
+        assert!(result.digest_matches());
+
+        // Deterministic: the same seeded session hashes the same
+        let again = run_session(&mut Player::new("test_player".to_string(), 15), config);
+        assert_eq!(again.digest, result.digest);
+
+        // Survives a JSON round trip
+        let reloaded: SessionResult = serde_json::from_str(&serde_json::to_string(&result).unwrap()).unwrap();
+        assert!(reloaded.digest_matches());
+
+        let mut edited = result.clone();
+        edited.shots[7].payout += 0.01;
+        assert_ne!(edited.digest(), result.digest);
+        assert!(!edited.digest_matches());
+
+        let mut reordered = result.clone();
+        reordered.shots.swap(0, 1);
+        assert!(!reordered.digest_matches());
+    }
+
+    #[test]
+    fn test_run_session_with_callback_reports_progress() {
+        let config = || SessionConfig {
+            num_shots: 250,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            seed: Some(303),
+            ..Default::default()
+        };
+        let mut calls = Vec::new();
+        let on_progress = Box::new(|done: usize, total: usize| calls.push((done, total)));
+        let reported =
+            run_session_with_callback(&mut Player::new("test_player".to_string(), 15), config(), Some(on_progress));
+        assert_eq!(calls, vec![(100, 250), (200, 250), (250, 250)]);
+
+        // The callback only observes; results match a plain seeded run
+        let plain = run_session(&mut Player::new("test_player".to_string(), 15), config());
+        assert_eq!(reported.shots, plain.shots);
+        assert_eq!(reported.net_gain_loss, plain.net_gain_loss);
+
+        // A session ending on an interval is not reported twice
+        let mut calls = Vec::new();
+        let on_progress = Box::new(|done: usize, total: usize| calls.push((done, total)));
+        let config = SessionConfig { num_shots: 200, ..config() };
+        run_session_with_callback(&mut Player::new("test_player".to_string(), 15), config, Some(on_progress));
+        assert_eq!(calls, vec![(100, 200), (200, 200)]);
+    }
+
+    #[test]
+    fn test_net_variance_tracks_p_max() {
+        use crate::models::hole::Hole;
+
+        // A long hole priced for rare big wins: steepening H8's curve raises
+        // its P_max for a handicap-15 player to about 24× against H1's 15.8×
+        let mut holes = HoleSet::standard().holes().to_vec();
+        holes[7] = Hole::new(8, 250, 101.14, 0.85, 10.0);
+        let holes = HoleSet::new(holes).unwrap();
+        let player = Player::new("test_player".to_string(), 15);
+        assert!(player.calculate_p_max(holes.get_hole_by_id(8).unwrap())
+            > player.calculate_p_max(holes.get_hole_by_id(1).unwrap()));
+
+        // Estimates are held fixed, so each session reflects its hole's payouts
+        let session_on = |hole_id: u8| {
+            let config = SessionConfig {
+                num_shots: 2000,
+                wager_min: 10.0,
+                wager_max: 10.0,
+                hole_selection: HoleSelection::Fixed(hole_id),
+                holes: holes.clone(),
+                developer_mode: Some(DeveloperMode {
+                    manual_miss_distance: None,
+                    manual_miss_sequence: None,
+                    disable_kalman: true,
+                }),
+                seed: Some(282),
+                ..Default::default()
+            };
+            run_session(&mut Player::new("test_player".to_string(), 15), config)
+        };
+
+        let short = session_on(1);
+        let long = session_on(8);
+        assert!(long.net_variance() > short.net_variance(),
+            "H8 variance {:.2} vs H1 {:.2}", long.net_variance(), short.net_variance());
+        assert!((short.net_std_dev().powi(2) - short.net_variance()).abs() < 1e-9);
+
+        let mean_net = short.net_gain_loss / short.shots.len() as f64;
+        assert!((short.risk_adjusted_return() - mean_net / short.net_std_dev()).abs() < 1e-9);
+        assert_eq!(SessionResult::default().risk_adjusted_return(), 0.0);
+    }
+}
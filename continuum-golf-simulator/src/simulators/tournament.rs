@@ -1,18 +1,22 @@
 //! Tournament Simulator
 //!
 //! Simulates competitive tournaments with:
-//! - Multiple game modes (Longest Drive, Closest to Pin)
+//! - Multiple game modes (Longest Drive, Closest to Pin, Match Play)
 //! - Flexible payout structures (Winner Takes All, Top 2, Top 3)
 //! - House rake management
 //! - Leaderboard generation
+//! - Single-round or elimination bracket formats
 
 use crate::models::{
-    hole::get_hole_by_id,
+    hole::HoleSet,
     player::Player,
-    shot::simulate_shot,
+    shot::simulate_shot_with_rng,
 };
-use crate::simulators::venue::generate_player_pool;
+use crate::simulators::assert_finite;
+use crate::simulators::venue::generate_player_pool_with_rng;
 use crate::simulators::venue::PlayerArchetype;
+use rand::rngs::StdRng;
+use rand::{Rng, SeedableRng};
 use serde::{Deserialize, Serialize};
 
 /// Configuration for tournament
@@ -30,6 +34,42 @@ pub struct TournamentConfig {
     pub payout_structure: PayoutStructure,
     /// Number of attempts each player gets
     pub attempts_per_player: usize,
+    /// Optional RNG seed; when set, the field and every attempt are reproducible
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Resolution of closest-to-pin measurements in feet (default: 0.0 = exact)
+    ///
+    /// CTP scores are rounded to the nearest multiple, modelling the launch
+    /// monitor's precision. A coarse resolution produces genuine ties, which
+    /// are broken by pool order (see `run_tournament`).
+    #[serde(default)]
+    pub measurement_resolution: f64,
+    /// Hole layout; `ClosestToPin` hole ids are looked up here
+    #[serde(default)]
+    pub holes: HoleSet,
+    /// Single round or elimination bracket (default: single round)
+    #[serde(default)]
+    pub format: TournamentFormat,
+    /// Rank closest-to-pin on net scores (default: false = gross)
+    ///
+    /// Each player's measured miss is reduced by `handicap_allowance`, so
+    /// high handicaps can compete with low ones.
+    #[serde(default)]
+    pub net_scoring: bool,
+    /// Probability of a fat-tail shot (default: 0.02, as in `SessionConfig`)
+    #[serde(default = "default_fat_tail_prob")]
+    pub fat_tail_prob: f64,
+    /// Dispersion multiplier for fat-tail shots (default: 3.0)
+    #[serde(default = "default_fat_tail_mult")]
+    pub fat_tail_mult: f64,
+}
+
+fn default_fat_tail_prob() -> f64 {
+    0.02
+}
+
+fn default_fat_tail_mult() -> f64 {
+    3.0
 }
 
 impl Default for TournamentConfig {
@@ -45,10 +85,28 @@ impl Default for TournamentConfig {
                 third: 0.15,
             },
             attempts_per_player: 5,
+            seed: None,
+            measurement_resolution: 0.0,
+            holes: HoleSet::standard(),
+            format: TournamentFormat::SingleRound,
+            net_scoring: false,
+            fat_tail_prob: default_fat_tail_prob(),
+            fat_tail_mult: default_fat_tail_mult(),
         }
     }
 }
 
+/// How the field is played down to a winner
+#[derive(Debug, Clone, Copy, Default, PartialEq, Eq, Serialize, Deserialize)]
+pub enum TournamentFormat {
+    /// Everyone takes their attempts once and is ranked on that score
+    #[default]
+    SingleRound,
+    /// Up to `rounds` elimination rounds; the top half of each round advances
+    /// until a single champion remains (see `run_bracket_tournament`)
+    Bracket { rounds: usize },
+}
+
 /// Game mode for tournament
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum GameMode {
@@ -56,6 +114,8 @@ pub enum GameMode {
     LongestDrive,
     /// Closest to pin (minimize miss distance)
     ClosestToPin { hole_id: u8 },
+    /// Head-to-head matches over `attempts_per_player` holes (see `run_match_play`)
+    MatchPlay,
 }
 
 /// Prize payout structure
@@ -82,32 +142,338 @@ pub struct TournamentResult {
     pub prize_pool: f64,
     /// Prize payouts: (player_id, amount)
     pub payouts: Vec<(String, f64)>,
+    /// Per-round results of a bracket, in round order (empty for a single round)
+    #[serde(default)]
+    pub rounds: Vec<RoundResult>,
+    /// Matches of a single-round `MatchPlay` tournament
+    #[serde(default)]
+    pub match_play: Option<MatchPlayResult>,
+}
+
+/// One round of a bracket tournament
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct RoundResult {
+    /// Round number (1-based)
+    pub round: usize,
+    /// Round leaderboard: (player_id, best_score) for everyone who played it
+    pub leaderboard: Vec<(String, f64)>,
+    /// Players advancing from the round, best first
+    pub survivors: Vec<String>,
+    /// The round's matches in `MatchPlay` mode
+    #[serde(default)]
+    pub match_play: Option<MatchPlayResult>,
+}
+
+/// Results of one round of head-to-head matches
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchPlayResult {
+    /// Every match, in pairing order
+    pub matches: Vec<MatchResult>,
+    /// Win/loss record per player, in pool order
+    pub records: Vec<MatchRecord>,
+    /// Player left without an opponent in an odd field (counted as a win)
+    pub bye: Option<String>,
+}
+
+/// One head-to-head match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct MatchResult {
+    /// The two players, in pairing order
+    pub players: (String, String),
+    /// Outcome of each hole, in order
+    pub holes: Vec<HoleOutcome>,
+    /// Holes won by each player
+    pub holes_won: (usize, usize),
+    /// Match winner (None if the match is halved)
+    pub winner: Option<String>,
+}
+
+/// Outcome of one hole of a match
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HoleOutcome {
+    /// Hole played
+    pub hole_id: u8,
+    /// Measured miss distance of each player in feet
+    pub misses: (f64, f64),
+    /// 0 if the first player won the hole, 1 if the second did
+    pub winner: usize,
+    /// True if equal measurements were settled by a playoff shot
+    pub playoff: bool,
+}
+
+/// A player's match record
+#[derive(Debug, Clone, PartialEq, Eq, Serialize, Deserialize)]
+pub struct MatchRecord {
+    /// Player identifier
+    pub player_id: String,
+    /// Matches won (a bye counts as a win)
+    pub wins: usize,
+    /// Matches lost
+    pub losses: usize,
+    /// Matches halved
+    pub ties: usize,
+}
+
+impl MatchRecord {
+    /// Match points: 1 per win, 0.5 per halved match
+    pub fn points(&self) -> f64 {
+        self.wins as f64 + 0.5 * self.ties as f64
+    }
 }
 
 /// Run a tournament simulation
 ///
+/// Scores are computed independently per player, so results do not depend on
+/// the order of the player pool except when two scores tie exactly: the
+/// leaderboard sort is stable, so the earlier player keeps the higher rank.
+/// Use `shuffle_player_pool` to make that order reproducible.
+///
 /// # Arguments
 /// * `config` - Tournament configuration
 ///
 /// # Returns
 /// TournamentResult with leaderboard and payouts
 pub fn run_tournament(config: TournamentConfig) -> TournamentResult {
+    match config.seed {
+        Some(seed) => run_tournament_with_rng(config, &mut StdRng::seed_from_u64(seed)),
+        None => run_tournament_with_rng(config, &mut rand::thread_rng()),
+    }
+}
+
+/// Run a tournament drawing all randomness from `rng`
+fn run_tournament_with_rng(config: TournamentConfig, rng: &mut impl Rng) -> TournamentResult {
+    if let TournamentFormat::Bracket { rounds } = config.format {
+        return run_bracket_tournament_with_rng(config, rounds, rng);
+    }
+
     // Generate players
-    let players = generate_player_pool(&PlayerArchetype::Uniform, config.num_players);
+    let players = generate_player_pool_with_rng(&PlayerArchetype::Uniform, config.num_players, rng);
 
     // Collect scores
-    let mut scores: Vec<(String, f64)> = players
+    let (mut scores, match_play) = play_round(&players, &config, rng);
+
+    for (player_id, score) in &scores {
+        assert_finite("run_tournament", &format!("score for {}", player_id), *score);
+    }
+
+    sort_leaderboard(&mut scores, &config.game_mode);
+
+    let mut result = settle_tournament(&config, scores, Vec::new());
+    result.match_play = match_play;
+    result
+}
+
+/// Run one round of head-to-head match play
+///
+/// Players are paired in pool order (1 v 2, 3 v 4, ...); in an odd field the
+/// last player gets a bye. Each match runs over `attempts_per_player` holes,
+/// cycling through `config.holes` in order. Both players hit one shot per
+/// hole and the closer measured miss wins it; if the measurements are equal
+/// (see `measurement_resolution`), each hits a playoff shot with
+/// `simulate_shot`, and the closer exact miss takes the hole. The player
+/// winning more holes wins the match; equal holes halve it.
+///
+/// # Arguments
+/// * `config` - Tournament configuration; `config.seed` makes it reproducible
+///
+/// # Returns
+/// Every match with its per-hole outcomes, and each player's record
+pub fn run_match_play(config: TournamentConfig) -> MatchPlayResult {
+    match config.seed {
+        Some(seed) => run_match_play_with_rng(&config, &mut StdRng::seed_from_u64(seed)),
+        None => run_match_play_with_rng(&config, &mut rand::thread_rng()),
+    }
+}
+
+fn run_match_play_with_rng(config: &TournamentConfig, rng: &mut impl Rng) -> MatchPlayResult {
+    let players = generate_player_pool_with_rng(&PlayerArchetype::Uniform, config.num_players, rng);
+    play_matches(&players, config, rng)
+}
+
+/// Score one round for the game mode
+///
+/// Stroke modes score each player's best attempt; match play scores match
+/// points and also returns the matches.
+fn play_round(
+    players: &[Player],
+    config: &TournamentConfig,
+    rng: &mut impl Rng,
+) -> (Vec<(String, f64)>, Option<MatchPlayResult>) {
+    match config.game_mode {
+        GameMode::MatchPlay => {
+            let result = play_matches(players, config, rng);
+            let scores = result.records.iter().map(|record| (record.player_id.clone(), record.points())).collect();
+            (scores, Some(result))
+        }
+        _ => {
+            let scores = players
+                .iter()
+                .map(|player| {
+                    let best_score = simulate_player_tournament_attempts(player, config, rng);
+                    (player.id.clone(), best_score)
+                })
+                .collect();
+            (scores, None)
+        }
+    }
+}
+
+/// Pair `players` in order and play every match
+fn play_matches(players: &[Player], config: &TournamentConfig, rng: &mut impl Rng) -> MatchPlayResult {
+    let mut records: Vec<MatchRecord> = players
         .iter()
-        .map(|player| {
-            let best_score = simulate_player_tournament_attempts(player, &config);
-            (player.id.clone(), best_score)
-        })
+        .map(|player| MatchRecord { player_id: player.id.clone(), wins: 0, losses: 0, ties: 0 })
         .collect();
+    let holes: Vec<_> = config.holes.iter().collect();
+
+    let mut matches = Vec::with_capacity(players.len() / 2);
+    for (pair, pair_players) in players.chunks_exact(2).enumerate() {
+        let (first, second) = (&pair_players[0], &pair_players[1]);
+        let mut hole_outcomes = Vec::with_capacity(config.attempts_per_player);
+        let mut holes_won = (0, 0);
+
+        for hole in holes.iter().cycle().take(config.attempts_per_player) {
+            let sigmas = (first.get_true_sigma(hole), second.get_true_sigma(hole));
+            let misses = (
+                measure(match_shot(sigmas.0, config, rng), config.measurement_resolution),
+                measure(match_shot(sigmas.1, config, rng), config.measurement_resolution),
+            );
+
+            let playoff = misses.0 == misses.1;
+            let first_wins = if playoff {
+                match_shot(sigmas.0, config, rng) < match_shot(sigmas.1, config, rng)
+            } else {
+                misses.0 < misses.1
+            };
+            if first_wins {
+                holes_won.0 += 1;
+            } else {
+                holes_won.1 += 1;
+            }
+            hole_outcomes.push(HoleOutcome {
+                hole_id: hole.id,
+                misses,
+                winner: usize::from(!first_wins),
+                playoff,
+            });
+        }
 
-    // Sort leaderboard based on game mode
-    match config.game_mode {
-        GameMode::LongestDrive => {
-            // Higher is better
+        let (i, j) = (2 * pair, 2 * pair + 1);
+        let winner = match holes_won.0.cmp(&holes_won.1) {
+            std::cmp::Ordering::Greater => {
+                records[i].wins += 1;
+                records[j].losses += 1;
+                Some(first.id.clone())
+            }
+            std::cmp::Ordering::Less => {
+                records[i].losses += 1;
+                records[j].wins += 1;
+                Some(second.id.clone())
+            }
+            std::cmp::Ordering::Equal => {
+                records[i].ties += 1;
+                records[j].ties += 1;
+                None
+            }
+        };
+        matches.push(MatchResult {
+            players: (first.id.clone(), second.id.clone()),
+            holes: hole_outcomes,
+            holes_won,
+            winner,
+        });
+    }
+
+    let bye = if players.len() % 2 == 1 {
+        let last = records.last_mut().expect("Odd field is not empty");
+        last.wins += 1;
+        Some(last.player_id.clone())
+    } else {
+        None
+    };
+
+    MatchPlayResult { matches, records, bye }
+}
+
+/// Run an elimination bracket tournament
+///
+/// Each round, the surviving players re-take their attempts and the top half
+/// of the round's leaderboard (at least one player) advances, for
+/// `TournamentFormat::Bracket { rounds }` rounds or until one player is
+/// left. A single-round format plays one round.
+///
+/// Final standings go by finishing round: the last round's players lead in
+/// that round's order, followed by each earlier round's eliminated players
+/// in that round's order. Prizes are paid on these standings, and each
+/// player's leaderboard score is from the last round they played.
+///
+/// # Arguments
+/// * `config` - Tournament configuration
+///
+/// # Returns
+/// TournamentResult with the final standings and every round in `rounds`
+pub fn run_bracket_tournament(config: TournamentConfig) -> TournamentResult {
+    let rounds = match config.format {
+        TournamentFormat::SingleRound => 1,
+        TournamentFormat::Bracket { rounds } => rounds,
+    };
+    match config.seed {
+        Some(seed) => run_bracket_tournament_with_rng(config, rounds, &mut StdRng::seed_from_u64(seed)),
+        None => run_bracket_tournament_with_rng(config, rounds, &mut rand::thread_rng()),
+    }
+}
+
+/// Run a bracket tournament of up to `rounds` rounds drawing all randomness from `rng`
+fn run_bracket_tournament_with_rng(
+    config: TournamentConfig,
+    rounds: usize,
+    rng: &mut impl Rng,
+) -> TournamentResult {
+    let mut survivors = generate_player_pool_with_rng(&PlayerArchetype::Uniform, config.num_players, rng);
+    let mut round_results: Vec<RoundResult> = Vec::new();
+
+    for round in 1..=rounds.max(1) {
+        if round > 1 && survivors.len() <= 1 {
+            break;
+        }
+
+        let (mut scores, match_play) = play_round(&survivors, &config, rng);
+        for (player_id, score) in &scores {
+            assert_finite("run_bracket_tournament", &format!("round {} score for {}", round, player_id), *score);
+        }
+        sort_leaderboard(&mut scores, &config.game_mode);
+
+        let advancing: Vec<String> =
+            scores.iter().take((scores.len() / 2).max(1)).map(|(id, _)| id.clone()).collect();
+        survivors.retain(|player| advancing.contains(&player.id));
+        round_results.push(RoundResult { round, leaderboard: scores, survivors: advancing, match_play });
+    }
+
+    // Standings by finishing round: the last round in full, then the players
+    // knocked out in each earlier round
+    let mut standings: Vec<(String, f64)> = Vec::with_capacity(config.num_players);
+    if let Some(last) = round_results.last() {
+        standings.extend(last.leaderboard.iter().cloned());
+    }
+    for (i, round) in round_results.iter().enumerate().rev().skip(1) {
+        let next_round = &round_results[i + 1].leaderboard;
+        standings.extend(
+            round
+                .leaderboard
+                .iter()
+                .filter(|(id, _)| !next_round.iter().any(|(next_id, _)| next_id == id))
+                .cloned(),
+        );
+    }
+
+    settle_tournament(&config, standings, round_results)
+}
+
+/// Sort scores best first for the game mode (stable, so ties keep their order)
+fn sort_leaderboard(scores: &mut [(String, f64)], game_mode: &GameMode) {
+    match game_mode {
+        GameMode::LongestDrive | GameMode::MatchPlay => {
+            // Higher is better (distance, or match points)
             scores.sort_by(|a, b| b.1.partial_cmp(&a.1).unwrap());
         }
         GameMode::ClosestToPin { .. } => {
@@ -115,9 +481,14 @@ pub fn run_tournament(config: TournamentConfig) -> TournamentResult {
             scores.sort_by(|a, b| a.1.partial_cmp(&b.1).unwrap());
         }
     }
+}
 
-    let leaderboard = scores;
-
+/// Collect entry fees, take the rake and pay prizes down the final standings
+fn settle_tournament(
+    config: &TournamentConfig,
+    leaderboard: Vec<(String, f64)>,
+    rounds: Vec<RoundResult>,
+) -> TournamentResult {
     // Calculate prize pool
     let total_pool = config.entry_fee * config.num_players as f64;
     let house_rake = total_pool * config.house_rake_percent;
@@ -126,17 +497,30 @@ pub fn run_tournament(config: TournamentConfig) -> TournamentResult {
     // Distribute prizes
     let payouts = distribute_prizes(&leaderboard, &config.payout_structure, prize_pool);
 
+    assert_finite("run_tournament", "total_pool", total_pool);
+    assert_finite("run_tournament", "house_rake", house_rake);
+    assert_finite("run_tournament", "prize_pool", prize_pool);
+    for (player_id, amount) in &payouts {
+        assert_finite("run_tournament", &format!("payout to {}", player_id), *amount);
+    }
+
     TournamentResult {
         leaderboard,
         total_pool,
         house_rake,
         prize_pool,
         payouts,
+        rounds,
+        match_play: None,
     }
 }
 
 /// Simulate a player's tournament attempts
-fn simulate_player_tournament_attempts(player: &Player, config: &TournamentConfig) -> f64 {
+fn simulate_player_tournament_attempts(
+    player: &Player,
+    config: &TournamentConfig,
+    rng: &mut impl Rng,
+) -> f64 {
     match config.game_mode {
         GameMode::LongestDrive => {
             // For longest drive, we'll use a simple distance model
@@ -147,7 +531,8 @@ fn simulate_player_tournament_attempts(player: &Player, config: &TournamentConfi
                 let base_distance = 250.0 - (player.handicap as f64 * 3.0);
                 // Add some randomness
                 let variance = 20.0;
-                let (random_offset, _) = simulate_shot(variance, 0.02, 3.0);
+                let (random_offset, _) =
+                    simulate_shot_with_rng(variance, config.fat_tail_prob, config.fat_tail_mult, rng);
                 let distance = base_distance + random_offset - variance;
                 best_distance = best_distance.max(distance);
             }
@@ -155,17 +540,63 @@ fn simulate_player_tournament_attempts(player: &Player, config: &TournamentConfi
         }
         GameMode::ClosestToPin { hole_id } => {
             // For closest to pin, use actual shot simulation
-            let hole = get_hole_by_id(hole_id).expect("Invalid hole_id");
-            let skill_profile = player.get_skill_for_hole(hole);
-            let sigma = skill_profile.kalman_filter.estimate;
+            let hole = config.holes.get_hole_by_id(hole_id).expect("Invalid hole_id");
+            let sigma = player.get_true_sigma(hole);
 
             let mut best_miss = f64::MAX;
             for _ in 0..config.attempts_per_player {
-                let (miss_distance, _) = simulate_shot(sigma, 0.02, 3.0);
+                let (miss_distance, _) =
+                    simulate_shot_with_rng(sigma, config.fat_tail_prob, config.fat_tail_mult, rng);
                 best_miss = best_miss.min(miss_distance);
             }
-            best_miss
+            let measured = measure(best_miss, config.measurement_resolution);
+            if config.net_scoring {
+                measured - handicap_allowance(player.handicap)
+            } else {
+                measured
+            }
         }
+        GameMode::MatchPlay => unreachable!("Match play is scored per match, not per player"),
+    }
+}
+
+/// Net-scoring allowance per handicap stroke, in feet
+///
+/// Calibrated on the default closest-to-pin setup (150-yard hole, 5
+/// attempts) so that handicaps 0-30 win about equally often. This is less
+/// than the growth in the expected best miss (~0.45 ft per stroke), because
+/// wider dispersion also means more very close shots.
+pub const ALLOWANCE_FT_PER_STROKE: f64 = 0.3;
+
+/// Miss distance allowance for a handicap under net scoring
+///
+/// Subtracted from a closest-to-pin score, so a net score can be negative.
+/// A scratch player gets no allowance.
+///
+/// # Example
+/// ```
+/// use continuum_golf_simulator::simulators::tournament::handicap_allowance;
+///
+/// assert_eq!(handicap_allowance(0), 0.0);
+/// assert!(handicap_allowance(30) > handicap_allowance(10));
+/// ```
+pub fn handicap_allowance(handicap: u8) -> f64 {
+    handicap as f64 * ALLOWANCE_FT_PER_STROKE
+}
+
+/// Exact miss distance of one match play shot
+fn match_shot(sigma: f64, config: &TournamentConfig, rng: &mut impl Rng) -> f64 {
+    simulate_shot_with_rng(sigma, config.fat_tail_prob, config.fat_tail_mult, rng).0
+}
+
+/// Round a measured distance to the nearest multiple of `resolution`
+///
+/// A non-positive resolution leaves the distance exact.
+fn measure(distance: f64, resolution: f64) -> f64 {
+    if resolution > 0.0 {
+        (distance / resolution).round() * resolution
+    } else {
+        distance
     }
 }
 
@@ -184,7 +615,7 @@ fn distribute_prizes(
             }
         }
         PayoutStructure::Top2 { first, second } => {
-            if leaderboard.len() >= 1 {
+            if !leaderboard.is_empty() {
                 payouts.push((leaderboard[0].0.clone(), prize_pool * first));
             }
             if leaderboard.len() >= 2 {
@@ -196,7 +627,7 @@ fn distribute_prizes(
             second,
             third,
         } => {
-            if leaderboard.len() >= 1 {
+            if !leaderboard.is_empty() {
                 payouts.push((leaderboard[0].0.clone(), prize_pool * first));
             }
             if leaderboard.len() >= 2 {
@@ -237,6 +668,7 @@ mod tests {
                 third: 0.15,
             },
             attempts_per_player: 3,
+            ..Default::default()
         };
 
         let result = run_tournament(config);
@@ -267,6 +699,7 @@ mod tests {
             house_rake_percent: 0.05,
             payout_structure: PayoutStructure::WinnerTakesAll,
             attempts_per_player: 3,
+            ..Default::default()
         };
 
         let result = run_tournament(config);
@@ -287,6 +720,47 @@ mod tests {
         assert_eq!(result.payouts[0].1, 47.5);
     }
 
+    #[test]
+    fn test_measurement_resolution_ties() {
+        let count_ties = |result: &TournamentResult| {
+            result.leaderboard.windows(2).filter(|pair| pair[0].1 == pair[1].1).count()
+        };
+        let config = TournamentConfig {
+            game_mode: GameMode::ClosestToPin { hole_id: 8 },
+            num_players: 200,
+            attempts_per_player: 1,
+            seed: Some(231),
+            ..Default::default()
+        };
+
+        let fine = run_tournament(TournamentConfig { measurement_resolution: 0.01, ..config.clone() });
+        assert!(count_ties(&fine) <= 5, "Fine resolution produced {} ties", count_ties(&fine));
+
+        let coarse = run_tournament(TournamentConfig { measurement_resolution: 5.0, ..config });
+        assert!(count_ties(&coarse) > 20, "Coarse resolution produced {} ties", count_ties(&coarse));
+        for (_, score) in &coarse.leaderboard {
+            assert_eq!(score % 5.0, 0.0);
+        }
+
+        // Ties keep pool order, and prizes still go to exactly three players
+        let pool_index = |id: &str| id.trim_start_matches("player_").parse::<usize>().unwrap();
+        for pair in coarse.leaderboard.windows(2) {
+            assert!(pair[0].1 <= pair[1].1);
+            if pair[0].1 == pair[1].1 {
+                assert!(pool_index(&pair[0].0) < pool_index(&pair[1].0));
+            }
+        }
+        assert_eq!(coarse.payouts.len(), 3);
+        let total_paid: f64 = coarse.payouts.iter().map(|(_, amount)| amount).sum();
+        assert!((total_paid - coarse.prize_pool).abs() < 1e-9);
+    }
+
+    #[test]
+    #[should_panic(expected = "run_tournament: total_pool is NaN")]
+    fn test_non_finite_results_are_caught_at_source() {
+        run_tournament(TournamentConfig { entry_fee: f64::NAN, seed: Some(235), ..Default::default() });
+    }
+
     #[test]
     fn test_distribute_prizes_winner_takes_all() {
         let leaderboard = vec![
@@ -382,6 +856,7 @@ mod tests {
                 third: 0.20,
             },
             attempts_per_player: 1,
+            ..Default::default()
         };
 
         let result = run_tournament(config);
@@ -389,4 +864,131 @@ mod tests {
         // Should only pay out to 2 players (not 3)
         assert_eq!(result.payouts.len(), 2);
     }
-}
\ No newline at end of file
+
+    #[test]
+    fn test_bracket_tournament_crowns_one_champion() {
+        let config = TournamentConfig {
+            num_players: 8,
+            format: TournamentFormat::Bracket { rounds: 3 },
+            seed: Some(271),
+            ..Default::default()
+        };
+
+        let result = run_bracket_tournament(config.clone());
+
+        let survivors: Vec<usize> = result.rounds.iter().map(|round| round.survivors.len()).collect();
+        assert_eq!(survivors, vec![4, 2, 1]);
+        let field: Vec<usize> = result.rounds.iter().map(|round| round.leaderboard.len()).collect();
+        assert_eq!(field, vec![8, 4, 2]);
+
+        // Standings run champion, runner-up, then the round 2 and round 1 exits
+        let champion = &result.rounds[2].survivors[0];
+        assert_eq!(&result.leaderboard[0].0, champion);
+        assert_eq!(result.leaderboard.len(), 8);
+        for (rank, (player_id, _)) in result.leaderboard.iter().enumerate().skip(2) {
+            let exit_round = if rank < 4 { 1 } else { 0 };
+            assert!(!result.rounds[exit_round].survivors.contains(player_id));
+        }
+        assert_eq!(&result.payouts[0].0, champion);
+        let total_paid: f64 = result.payouts.iter().map(|(_, amount)| amount).sum();
+        assert!((total_paid - result.prize_pool).abs() < 1e-9);
+
+        // run_tournament dispatches on the format
+        let dispatched = run_tournament(config);
+        assert_eq!(dispatched.leaderboard, result.leaderboard);
+    }
+
+    #[test]
+    fn test_match_play_uses_configured_fat_tails() {
+        let misses = |fat_tail_prob: f64| {
+            let config = TournamentConfig {
+                game_mode: GameMode::MatchPlay,
+                num_players: 4,
+                seed: Some(272),
+                fat_tail_prob,
+                fat_tail_mult: 4.0,
+                ..Default::default()
+            };
+            let result = run_match_play(config);
+            result.matches.iter().flat_map(|m| m.holes.iter().map(|hole| hole.misses)).collect::<Vec<_>>()
+        };
+
+        // Same draws; every shot is fat-tailed, so each miss is 4x wider
+        let (wide, normal) = (misses(1.0), misses(0.0));
+        assert_eq!(wide.len(), 10);
+        for (wide, normal) in wide.iter().zip(normal) {
+            assert!((wide.0 - 4.0 * normal.0).abs() < 1e-9 * wide.0);
+            assert!((wide.1 - 4.0 * normal.1).abs() < 1e-9 * wide.1);
+        }
+    }
+
+    #[test]
+    fn test_match_play_records_every_match() {
+        let config = TournamentConfig {
+            num_players: 7,
+            attempts_per_player: 5,
+            game_mode: GameMode::MatchPlay,
+            seed: Some(272),
+            ..Default::default()
+        };
+
+        let result = run_match_play(config.clone());
+
+        assert_eq!(result.matches.len(), 3);
+        assert_eq!(result.bye.as_deref(), Some(result.records[6].player_id.as_str()));
+        for game in &result.matches {
+            assert_eq!(game.holes.len(), 5);
+            assert_eq!(game.holes_won.0 + game.holes_won.1, 5);
+            // Five holes cannot be halved
+            let winner = game.winner.as_ref().unwrap();
+            let expected = if game.holes_won.0 > game.holes_won.1 { &game.players.0 } else { &game.players.1 };
+            assert_eq!(winner, expected);
+            for hole in game.holes.iter().filter(|hole| !hole.playoff) {
+                let closer = if hole.misses.0 < hole.misses.1 { 0 } else { 1 };
+                assert_eq!(hole.winner, closer);
+            }
+        }
+        for record in &result.records {
+            assert_eq!(record.wins + record.losses + record.ties, 1, "{:?}", record);
+        }
+
+        // The tournament ranks by match points: four winners (bye included) lead
+        let tournament = run_tournament(config);
+        let match_play = tournament.match_play.as_ref().unwrap();
+        assert_eq!(match_play.matches.len(), 3);
+        let points: Vec<f64> = tournament.leaderboard.iter().map(|(_, points)| *points).collect();
+        assert_eq!(points, vec![1.0, 1.0, 1.0, 1.0, 0.0, 0.0, 0.0]);
+    }
+
+    #[test]
+    fn test_net_scoring_levels_win_rates_across_handicaps() {
+        let handicaps = [0u8, 10, 20, 30];
+        let field: Vec<Player> = handicaps.iter().map(|&h| Player::new(format!("hcp_{}", h), h)).collect();
+
+        // Win rate per handicap over many four-player closest-to-pin rounds
+        let win_rate_spread = |net_scoring: bool| {
+            let config = TournamentConfig { net_scoring, ..Default::default() };
+            let mut rng = StdRng::seed_from_u64(273);
+            let trials = 4000;
+            let mut wins = [0usize; 4];
+            for _ in 0..trials {
+                let mut scores: Vec<(String, f64)> = field
+                    .iter()
+                    .map(|player| (player.id.clone(), simulate_player_tournament_attempts(player, &config, &mut rng)))
+                    .collect();
+                sort_leaderboard(&mut scores, &config.game_mode);
+                wins[field.iter().position(|player| player.id == scores[0].0).unwrap()] += 1;
+            }
+            let rates: Vec<f64> = wins.iter().map(|&w| w as f64 / trials as f64).collect();
+            let max = rates.iter().cloned().fold(f64::MIN, f64::max);
+            let min = rates.iter().cloned().fold(f64::MAX, f64::min);
+            max - min
+        };
+
+        let gross = win_rate_spread(false);
+        let net = win_rate_spread(true);
+        println!("Win-rate spread: gross {:.3}, net {:.3}", gross, net);
+        assert!(gross > 0.3, "Scratch should dominate gross scoring: {}", gross);
+        assert!(net < 0.1, "Net scoring should level the field: {}", net);
+    }
+}
@@ -7,15 +7,24 @@
 //! - Heatmap data for handicap × distance analysis
 //! - Payout distribution histograms
 
+use crate::math::distributions::{normal_random_with_rng, NormalSampler};
+use crate::math::integration::simpsons_rule;
 use crate::models::{
-    hole::HOLE_CONFIGURATIONS,
+    hole::HoleSet,
     player::Player,
+    shot::ShotOutcome,
 };
-use crate::simulators::player_session::{run_session, HoleSelection, SessionConfig};
-use rand::Rng;
-use rand_distr::{Distribution, Normal, Uniform};
+use crate::simulators::{assert_finite, CancelToken};
+use crate::simulators::player_session::{
+    cohort_session_seed, play_session, HoleSelection, SessionConfig, SessionResult,
+};
+use rand::rngs::StdRng;
+use rand::seq::SliceRandom;
+use rand::{Rng, SeedableRng};
+use rand_distr::{Distribution, Uniform};
 use rayon::prelude::*;
 use serde::{Deserialize, Serialize};
+use std::collections::HashMap;
 
 /// Configuration for venue simulation
 #[derive(Debug, Clone, Serialize, Deserialize)]
@@ -30,6 +39,37 @@ pub struct VenueConfig {
     pub player_archetype: PlayerArchetype,
     /// Wager range for players (min, max)
     pub wager_range: (f64, f64),
+    /// Optional RNG seed; when set, the player pool and every bay's session
+    /// are reproducible regardless of thread scheduling
+    #[serde(default)]
+    pub seed: Option<u64>,
+    /// Reduce each bay's session to running totals as soon as it finishes and
+    /// drop its shot log. Results are unchanged; peak memory is bounded by the
+    /// sessions in flight instead of growing with the total shot count.
+    #[serde(default)]
+    pub streaming: bool,
+    /// Sampler for normally distributed handicaps; use `InverseCdf` when a
+    /// seeded run must reproduce exactly on other platforms
+    #[serde(default)]
+    pub normal_sampler: NormalSampler,
+    /// Holes the venue offers (default: the 8 standard holes)
+    #[serde(default)]
+    pub holes: HoleSet,
+    /// Players who take turns in each bay over the day (default: 1; 0 is treated as 1)
+    ///
+    /// Each bay's shots are split evenly between its players, who play one
+    /// session each, in turn.
+    #[serde(default)]
+    pub players_per_bay: usize,
+    /// How traffic and wagers vary over the operating hours (default: flat)
+    #[serde(default)]
+    pub traffic_profile: TrafficProfile,
+    /// Keep every shot in `VenueResult::shots` (default: false)
+    ///
+    /// Needed for per-shot exports such as `export_venue_parquet`. Memory
+    /// grows with the total shot count, even when `streaming` is set.
+    #[serde(default)]
+    pub retain_shots: bool,
 }
 
 impl Default for VenueConfig {
@@ -40,10 +80,87 @@ impl Default for VenueConfig {
             shots_per_hour: 100,
             player_archetype: PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 },
             wager_range: (5.0, 20.0),
+            seed: None,
+            streaming: false,
+            normal_sampler: NormalSampler::default(),
+            holes: HoleSet::standard(),
+            players_per_bay: 1,
+            traffic_profile: TrafficProfile::Flat,
+            retain_shots: false,
+        }
+    }
+}
+
+/// Time-of-day shape of venue traffic and wagering
+///
+/// Each bay still plays `hours × shots_per_hour` shots in total; the profile
+/// only decides which hours they fall in, and scales the wager range hour by
+/// hour.
+#[derive(Debug, Clone, Copy, Default, PartialEq, Serialize, Deserialize)]
+pub enum TrafficProfile {
+    /// Same traffic and wagers every hour
+    #[default]
+    Flat,
+    /// Quiet opening that builds to a busy, higher-stakes close
+    EveningPeak,
+    /// Traffic and wagers peak mid-session and taper at both ends
+    WeekendRush,
+}
+
+impl TrafficProfile {
+    /// Relative traffic at `t`, the fraction of the operating window elapsed
+    /// (0.0 = opening, 1.0 = closing)
+    pub fn traffic_weight(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            TrafficProfile::Flat => 1.0,
+            TrafficProfile::EveningPeak => 0.4 + 1.6 * t * t,
+            TrafficProfile::WeekendRush => 0.5 + (std::f64::consts::PI * t).sin(),
+        }
+    }
+
+    /// Multiplier applied to both ends of the wager range at `t`
+    pub fn wager_multiplier(&self, t: f64) -> f64 {
+        let t = t.clamp(0.0, 1.0);
+        match self {
+            TrafficProfile::Flat => 1.0,
+            TrafficProfile::EveningPeak => 0.8 + 0.4 * t,
+            TrafficProfile::WeekendRush => 0.9 + 0.2 * (std::f64::consts::PI * t).sin(),
+        }
+    }
+
+    /// Split `total_shots` across the hours of an operating window
+    ///
+    /// Returns one count per started hour (a fractional final hour gets a
+    /// proportionally smaller share); the counts always sum to `total_shots`.
+    pub fn shots_by_hour(&self, hours: f64, total_shots: usize) -> Vec<usize> {
+        let weights: Vec<f64> = hour_spans(hours)
+            .map(|(start, end)| self.traffic_weight((start + end) / 2.0 / hours) * (end - start))
+            .collect();
+        let total_weight: f64 = weights.iter().sum();
+        if total_weight <= 0.0 {
+            return vec![0; weights.len()];
+        }
+
+        // Largest-remainder rounding keeps the total exact
+        let exact: Vec<f64> = weights.iter().map(|w| w / total_weight * total_shots as f64).collect();
+        let mut counts: Vec<usize> = exact.iter().map(|x| x.floor() as usize).collect();
+        let mut by_remainder: Vec<usize> = (0..exact.len()).collect();
+        by_remainder.sort_by(|&a, &b| (exact[b] - exact[b].floor()).total_cmp(&(exact[a] - exact[a].floor())));
+        let shortfall = total_shots - counts.iter().sum::<usize>();
+        for &hour in by_remainder.iter().take(shortfall) {
+            counts[hour] += 1;
         }
+        counts
     }
 }
 
+/// (start, end) of each started hour in an operating window of `hours`
+fn hour_spans(hours: f64) -> impl Iterator<Item = (f64, f64)> {
+    let hours = hours.max(0.0);
+    (0..hours.ceil() as usize).map(move |hour| (hour as f64, (hour as f64 + 1.0).min(hours)))
+}
+
 /// Player population distribution strategy
 #[derive(Debug, Clone, Serialize, Deserialize)]
 pub enum PlayerArchetype {
@@ -76,6 +193,98 @@ pub struct VenueResult {
     pub payout_distribution: [usize; 11],
     /// Total number of shots simulated
     pub total_shots: usize,
+    /// Totals per handicap band, in heatmap bin order
+    #[serde(default)]
+    pub band_summary: Vec<HandicapBand>,
+    /// Totals per bay, in bay order
+    #[serde(default)]
+    pub bay_summaries: Vec<BaySummary>,
+    /// Totals per hole, keyed by hole id (holes nobody played are omitted)
+    #[serde(default)]
+    pub per_hole: HashMap<u8, HoleStats>,
+    /// Every shot, by bay and then by turn, if `retain_shots` was set (else empty)
+    #[serde(default, skip_serializing_if = "Vec::is_empty")]
+    pub shots: Vec<ShotOutcome>,
+    /// The run was cancelled before every bay finished; totals cover only
+    /// the shots played
+    #[serde(default)]
+    pub was_cancelled: bool,
+}
+
+impl VenueResult {
+    /// Up to `n` holes with the highest house profit, most profitable first
+    ///
+    /// Ties are broken by hole id so the order is stable.
+    pub fn most_profitable_holes(&self, n: usize) -> Vec<(u8, &HoleStats)> {
+        let mut holes: Vec<(u8, &HoleStats)> = self.per_hole.iter().map(|(id, stats)| (*id, stats)).collect();
+        holes.sort_by(|a, b| b.1.net_profit().total_cmp(&a.1.net_profit()).then(a.0.cmp(&b.0)));
+        holes.truncate(n);
+        holes
+    }
+}
+
+/// Venue totals for one hole
+#[derive(Debug, Clone, PartialEq, Serialize, Deserialize)]
+pub struct HoleStats {
+    /// Amount wagered on the hole
+    pub wagered: f64,
+    /// Payouts on the hole
+    pub payouts: f64,
+    /// Hold percentage (profit / wagered)
+    pub hold_percentage: f64,
+    /// Shots played on the hole
+    pub shot_count: usize,
+}
+
+impl HoleStats {
+    /// House profit on the hole (wagered - payouts)
+    pub fn net_profit(&self) -> f64 {
+        self.wagered - self.payouts
+    }
+}
+
+/// Venue totals for one hitting bay
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct BaySummary {
+    /// Bay index (0-based)
+    pub bay: usize,
+    /// Handicap of the bay's first player
+    pub handicap: u8,
+    /// Players who used the bay
+    #[serde(default)]
+    pub players: usize,
+    /// Shots played in the bay
+    pub shots: usize,
+    /// Amount wagered in the bay
+    pub total_wagered: f64,
+    /// Payouts from the bay
+    pub total_won: f64,
+}
+
+impl BaySummary {
+    /// Realized return to player (payouts / wagered, 0.0 if nothing was wagered)
+    pub fn realized_rtp(&self) -> f64 {
+        if self.total_wagered > 0.0 {
+            self.total_won / self.total_wagered
+        } else {
+            0.0
+        }
+    }
+}
+
+/// Venue totals for one handicap band
+#[derive(Debug, Clone, Serialize, Deserialize)]
+pub struct HandicapBand {
+    /// Band label, e.g. "0-4"
+    pub label: String,
+    /// Players in the band
+    pub players: usize,
+    /// Shots played by the band
+    pub shots: usize,
+    /// Amount wagered by the band
+    pub total_wagered: f64,
+    /// House profit from the band (wagered - payouts)
+    pub net_profit: f64,
 }
 
 /// Heatmap data showing hold percentage by handicap and distance
@@ -98,7 +307,30 @@ pub struct HeatmapData {
 /// # Returns
 /// Vector of players with handicaps drawn from the specified distribution
 pub fn generate_player_pool(archetype: &PlayerArchetype, size: usize) -> Vec<Player> {
-    let mut rng = rand::thread_rng();
+    generate_player_pool_with_rng(archetype, size, &mut rand::thread_rng())
+}
+
+/// Generate a pool of players drawing handicaps from `rng`
+///
+/// Same as [`generate_player_pool`], but reproducible with a seeded `rng`.
+pub fn generate_player_pool_with_rng(
+    archetype: &PlayerArchetype,
+    size: usize,
+    rng: &mut impl Rng,
+) -> Vec<Player> {
+    generate_player_pool_with_sampler(archetype, size, NormalSampler::default(), rng)
+}
+
+/// Generate a pool of players with an explicit normal sampler
+///
+/// Same as [`generate_player_pool_with_rng`]; `sampler` only affects the
+/// `BellCurve` archetype.
+pub fn generate_player_pool_with_sampler(
+    archetype: &PlayerArchetype,
+    size: usize,
+    sampler: NormalSampler,
+    rng: &mut impl Rng,
+) -> Vec<Player> {
     let mut players = Vec::with_capacity(size);
 
     for i in 0..size {
@@ -107,21 +339,20 @@ pub fn generate_player_pool(archetype: &PlayerArchetype, size: usize) -> Vec<Pla
                 rng.gen_range(0..=30)
             }
             PlayerArchetype::BellCurve { mean, std_dev } => {
-                let normal = Normal::new(*mean as f64, *std_dev).unwrap();
-                let sample = normal.sample(&mut rng);
+                let sample = normal_random_with_rng(*mean as f64, *std_dev, sampler, rng);
                 sample.round().clamp(0.0, 30.0) as u8
             }
             PlayerArchetype::SkewedHigh => {
                 // Beta-like distribution skewed toward high handicaps (20-30)
                 let uniform = Uniform::new(0.0, 1.0);
-                let u: f64 = uniform.sample(&mut rng);
+                let u: f64 = uniform.sample(rng);
                 let skewed = 1.0 - (1.0 - u) * (1.0 - u); // Skew toward 1
                 (skewed * 30.0).round() as u8
             }
             PlayerArchetype::SkewedLow => {
                 // Beta-like distribution skewed toward low handicaps (0-10)
                 let uniform = Uniform::new(0.0, 1.0);
-                let u: f64 = uniform.sample(&mut rng);
+                let u: f64 = uniform.sample(rng);
                 let skewed = u * u; // Skew toward 0
                 (skewed * 30.0).round() as u8
             }
@@ -133,6 +364,63 @@ pub fn generate_player_pool(archetype: &PlayerArchetype, size: usize) -> Vec<Pla
     players
 }
 
+/// Exact handicap distribution produced by `generate_player_pool` for an archetype
+///
+/// # Returns
+/// (handicap, probability) for every handicap 0-30; probabilities sum to 1
+pub fn handicap_distribution(archetype: &PlayerArchetype) -> Vec<(u8, f64)> {
+    // Each handicap h collects the draws that round to it: [h - 0.5, h + 0.5)
+    // on the 0-30 scale, with the ends absorbing anything clamped to 0 or 30.
+    // `cdf` is the CDF of the underlying continuous draw on that scale.
+    let from_cdf = |cdf: &dyn Fn(f64) -> f64| -> Vec<(u8, f64)> {
+        (0..=30u8)
+            .map(|h| {
+                let lower = if h == 0 { 0.0 } else { cdf(h as f64 - 0.5) };
+                let upper = if h == 30 { 1.0 } else { cdf(h as f64 + 0.5) };
+                (h, upper - lower)
+            })
+            .collect()
+    };
+
+    match archetype {
+        PlayerArchetype::Uniform => (0..=30u8).map(|h| (h, 1.0 / 31.0)).collect(),
+        PlayerArchetype::BellCurve { mean, std_dev } => {
+            let mean = *mean as f64;
+            if *std_dev <= 0.0 {
+                let mode = mean.round().clamp(0.0, 30.0) as u8;
+                return (0..=30u8).map(|h| (h, if h == mode { 1.0 } else { 0.0 })).collect();
+            }
+            let pdf = |x: f64| {
+                let z = (x - mean) / std_dev;
+                (-0.5 * z * z).exp() / (std_dev * (2.0 * std::f64::consts::PI).sqrt())
+            };
+            let tail_start = mean - 12.0 * std_dev;
+            from_cdf(&|x: f64| if x <= tail_start { 0.0 } else { simpsons_rule(pdf, tail_start, x, 2000) })
+        }
+        // Draw is 30 × (1 - (1 - u)²), whose CDF is 1 - sqrt(1 - x/30)
+        PlayerArchetype::SkewedHigh => from_cdf(&|x: f64| 1.0 - (1.0 - (x / 30.0).clamp(0.0, 1.0)).sqrt()),
+        // Draw is 30 × u², whose CDF is sqrt(x/30)
+        PlayerArchetype::SkewedLow => from_cdf(&|x: f64| (x / 30.0).clamp(0.0, 1.0).sqrt()),
+    }
+}
+
+/// Shuffle a player pool into a reproducible order
+///
+/// The same seed always produces the same permutation, which makes processing
+/// order (bay assignment, leaderboard tie resolution) repeatable when debugging.
+///
+/// Scoring must not depend on pool order: aggregates are sums over players and
+/// leaderboards use a stable sort, so the shuffle only changes which player wins
+/// an exact tie and which RNG stream each player is paired with.
+///
+/// # Arguments
+/// * `players` - Pool to reorder in place
+/// * `seed` - Seed for the permutation
+pub fn shuffle_player_pool(players: &mut [Player], seed: u64) {
+    let mut rng = StdRng::seed_from_u64(seed);
+    players.shuffle(&mut rng);
+}
+
 /// Run full venue simulation
 ///
 /// # Arguments
@@ -141,41 +429,183 @@ pub fn generate_player_pool(archetype: &PlayerArchetype, size: usize) -> Vec<Pla
 /// # Returns
 /// VenueResult with comprehensive analytics
 pub fn run_venue_simulation(config: VenueConfig) -> VenueResult {
+    run_venue_simulation_cancellable(config, &CancelToken::new())
+}
+
+/// Same as [`run_venue_simulation`], stopping early once `cancel` is set
+///
+/// Each bay checks the token every `CANCEL_CHECK_INTERVAL` shots and ends
+/// its current session there; later players in the bay don't start. The
+/// result aggregates whatever shots were played and sets `was_cancelled`.
+pub fn run_venue_simulation_cancellable(config: VenueConfig, cancel: &CancelToken) -> VenueResult {
     let total_shots = (config.num_bays as f64 * config.hours * config.shots_per_hour as f64) as usize;
-    let shots_per_bay = (total_shots / config.num_bays) as usize;
-
-    // Generate player pool (one per bay for simplicity)
-    let players = generate_player_pool(&config.player_archetype, config.num_bays);
-
-    // Run sessions in parallel for each bay
-    let bay_results: Vec<_> = players
-        .into_par_iter()
-        .map(|mut player| {
-            let session_config = SessionConfig {
-                num_shots: shots_per_bay,
-                wager_min: config.wager_range.0,
-                wager_max: config.wager_range.1,
-                hole_selection: HoleSelection::Random,
-                developer_mode: None,
-                ..Default::default()
-            };
+    let shots_per_bay = total_shots.checked_div(config.num_bays).unwrap_or(0);
+
+    let players_per_bay = config.players_per_bay.max(1);
+
+    // Generate player pool (players_per_bay per bay). With a seed, the pool
+    // RNG also supplies the base seed for the per-player sessions.
+    let pool_size = config.num_bays * players_per_bay;
+    let (players, session_seed) = match config.seed {
+        Some(seed) => {
+            let mut rng = StdRng::seed_from_u64(seed);
+            let players = generate_player_pool_with_sampler(
+                &config.player_archetype,
+                pool_size,
+                config.normal_sampler,
+                &mut rng,
+            );
+            (players, Some(rng.gen::<u64>()))
+        }
+        None => (
+            generate_player_pool_with_sampler(
+                &config.player_archetype,
+                pool_size,
+                config.normal_sampler,
+                &mut rand::thread_rng(),
+            ),
+            None,
+        ),
+    };
+    let mut pool = players.into_iter();
+    let bays: Vec<Vec<Player>> = (0..config.num_bays)
+        .map(|_| pool.by_ref().take(players_per_bay).collect())
+        .collect();
+
+    // Every bay follows the same hourly traffic curve: `shot_hours[i]` is the
+    // hour in which the bay's i-th shot is played
+    let hourly_shots = config.traffic_profile.shots_by_hour(config.hours, shots_per_bay);
+    let shot_hours: Vec<usize> = hourly_shots
+        .iter()
+        .enumerate()
+        .flat_map(|(hour, &count)| std::iter::repeat_n(hour, count))
+        .collect();
+    let wager_multipliers: Vec<f64> = hour_spans(config.hours)
+        .map(|(start, end)| config.traffic_profile.wager_multiplier((start + end) / 2.0 / config.hours))
+        .collect();
 
-            let result = run_session(&mut player, session_config);
-            (player, result)
+    // Each player in a bay plays their share of its shots, in turn; the first
+    // `shots_per_bay % players_per_bay` players take one extra
+    let turn_shots = |turn: usize| {
+        let base = shots_per_bay / players_per_bay;
+        let extra = shots_per_bay % players_per_bay;
+        let first = turn * base + turn.min(extra);
+        first..first + base + usize::from(turn < extra)
+    };
+    let play_turn = |bay: usize, turn: usize, mut player: Player| {
+        let session_config = SessionConfig {
+            num_shots: turn_shots(turn).len(),
+            wager_min: config.wager_range.0,
+            wager_max: config.wager_range.1,
+            hole_selection: HoleSelection::Random,
+            developer_mode: None,
+            seed: session_seed.map(|seed| cohort_session_seed(seed, bay * players_per_bay + turn)),
+            holes: config.holes.clone(),
+            ..Default::default()
+        };
+        let hours = &shot_hours[turn_shots(turn)];
+
+        let result = match session_config.seed {
+            Some(seed) => play_bay_session(
+                &mut player,
+                session_config,
+                hours,
+                &wager_multipliers,
+                cancel,
+                &mut StdRng::seed_from_u64(seed),
+            ),
+            None => play_bay_session(
+                &mut player,
+                session_config,
+                hours,
+                &wager_multipliers,
+                cancel,
+                &mut rand::thread_rng(),
+            ),
+        };
+        (player, result)
+    };
+    let tally = |turn: usize, player: &Player, result: &SessionResult| {
+        let mut tally =
+            PlayerTally::from_session(&config.holes, player, result, &shot_hours[turn_shots(turn)], hourly_shots.len());
+        if config.retain_shots {
+            tally.shots = result.shots.clone();
+        }
+        tally
+    };
+
+    // Run bays in parallel, and each bay's players in turn
+    let bay_tallies: Vec<Vec<PlayerTally>> = if config.streaming {
+        bays.into_par_iter()
+            .enumerate()
+            .map(|(bay, players)| {
+                players
+                    .into_iter()
+                    .enumerate()
+                    .map(|(turn, player)| {
+                        let (player, result) = play_turn(bay, turn, player);
+                        tally(turn, &player, &result)
+                    })
+                    .collect()
+            })
+            .collect()
+    } else {
+        let bay_results: Vec<Vec<_>> = bays
+            .into_par_iter()
+            .enumerate()
+            .map(|(bay, players)| {
+                players
+                    .into_iter()
+                    .enumerate()
+                    .map(|(turn, player)| play_turn(bay, turn, player))
+                    .collect()
+            })
+            .collect();
+        bay_results
+            .iter()
+            .map(|results| {
+                results
+                    .iter()
+                    .enumerate()
+                    .map(|(turn, (player, result))| tally(turn, player, result))
+                    .collect()
+            })
+            .collect()
+    };
+
+    let bay_summaries: Vec<BaySummary> = bay_tallies
+        .iter()
+        .enumerate()
+        .map(|(bay, tallies)| BaySummary {
+            bay,
+            handicap: tallies.first().map_or(0, |tally| tally.handicap),
+            players: tallies.len(),
+            shots: tallies.iter().map(|tally| tally.num_shots).sum(),
+            total_wagered: tallies.iter().map(|tally| tally.total_wagered).sum(),
+            total_won: tallies.iter().map(|tally| tally.total_won).sum(),
         })
         .collect();
+    let mut player_tallies: Vec<PlayerTally> = bay_tallies.into_iter().flatten().collect();
+    let shots: Vec<ShotOutcome> =
+        player_tallies.iter_mut().flat_map(|tally| std::mem::take(&mut tally.shots)).collect();
 
     // Aggregate results
     let mut total_wagered = 0.0;
     let mut total_payouts = 0.0;
-    let mut all_shots = Vec::new();
+    let mut payout_distribution = [0; 11];
+    let mut total_shots = 0;
 
-    for (_player, session_result) in &bay_results {
-        total_wagered += session_result.total_wagered;
-        total_payouts += session_result.total_won;
-        all_shots.extend(session_result.shots.clone());
+    for tally in &player_tallies {
+        total_wagered += tally.total_wagered;
+        total_payouts += tally.total_won;
+        for (bin, count) in tally.payout_distribution.iter().enumerate() {
+            payout_distribution[bin] += count;
+        }
+        total_shots += tally.num_shots;
     }
 
+    let was_cancelled = cancel.is_cancelled() && total_shots < shots_per_bay * config.num_bays;
+
     let net_profit = total_wagered - total_payouts;
     let hold_percentage = if total_wagered > 0.0 {
         net_profit / total_wagered
@@ -183,19 +613,26 @@ pub fn run_venue_simulation(config: VenueConfig) -> VenueResult {
         0.0
     };
 
-    // Calculate profit over time (simplified: evenly distributed)
-    let mut profit_over_time = Vec::new();
-    let profit_per_hour = net_profit / config.hours;
-    for hour in 0..=(config.hours as usize) {
-        let cumulative = profit_per_hour * hour as f64;
-        profit_over_time.push((hour as f64, cumulative));
+    // Cumulative profit at the end of each hour, as it was actually earned
+    let mut profit_over_time = vec![(0.0, 0.0)];
+    let mut cumulative = 0.0;
+    for (hour, (_, end)) in hour_spans(config.hours).enumerate() {
+        cumulative += player_tallies.iter().map(|tally| tally.hourly_profit[hour]).sum::<f64>();
+        profit_over_time.push((end, cumulative));
     }
 
-    // Build heatmap data
-    let heatmap_data = build_heatmap(&bay_results);
+    assert_finite("run_venue_simulation", "total_wagered", total_wagered);
+    assert_finite("run_venue_simulation", "total_payouts", total_payouts);
+    assert_finite("run_venue_simulation", "net_profit", net_profit);
+    assert_finite("run_venue_simulation", "hold_percentage", hold_percentage);
+    for (hour, cumulative) in &profit_over_time {
+        assert_finite("run_venue_simulation", &format!("profit at hour {}", hour), *cumulative);
+    }
 
-    // Build payout distribution
-    let payout_distribution = build_payout_distribution(&all_shots);
+    // Build heatmap data and band totals
+    let heatmap_data = build_heatmap(&config.holes, &player_tallies);
+    let band_summary = build_band_summary(&player_tallies);
+    let per_hole = build_hole_stats(&config.holes, &player_tallies);
 
     VenueResult {
         total_wagered,
@@ -205,66 +642,183 @@ pub fn run_venue_simulation(config: VenueConfig) -> VenueResult {
         profit_over_time,
         heatmap_data,
         payout_distribution,
-        total_shots: all_shots.len(),
+        total_shots,
+        band_summary,
+        bay_summaries,
+        per_hole,
+        shots,
+        was_cancelled,
     }
 }
 
-/// Build heatmap data from bay results
-fn build_heatmap(bay_results: &[(Player, crate::simulators::player_session::SessionResult)]) -> HeatmapData {
-    // Define handicap bins
-    let handicap_bins = vec![
-        "0-4".to_string(),
-        "5-9".to_string(),
-        "10-14".to_string(),
-        "15-19".to_string(),
-        "20-24".to_string(),
-        "25-30".to_string(),
-    ];
+/// Per-player session totals needed for the venue aggregates
+///
+/// Small enough to keep for every player, so a streaming run can drop the
+/// session's shot log as soon as it has been tallied.
+struct PlayerTally {
+    handicap: u8,
+    total_wagered: f64,
+    total_won: f64,
+    /// House profit, amount wagered and shot count per hole, in the venue's hole order
+    hole_profit: Vec<f64>,
+    hole_wagered: Vec<f64>,
+    hole_counts: Vec<usize>,
+    payout_distribution: [usize; 11],
+    num_shots: usize,
+    /// House profit per operating hour
+    hourly_profit: Vec<f64>,
+    /// The session's shots, kept only with `retain_shots`
+    shots: Vec<ShotOutcome>,
+}
+
+impl PlayerTally {
+    /// `shot_hours` gives the operating hour of each of the session's shots
+    fn from_session(
+        holes: &HoleSet,
+        player: &Player,
+        session_result: &SessionResult,
+        shot_hours: &[usize],
+        num_hours: usize,
+    ) -> Self {
+        let mut hole_profit = vec![0.0; holes.len()];
+        let mut hole_wagered = vec![0.0; holes.len()];
+        let mut hole_counts = vec![0; holes.len()];
+        let mut hourly_profit = vec![0.0; num_hours];
+
+        for (shot, &hour) in session_result.shots.iter().zip(shot_hours) {
+            if let Some(hole_idx) = holes.index_of(shot.hole_id) {
+                hole_profit[hole_idx] += shot.wager - shot.payout;
+                hole_wagered[hole_idx] += shot.wager;
+                hole_counts[hole_idx] += 1;
+            }
+            hourly_profit[hour] += shot.wager - shot.payout;
+        }
+
+        PlayerTally {
+            handicap: player.handicap,
+            total_wagered: session_result.total_wagered,
+            total_won: session_result.total_won,
+            hole_profit,
+            hole_wagered,
+            hole_counts,
+            payout_distribution: build_payout_distribution(&session_result.shots),
+            num_shots: session_result.shots.len(),
+            hourly_profit,
+            shots: Vec::new(),
+        }
+    }
+}
+
+/// Play one bay session, scaling each shot's wager range by its hour's multiplier
+///
+/// Runs the shared session loop, so a flat profile (every multiplier 1.0)
+/// reproduces `run_session_with_rng` on the same generator. Stops early, with
+/// the shots played so far, if `cancel` is set.
+fn play_bay_session(
+    player: &mut Player,
+    config: SessionConfig,
+    shot_hours: &[usize],
+    wager_multipliers: &[f64],
+    cancel: &CancelToken,
+    rng: &mut impl Rng,
+) -> SessionResult {
+    play_session(player, config, rng, |shot| wager_multipliers[shot_hours[shot]], Some(cancel), None)
+}
+
+/// Handicap band labels shared by the heatmap and the band summary
+const HANDICAP_BANDS: [&str; 6] = ["0-4", "5-9", "10-14", "15-19", "20-24", "25-30"];
+
+/// Index into `HANDICAP_BANDS` for a handicap
+fn handicap_band(handicap: u8) -> usize {
+    match handicap {
+        0..=4 => 0,
+        5..=9 => 1,
+        10..=14 => 2,
+        15..=19 => 3,
+        20..=24 => 4,
+        _ => 5,
+    }
+}
+
+/// Sum player totals into handicap bands
+fn build_band_summary(player_tallies: &[PlayerTally]) -> Vec<HandicapBand> {
+    let mut bands: Vec<HandicapBand> = HANDICAP_BANDS
+        .iter()
+        .map(|label| HandicapBand {
+            label: label.to_string(),
+            players: 0,
+            shots: 0,
+            total_wagered: 0.0,
+            net_profit: 0.0,
+        })
+        .collect();
+
+    for tally in player_tallies {
+        let band = &mut bands[handicap_band(tally.handicap)];
+        band.players += 1;
+        band.shots += tally.num_shots;
+        band.total_wagered += tally.total_wagered;
+        band.net_profit += tally.total_wagered - tally.total_won;
+    }
+
+    bands
+}
+
+/// Sum player totals per hole, skipping holes with no shots
+fn build_hole_stats(holes: &HoleSet, player_tallies: &[PlayerTally]) -> HashMap<u8, HoleStats> {
+    holes
+        .iter()
+        .enumerate()
+        .filter_map(|(hole_idx, hole)| {
+            let shot_count: usize = player_tallies.iter().map(|tally| tally.hole_counts[hole_idx]).sum();
+            if shot_count == 0 {
+                return None;
+            }
+            let wagered: f64 = player_tallies.iter().map(|tally| tally.hole_wagered[hole_idx]).sum();
+            let profit: f64 = player_tallies.iter().map(|tally| tally.hole_profit[hole_idx]).sum();
+            let stats = HoleStats {
+                wagered,
+                payouts: wagered - profit,
+                hold_percentage: if wagered > 0.0 { profit / wagered } else { 0.0 },
+                shot_count,
+            };
+            Some((hole.id, stats))
+        })
+        .collect()
+}
+
+/// Build heatmap data from per-player results
+///
+/// Each player's shots go to their own handicap bin, so bays shared by
+/// several players are split correctly.
+fn build_heatmap(holes: &HoleSet, player_tallies: &[PlayerTally]) -> HeatmapData {
+    let handicap_bins: Vec<String> = HANDICAP_BANDS.iter().map(|label| label.to_string()).collect();
 
     // Get all hole distances
-    let distance_bins: Vec<u16> = HOLE_CONFIGURATIONS.iter().map(|h| h.distance_yds).collect();
+    let distance_bins: Vec<u16> = holes.iter().map(|h| h.distance_yds).collect();
 
-    // Initialize hold percentage matrix
+    // House profit and amount wagered per (handicap band, hole) cell
     let mut hold_matrix = vec![vec![0.0; distance_bins.len()]; handicap_bins.len()];
-    let mut count_matrix = vec![vec![0; distance_bins.len()]; handicap_bins.len()];
-
-    for (player, session_result) in bay_results {
-        let handicap_bin = match player.handicap {
-            0..=4 => 0,
-            5..=9 => 1,
-            10..=14 => 2,
-            15..=19 => 3,
-            20..=24 => 4,
-            25..=30 => 5,
-            _ => 5,
-        };
+    let mut wagered_matrix = vec![vec![0.0; distance_bins.len()]; handicap_bins.len()];
 
-        for shot in &session_result.shots {
-            if let Some(hole_idx) = HOLE_CONFIGURATIONS.iter().position(|h| h.id == shot.hole_id) {
-                let profit = shot.wager - shot.payout;
-                hold_matrix[handicap_bin][hole_idx] += profit;
-                count_matrix[handicap_bin][hole_idx] += 1;
-            }
+    for tally in player_tallies {
+        let handicap_bin = handicap_band(tally.handicap);
+
+        for hole_idx in 0..distance_bins.len() {
+            hold_matrix[handicap_bin][hole_idx] += tally.hole_profit[hole_idx];
+            wagered_matrix[handicap_bin][hole_idx] += tally.hole_wagered[hole_idx];
         }
     }
 
-    // Calculate hold percentages
+    // Hold % = profit / total wagered in the cell (0 for cells nobody played)
     let hold_percentages: Vec<Vec<f64>> = hold_matrix
         .iter()
-        .zip(count_matrix.iter())
-        .map(|(holds, counts)| {
+        .zip(wagered_matrix.iter())
+        .map(|(holds, wagered)| {
             holds
                 .iter()
-                .zip(counts.iter())
-                .map(|(profit, count)| {
-                    if *count > 0 {
-                        // Hold % = profit / total_wagered_in_bin
-                        // Approximate wager as profit / hold_rate (we use average)
-                        profit / (*count as f64 * 10.0) // Assume avg wager ~$10
-                    } else {
-                        0.0
-                    }
-                })
+                .zip(wagered.iter())
+                .map(|(profit, wagered)| if *wagered > 0.0 { profit / wagered } else { 0.0 })
                 .collect()
         })
         .collect();
@@ -277,7 +831,7 @@ fn build_heatmap(bay_results: &[(Player, crate::simulators::player_session::Sess
 }
 
 /// Build payout distribution histogram
-fn build_payout_distribution(shots: &[crate::models::shot::ShotOutcome]) -> [usize; 11] {
+fn build_payout_distribution(shots: &[ShotOutcome]) -> [usize; 11] {
     let mut distribution = [0; 11];
 
     for shot in shots {
@@ -355,6 +909,29 @@ mod tests {
         assert!(mean < 15.0, "SkewedLow should have mean < 15, got {}", mean);
     }
 
+    #[test]
+    fn test_shuffle_player_pool_is_reproducible() {
+        let pool = generate_player_pool(&PlayerArchetype::Uniform, 50);
+        let ids = |players: &[Player]| players.iter().map(|p| p.id.clone()).collect::<Vec<_>>();
+
+        let mut first = pool.clone();
+        let mut second = pool.clone();
+        shuffle_player_pool(&mut first, 42);
+        shuffle_player_pool(&mut second, 42);
+        assert_eq!(ids(&first), ids(&second));
+
+        let mut other_seed = pool.clone();
+        shuffle_player_pool(&mut other_seed, 43);
+        assert_ne!(ids(&first), ids(&other_seed));
+
+        // Shuffling is a permutation: no players lost or duplicated
+        let mut sorted = ids(&first);
+        sorted.sort();
+        let mut original = ids(&pool);
+        original.sort();
+        assert_eq!(sorted, original);
+    }
+
     #[test]
     fn test_run_venue_simulation_basic() {
         let config = VenueConfig {
@@ -363,6 +940,7 @@ mod tests {
             shots_per_hour: 10,
             player_archetype: PlayerArchetype::Uniform,
             wager_range: (5.0, 10.0),
+            ..Default::default()
         };
 
         let result = run_venue_simulation(config);
@@ -376,8 +954,6 @@ mod tests {
 
     #[test]
     fn test_build_payout_distribution() {
-        use crate::models::shot::ShotOutcome;
-
         let shots = vec![
             ShotOutcome {
                 miss_distance_ft: 5.0,
@@ -386,6 +962,7 @@ mod tests {
                 wager: 10.0,
                 hole_id: 1,
                 is_fat_tail: false,
+                timestamp_ms: None,
             },
             ShotOutcome {
                 miss_distance_ft: 2.0,
@@ -394,6 +971,7 @@ mod tests {
                 wager: 10.0,
                 hole_id: 1,
                 is_fat_tail: false,
+                timestamp_ms: None,
             },
             ShotOutcome {
                 miss_distance_ft: 1.0,
@@ -402,6 +980,7 @@ mod tests {
                 wager: 10.0,
                 hole_id: 1,
                 is_fat_tail: false,
+                timestamp_ms: None,
             },
         ];
 
@@ -412,6 +991,73 @@ mod tests {
         assert_eq!(dist[10], 1); // 10x+ multiplier (12.0)
     }
 
+    #[test]
+    fn test_handicap_distribution_matches_pool() {
+        let archetypes = [
+            PlayerArchetype::Uniform,
+            PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 },
+            PlayerArchetype::SkewedHigh,
+            PlayerArchetype::SkewedLow,
+        ];
+
+        for archetype in &archetypes {
+            let distribution = handicap_distribution(archetype);
+            assert_eq!(distribution.len(), 31);
+            let total: f64 = distribution.iter().map(|(_, p)| p).sum();
+            assert!((total - 1.0).abs() < 1e-9, "{:?} sums to {}", archetype, total);
+
+            let expected_mean: f64 = distribution.iter().map(|(h, p)| *h as f64 * p).sum();
+            let mut rng = StdRng::seed_from_u64(3);
+            let pool = generate_player_pool_with_rng(archetype, 20_000, &mut rng);
+            let sample_mean = pool.iter().map(|p| p.handicap as f64).sum::<f64>() / pool.len() as f64;
+            assert!(
+                (sample_mean - expected_mean).abs() < 0.2,
+                "{:?}: sample mean {} vs exact {}",
+                archetype,
+                sample_mean,
+                expected_mean
+            );
+        }
+    }
+
+    #[test]
+    fn test_bell_curve_pool_with_inverse_cdf_sampler() {
+        let archetype = PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 };
+        let handicaps = |seed| {
+            let mut rng = StdRng::seed_from_u64(seed);
+            generate_player_pool_with_sampler(&archetype, 200, NormalSampler::InverseCdf, &mut rng)
+                .iter()
+                .map(|p| p.handicap)
+                .collect::<Vec<_>>()
+        };
+
+        let first = handicaps(5);
+        assert_eq!(first, handicaps(5));
+        assert_ne!(first, handicaps(6));
+
+        let mean = first.iter().map(|&h| h as f64).sum::<f64>() / first.len() as f64;
+        assert!((mean - 15.0).abs() < 1.5, "Mean handicap should be near 15, got {}", mean);
+    }
+
+    #[test]
+    fn test_streaming_matches_retained_run() {
+        let config = VenueConfig {
+            num_bays: 6,
+            hours: 1.0,
+            shots_per_hour: 50,
+            seed: Some(11),
+            ..Default::default()
+        };
+
+        let retained = run_venue_simulation(config.clone());
+        let streamed = run_venue_simulation(VenueConfig { streaming: true, ..config });
+
+        assert_eq!(streamed.total_shots, retained.total_shots);
+        assert_eq!(streamed.net_profit, retained.net_profit);
+        assert_eq!(streamed.payout_distribution, retained.payout_distribution);
+        assert_eq!(streamed.heatmap_data.hold_percentages, retained.heatmap_data.hold_percentages);
+    }
+
     #[test]
     fn test_venue_result_profit_over_time() {
         let config = VenueConfig {
@@ -420,6 +1066,7 @@ mod tests {
             shots_per_hour: 20,
             player_archetype: PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 },
             wager_range: (5.0, 15.0),
+            ..Default::default()
         };
 
         let result = run_venue_simulation(config);
@@ -434,6 +1081,38 @@ mod tests {
         assert!((result.profit_over_time[4].1 - result.net_profit).abs() < 0.01);
     }
 
+    #[test]
+    fn test_band_summary_totals() {
+        let result = run_venue_simulation(VenueConfig {
+            num_bays: 12,
+            hours: 1.0,
+            shots_per_hour: 30,
+            player_archetype: PlayerArchetype::Uniform,
+            seed: Some(232),
+            ..Default::default()
+        });
+
+        assert_eq!(result.band_summary.len(), 6);
+        assert_eq!(result.band_summary[0].label, "0-4");
+        assert_eq!(result.band_summary.iter().map(|b| b.players).sum::<usize>(), 12);
+        assert_eq!(result.band_summary.iter().map(|b| b.shots).sum::<usize>(), result.total_shots);
+        let band_profit: f64 = result.band_summary.iter().map(|b| b.net_profit).sum();
+        assert!((band_profit - result.net_profit).abs() < 1e-6);
+    }
+
+    #[test]
+    fn test_empty_venue_results_are_finite() {
+        let closed = run_venue_simulation(VenueConfig { hours: 0.0, num_bays: 3, ..Default::default() });
+        assert_eq!(closed.total_shots, 0);
+        assert_eq!(closed.hold_percentage, 0.0);
+        assert_eq!(closed.profit_over_time, vec![(0.0, 0.0)]);
+
+        let no_bays = run_venue_simulation(VenueConfig { num_bays: 0, ..Default::default() });
+        assert_eq!(no_bays.total_shots, 0);
+        assert_eq!(no_bays.net_profit, 0.0);
+        assert!(no_bays.profit_over_time.iter().all(|(_, profit)| profit.is_finite()));
+    }
+
     #[test]
     fn test_heatmap_structure() {
         let config = VenueConfig {
@@ -442,6 +1121,7 @@ mod tests {
             shots_per_hour: 10,
             player_archetype: PlayerArchetype::Uniform,
             wager_range: (5.0, 10.0),
+            ..Default::default()
         };
 
         let result = run_venue_simulation(config);
@@ -458,4 +1138,243 @@ mod tests {
             assert_eq!(row.len(), 8);
         }
     }
+
+    #[test]
+    fn test_heatmap_hold_uses_actual_wagers() {
+        let config = VenueConfig {
+            num_bays: 12,
+            hours: 1.0,
+            shots_per_hour: 60,
+            player_archetype: PlayerArchetype::Uniform,
+            wager_range: (50.0, 50.0),
+            seed: Some(310),
+            retain_shots: true,
+            ..Default::default()
+        };
+        let result = run_venue_simulation(config);
+
+        // One player per bay, and shots are kept in bay order
+        let holes = HoleSet::standard();
+        let mut profit = vec![vec![0.0; holes.len()]; HANDICAP_BANDS.len()];
+        let mut wagered = vec![vec![0.0; holes.len()]; HANDICAP_BANDS.len()];
+        let mut shots = result.shots.iter();
+        for bay in &result.bay_summaries {
+            for shot in shots.by_ref().take(bay.shots) {
+                let hole_idx = holes.index_of(shot.hole_id).unwrap();
+                profit[handicap_band(bay.handicap)][hole_idx] += shot.wager - shot.payout;
+                wagered[handicap_band(bay.handicap)][hole_idx] += shot.wager;
+            }
+        }
+
+        let mut played_cells = 0;
+        for (band, row) in result.heatmap_data.hold_percentages.iter().enumerate() {
+            for (hole_idx, hold) in row.iter().enumerate() {
+                // The house can't keep more than was wagered
+                assert!(*hold <= 1.0, "hold {} in band {} hole {}", hold, band, hole_idx);
+                if wagered[band][hole_idx] > 0.0 {
+                    played_cells += 1;
+                    assert!((hold - profit[band][hole_idx] / wagered[band][hole_idx]).abs() < 1e-12);
+                } else {
+                    assert_eq!(*hold, 0.0);
+                }
+            }
+        }
+        assert!(played_cells > 0);
+    }
+
+    #[test]
+    fn test_venue_with_custom_hole_set() {
+        use crate::models::hole::Hole;
+
+        let holes = HoleSet::new(vec![
+            Hole::new(1, 100, 20.0, 0.85, 1.0),
+            Hole::new(2, 160, 30.0, 0.85, 1.0),
+            Hole::new(3, 230, 45.0, 0.85, 1.0),
+        ])
+        .unwrap();
+        let config = VenueConfig {
+            num_bays: 3,
+            hours: 1.0,
+            shots_per_hour: 30,
+            holes,
+            seed: Some(254),
+            ..Default::default()
+        };
+
+        let result = run_venue_simulation(config);
+
+        assert_eq!(result.heatmap_data.distance_bins, vec![100, 160, 230]);
+        for row in &result.heatmap_data.hold_percentages {
+            assert_eq!(row.len(), 3);
+        }
+        assert!(result.total_wagered > 0.0);
+    }
+
+    #[test]
+    fn test_players_rotate_through_bays() {
+        let config = VenueConfig {
+            num_bays: 4,
+            hours: 1.0,
+            shots_per_hour: 31,
+            players_per_bay: 3,
+            player_archetype: PlayerArchetype::Uniform,
+            seed: Some(263),
+            ..Default::default()
+        };
+
+        let result = run_venue_simulation(config.clone());
+
+        assert_eq!(result.total_shots, 124);
+        for summary in &result.bay_summaries {
+            assert_eq!(summary.players, 3);
+            assert_eq!(summary.shots, 31);
+        }
+
+        // Bands count every player, with each player's own shots
+        let band_players: usize = result.band_summary.iter().map(|band| band.players).sum();
+        let band_shots: usize = result.band_summary.iter().map(|band| band.shots).sum();
+        assert_eq!(band_players, 12);
+        assert_eq!(band_shots, 124);
+
+        let streamed = run_venue_simulation(VenueConfig { streaming: true, ..config });
+        assert_eq!(streamed.total_wagered, result.total_wagered);
+        assert_eq!(streamed.heatmap_data.hold_percentages, result.heatmap_data.hold_percentages);
+    }
+
+    #[test]
+    fn test_evening_peak_back_loads_traffic() {
+        let shots = TrafficProfile::EveningPeak.shots_by_hour(8.0, 800);
+        assert_eq!(shots.len(), 8);
+        assert_eq!(shots.iter().sum::<usize>(), 800);
+        assert!(shots[..2].iter().sum::<usize>() < shots[6..].iter().sum::<usize>(), "{:?}", shots);
+        assert_eq!(TrafficProfile::Flat.shots_by_hour(8.0, 800), vec![100; 8]);
+
+        // A fractional final hour gets a smaller share, and the total still holds
+        let partial = TrafficProfile::WeekendRush.shots_by_hour(2.5, 101);
+        assert_eq!(partial.len(), 3);
+        assert_eq!(partial.iter().sum::<usize>(), 101);
+
+        let config = VenueConfig {
+            num_bays: 10,
+            hours: 8.0,
+            shots_per_hour: 50,
+            traffic_profile: TrafficProfile::EveningPeak,
+            seed: Some(264),
+            ..Default::default()
+        };
+        let result = run_venue_simulation(config);
+
+        assert_eq!(result.total_shots, 4000);
+        assert_eq!(result.profit_over_time.len(), 9);
+        assert!((result.profit_over_time[8].1 - result.net_profit).abs() < 1e-6);
+        // Busier, higher-stakes closing hours earn more than the opening ones
+        let slope = |hour: usize| result.profit_over_time[hour + 1].1 - result.profit_over_time[hour].1;
+        assert!(slope(0) + slope(1) < slope(6) + slope(7), "{:?}", result.profit_over_time);
+    }
+
+    #[test]
+    fn test_bay_session_runs_the_session_loop() {
+        use crate::simulators::player_session::{run_session_with_rng, BettingStrategy};
+
+        let config = SessionConfig {
+            num_shots: 40,
+            kalman_batch_size: 3,
+            betting_strategy: BettingStrategy::Martingale { base: 2.0, max: 50.0 },
+            ..Default::default()
+        };
+        let hours = vec![0; 20].into_iter().chain(vec![1; 20]).collect::<Vec<_>>();
+
+        // A flat profile is exactly a plain session on the same generator
+        let mut bay_player = Player::new("bay".to_string(), 12);
+        let mut plain_player = bay_player.clone();
+        let bay = play_bay_session(
+            &mut bay_player,
+            config.clone(),
+            &hours,
+            &[1.0, 1.0],
+            &CancelToken::new(),
+            &mut StdRng::seed_from_u64(264),
+        );
+        let plain = run_session_with_rng(&mut plain_player, config.clone(), &mut StdRng::seed_from_u64(264));
+        assert_eq!(bay.digest, plain.digest);
+        assert!(bay_player.skill_profiles.values().all(|skill| skill.batch_size == 3));
+
+        // The strategy's stakes follow the hour's multiplier
+        let scaled = play_bay_session(
+            &mut Player::new("bay".to_string(), 12),
+            config,
+            &hours,
+            &[1.0, 3.0],
+            &CancelToken::new(),
+            &mut StdRng::seed_from_u64(264),
+        );
+        assert_eq!(scaled.shots[0].wager, 2.0);
+        assert!(scaled.shots[20..].iter().all(|shot| shot.wager >= 6.0 && shot.wager <= 150.0));
+    }
+
+    #[test]
+    fn test_per_hole_stats_sum_to_totals() {
+        let config = VenueConfig {
+            num_bays: 6,
+            hours: 2.0,
+            shots_per_hour: 40,
+            seed: Some(265),
+            ..Default::default()
+        };
+        let result = run_venue_simulation(config);
+
+        assert!(!result.per_hole.is_empty());
+        let shots: usize = result.per_hole.values().map(|stats| stats.shot_count).sum();
+        let wagered: f64 = result.per_hole.values().map(|stats| stats.wagered).sum();
+        let payouts: f64 = result.per_hole.values().map(|stats| stats.payouts).sum();
+        assert_eq!(shots, result.total_shots);
+        assert!((wagered - result.total_wagered).abs() < 1e-6);
+        assert!((payouts - result.total_payouts).abs() < 1e-6);
+        for stats in result.per_hole.values() {
+            assert!((stats.hold_percentage - stats.net_profit() / stats.wagered).abs() < 1e-12);
+        }
+
+        let top = result.most_profitable_holes(3);
+        assert_eq!(top.len(), 3);
+        assert!(top[0].1.net_profit() >= top[1].1.net_profit());
+        assert!(top[1].1.net_profit() >= top[2].1.net_profit());
+    }
+
+    #[test]
+    fn test_cancelled_venue_returns_partial_result() {
+        let config = VenueConfig {
+            num_bays: 4,
+            hours: 20_000.0,
+            shots_per_hour: 100,
+            seed: Some(304),
+            streaming: true,
+            ..Default::default()
+        };
+        let planned = 4 * 20_000 * 100;
+
+        let cancel = CancelToken::new();
+        let canceller = {
+            let cancel = cancel.clone();
+            std::thread::spawn(move || {
+                std::thread::sleep(std::time::Duration::from_millis(50));
+                cancel.cancel();
+            })
+        };
+        let result = run_venue_simulation_cancellable(config.clone(), &cancel);
+        canceller.join().unwrap();
+
+        assert!(result.was_cancelled);
+        assert!(result.total_shots < planned, "{} shots", result.total_shots);
+        let bay_shots: usize = result.bay_summaries.iter().map(|bay| bay.shots).sum();
+        assert_eq!(bay_shots, result.total_shots);
+        if result.total_wagered > 0.0 {
+            assert!((result.hold_percentage - result.net_profit / result.total_wagered).abs() < 1e-12);
+        }
+
+        // A token cancelled up front stops every bay before its first shot
+        let result = run_venue_simulation_cancellable(config, &cancel);
+        assert!(result.was_cancelled);
+        assert_eq!(result.total_shots, 0);
+        assert_eq!(result.net_profit, 0.0);
+    }
 }
\ No newline at end of file
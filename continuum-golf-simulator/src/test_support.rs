@@ -0,0 +1,68 @@
+//! Statistical assertion helpers for Monte Carlo tests
+//!
+//! Simulated results are noisy, so fixed tolerances are either too loose
+//! (they hide real bias) or too tight (they fail by chance). These helpers
+//! phrase the tolerance in standard errors instead: `z = 3` fails a correct
+//! implementation about 0.3% of the time, whatever the sample size.
+
+/// Sample mean and standard error of the mean
+///
+/// Standard error is 0.0 with fewer than two samples.
+pub fn mean_and_std_error(samples: &[f64]) -> (f64, f64) {
+    let n = samples.len() as f64;
+    if samples.is_empty() {
+        return (0.0, 0.0);
+    }
+    let mean = samples.iter().sum::<f64>() / n;
+    if samples.len() < 2 {
+        return (mean, 0.0);
+    }
+    let variance = samples.iter().map(|x| (x - mean).powi(2)).sum::<f64>() / (n - 1.0);
+    (mean, (variance / n).sqrt())
+}
+
+/// Assert that `observed` lies within `z` standard errors of `expected`
+///
+/// # Panics
+/// If `|observed - expected| > z × std_error`, reporting the gap in standard errors
+#[track_caller]
+pub fn assert_within_ci(observed: f64, expected: f64, std_error: f64, z: f64) {
+    let allowed = z * std_error;
+    let gap = (observed - expected).abs();
+    assert!(
+        gap <= allowed,
+        "Observed {:.6} is {:.2} standard errors from expected {:.6} (std error {:.6}, allowed z = {})",
+        observed,
+        gap / std_error,
+        expected,
+        std_error,
+        z
+    );
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_assert_within_ci_one_std_error_passes() {
+        assert_within_ci(10.5, 10.0, 0.5, 2.0);
+        assert_within_ci(9.5, 10.0, 0.5, 2.0);
+    }
+
+    #[test]
+    #[should_panic(expected = "3.00 standard errors")]
+    fn test_assert_within_ci_three_std_errors_fails() {
+        assert_within_ci(11.5, 10.0, 0.5, 2.0);
+    }
+
+    #[test]
+    fn test_mean_and_std_error() {
+        let (mean, std_error) = mean_and_std_error(&[1.0, 2.0, 3.0, 4.0]);
+        assert_eq!(mean, 2.5);
+        // Sample variance 5/3 over 4 samples
+        assert!((std_error - (5.0_f64 / 3.0 / 4.0).sqrt()).abs() < 1e-12);
+        assert_eq!(mean_and_std_error(&[7.0]), (7.0, 0.0));
+        assert_eq!(mean_and_std_error(&[]), (0.0, 0.0));
+    }
+}
@@ -1,8 +1,8 @@
-/// Anti-Cheat and Fraud Detection Tests
-///
-/// This test suite validates that the Kalman filter and game mechanics
-/// can detect and prevent various cheating strategies that players might
-/// attempt to exploit the system.
+//! Anti-Cheat and Fraud Detection Tests
+//!
+//! This test suite validates that the Kalman filter and game mechanics
+//! can detect and prevent various cheating strategies that players might
+//! attempt to exploit the system.
 
 use continuum_golf_simulator::models::hole::*;
 use continuum_golf_simulator::models::player::*;
@@ -36,10 +36,12 @@ fn test_sandbagging_attack() {
         hole_selection: HoleSelection::Fixed(4),
         developer_mode: Some(DeveloperMode {
             manual_miss_distance: Some(100.0), // Terrible miss
+            manual_miss_sequence: None,
             disable_kalman: false,
         }),
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let sandbagging_result = run_session(&mut player, sandbagging_config);
@@ -62,6 +64,7 @@ fn test_sandbagging_attack() {
         developer_mode: None, // Real shots now
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let exploit_result = run_session(&mut player, exploit_config);
@@ -123,6 +126,7 @@ fn test_gradual_skill_manipulation() {
             // Intentional bad shot
             Some(DeveloperMode {
                 manual_miss_distance: Some(60.0),
+                manual_miss_sequence: None,
                 disable_kalman: false,
             })
         } else {
@@ -138,6 +142,7 @@ fn test_gradual_skill_manipulation() {
             developer_mode,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
 
         run_session(&mut player, config);
@@ -191,10 +196,12 @@ fn test_sudden_skill_jump_detection() {
         hole_selection: HoleSelection::Fixed(4),
         developer_mode: Some(DeveloperMode {
             manual_miss_distance: Some(80.0), // Intentional poor performance
+            manual_miss_sequence: None,
             disable_kalman: false,
         }),
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let baseline_result = run_session(&mut player, baseline_config);
@@ -215,6 +222,7 @@ fn test_sudden_skill_jump_detection() {
         developer_mode: None, // Real shots from skilled player
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let cheat_result = run_session(&mut player, cheat_config);
@@ -287,6 +295,7 @@ fn test_bet_timing_exploitation() {
             // Low wager on intentional bad shot
             (5.0, Some(DeveloperMode {
                 manual_miss_distance: Some(60.0),
+                manual_miss_sequence: None,
                 disable_kalman: false,
             }))
         };
@@ -299,6 +308,7 @@ fn test_bet_timing_exploitation() {
             developer_mode,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
 
         let result = run_session(&mut player, config);
@@ -344,7 +354,7 @@ fn test_multi_account_collusion() {
     let hole = get_hole_by_id(4).unwrap();
 
     // Create 3 colluding accounts
-    let mut accounts = vec![
+    let mut accounts = [
         Player::new("colluder_1".to_string(), 15),
         Player::new("colluder_2".to_string(), 15),
         Player::new("colluder_3".to_string(), 15),
@@ -353,7 +363,7 @@ fn test_multi_account_collusion() {
     println!("\n--- Strategy: Share information across 3 accounts ---");
 
     // Each account tests different strategies
-    let strategies = vec![
+    let strategies = [
         ("Conservative", 30.0),
         ("Moderate", 50.0),
         ("Aggressive", 70.0),
@@ -372,10 +382,12 @@ fn test_multi_account_collusion() {
             hole_selection: HoleSelection::Fixed(4),
             developer_mode: Some(DeveloperMode {
                 manual_miss_distance: Some(*miss_distance),
+                manual_miss_sequence: None,
                 disable_kalman: false,
             }),
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
 
         let result = run_session(&mut accounts[idx], config);
@@ -412,7 +424,6 @@ fn test_session_interruption_exploitation() {
     println!("\n=== Anti-Cheat Test: Session Interruption Exploitation ===");
 
     let mut player = Player::new("cheater_interruption".to_string(), 15);
-    let hole = get_hole_by_id(4).unwrap();
 
     println!("\n--- Strategy: Interrupt after real good shots, resume with bad shots ---");
 
@@ -430,6 +441,7 @@ fn test_session_interruption_exploitation() {
             // Intentional bad shots
             Some(DeveloperMode {
                 manual_miss_distance: Some(65.0),
+                manual_miss_sequence: None,
                 disable_kalman: false,
             })
         };
@@ -442,6 +454,7 @@ fn test_session_interruption_exploitation() {
             developer_mode,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
 
         let result = run_session(&mut player, config);
@@ -499,10 +512,12 @@ fn test_maximum_exploitation_attempt() {
             hole_selection: HoleSelection::Fixed(4),
             developer_mode: Some(DeveloperMode {
                 manual_miss_distance: Some(120.0),
+                manual_miss_sequence: None,
                 disable_kalman: false,
             }),
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
         run_session(&mut player, config);
     }
@@ -524,6 +539,7 @@ fn test_maximum_exploitation_attempt() {
             // Throw-away shot (intentional bad miss)
             (1.0, Some(DeveloperMode {
                 manual_miss_distance: Some(90.0),
+                manual_miss_sequence: None,
                 disable_kalman: false,
             }))
         };
@@ -536,6 +552,7 @@ fn test_maximum_exploitation_attempt() {
             developer_mode,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
 
         let result = run_session(&mut player, config);
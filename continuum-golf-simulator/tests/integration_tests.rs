@@ -1,26 +1,25 @@
-use continuum_golf_simulator::math::distributions::*;
 use continuum_golf_simulator::models::hole::*;
 use continuum_golf_simulator::models::player::*;
 use continuum_golf_simulator::simulators::player_session::*;
 use continuum_golf_simulator::simulators::venue::*;
 use continuum_golf_simulator::simulators::tournament::*;
+use continuum_golf_simulator::test_support::{assert_within_ci, mean_and_std_error};
 
 /// Test 1: RTP Validation - 10,000-shot session
 ///
 /// For each hole, simulate 10,000 shots across handicaps 0-30
-/// Aggregate: total_wagered, total_won
-/// Assert: (total_won / total_wagered) == hole.rtp ± 0.015
+/// Aggregate: per-shot return (payout / wager)
+/// Assert: mean return == hole.rtp within 4 standard errors
 #[test]
 fn test_rtp_validation_10k_shots() {
     const NUM_SHOTS: usize = 10_000;
-    const TOLERANCE: f64 = 0.025; // 2.5% tolerance (accounting for statistical variance)
+    const Z: f64 = 4.0; // 8 holes tested; keeps the chance of a false alarm well under 0.1%
 
     for hole in HOLE_CONFIGURATIONS.iter() {
         println!("\n=== Testing Hole {} ({}yd, target RTP: {}) ===",
                  hole.id, hole.distance_yds, hole.rtp);
 
-        let mut total_wagered = 0.0;
-        let mut total_won = 0.0;
+        let mut returns = Vec::with_capacity(NUM_SHOTS);
 
         // Test across multiple handicap levels
         for handicap in [0, 10, 20, 30].iter() {
@@ -34,26 +33,19 @@ fn test_rtp_validation_10k_shots() {
                 developer_mode: None,
                 fat_tail_prob: 0.02,
                 fat_tail_mult: 3.0,
+                ..Default::default()
             };
 
             let result = run_session(&mut player, config);
-            total_wagered += result.total_wagered;
-            total_won += result.total_won;
+            returns.extend(result.shots.iter().map(|shot| shot.payout / shot.wager));
         }
 
-        let actual_rtp = total_won / total_wagered;
-        let diff = (actual_rtp - hole.rtp).abs();
+        let (actual_rtp, std_error) = mean_and_std_error(&returns);
 
-        println!("  Total Wagered: ${:.2}", total_wagered);
-        println!("  Total Won: ${:.2}", total_won);
         println!("  Actual RTP: {:.4} (target: {:.4})", actual_rtp, hole.rtp);
-        println!("  Difference: {:.4} (tolerance: {:.4})", diff, TOLERANCE);
+        println!("  Std error: {:.4} (allowed: {:.1} SE)", std_error, Z);
 
-        assert!(
-            diff < TOLERANCE,
-            "Hole {} RTP validation failed: actual={:.4}, target={:.4}, diff={:.4}",
-            hole.id, actual_rtp, hole.rtp, diff
-        );
+        assert_within_ci(actual_rtp, hole.rtp, std_error, Z);
     }
 }
 
@@ -67,7 +59,6 @@ fn test_rtp_validation_10k_shots() {
 #[test]
 fn test_kalman_convergence_50_shots() {
     const NUM_SHOTS: usize = 100;
-    const TARGET_CONFIDENCE: f64 = 80.0;
 
     let mut player = Player::new(format!("player_{}", 15), 15);
     let hole = get_hole_by_id(4).unwrap();
@@ -86,6 +77,7 @@ fn test_kalman_convergence_50_shots() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let result = run_session(&mut player, config);
@@ -119,15 +111,14 @@ fn test_kalman_convergence_50_shots() {
 /// For hole H4 (150yds):
 ///   Player A: handicap 5  → should have P_max that compensates for better skill
 ///   Player B: handicap 25 → should have P_max that compensates for worse skill
-/// Run 5,000 trials each, calculate average net gain
-/// Assert: |EV_A - EV_B| < $0.05 per $10 wagered
+/// Run 10,000 shots each, calculate average net gain per shot
+/// Assert: EV_A == EV_B within 3 standard errors of the difference
 #[test]
 fn test_fairness_equal_ev() {
     const NUM_SHOTS: usize = 10_000;
     const WAGER: f64 = 10.0;
-    const MAX_EV_DIFF: f64 = 0.20; // $0.20 tolerance per $10 wagered (accounts for variance and Kalman adaptation)
+    const Z: f64 = 3.0;
 
-    let hole = get_hole_by_id(4).unwrap();
     println!("\n=== Fairness Test: Hole 4 (150yd) ===");
 
     // Test low handicap player
@@ -140,9 +131,10 @@ fn test_fairness_equal_ev() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
     let result_low = run_session(&mut player_low, config_low);
-    let ev_low = result_low.net_gain_loss / (NUM_SHOTS as f64);
+    let (ev_low, se_low) = net_per_shot(&result_low);
 
     println!("Low Handicap (5):");
     println!("  Net: ${:.2}", result_low.net_gain_loss);
@@ -158,22 +150,26 @@ fn test_fairness_equal_ev() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
     let result_high = run_session(&mut player_high, config_high);
-    let ev_high = result_high.net_gain_loss / (NUM_SHOTS as f64);
+    let (ev_high, se_high) = net_per_shot(&result_high);
 
     println!("High Handicap (25):");
     println!("  Net: ${:.2}", result_high.net_gain_loss);
     println!("  EV per shot: ${:.4}", ev_high);
 
     let ev_diff = (ev_low - ev_high).abs();
-    println!("EV Difference: ${:.4} (max allowed: ${:.4})", ev_diff, MAX_EV_DIFF);
+    let std_error = (se_low.powi(2) + se_high.powi(2)).sqrt();
+    println!("EV Difference: ${:.4} (std error: ${:.4})", ev_diff, std_error);
 
-    assert!(
-        ev_diff < MAX_EV_DIFF,
-        "Fairness validation failed: EV difference ${:.4} exceeds tolerance ${:.4}",
-        ev_diff, MAX_EV_DIFF
-    );
+    assert_within_ci(ev_low, ev_high, std_error, Z);
+}
+
+/// Mean net result per shot and its standard error
+fn net_per_shot(result: &SessionResult) -> (f64, f64) {
+    let nets: Vec<f64> = result.shots.iter().map(|shot| shot.payout - shot.wager).collect();
+    mean_and_std_error(&nets)
 }
 
 /// Test 4: Venue Simulation with Different Archetypes
@@ -202,6 +198,7 @@ fn test_venue_simulation_archetypes() {
             shots_per_hour: SHOTS_PER_HOUR,
             player_archetype: archetype,
             wager_range: (5.0, 15.0),
+            ..Default::default()
         };
 
         let result = run_venue_simulation(config);
@@ -225,9 +222,9 @@ fn test_venue_simulation_archetypes() {
         );
 
         // Verify heatmap data structure
-        assert!(result.heatmap_data.handicap_bins.len() > 0, "No handicap bins");
-        assert!(result.heatmap_data.distance_bins.len() > 0, "No distance bins");
-        assert!(result.heatmap_data.hold_percentages.len() > 0, "No hold percentages");
+        assert!(!result.heatmap_data.handicap_bins.is_empty(), "No handicap bins");
+        assert!(!result.heatmap_data.distance_bins.is_empty(), "No distance bins");
+        assert!(!result.heatmap_data.hold_percentages.is_empty(), "No hold percentages");
     }
 }
 
@@ -251,6 +248,7 @@ fn test_tournament_payout_distribution() {
         house_rake_percent: RAKE_PERCENT,
         payout_structure: PayoutStructure::WinnerTakesAll,
         attempts_per_player: 3,
+        ..Default::default()
     };
 
     let result_wta = run_tournament(config_wta);
@@ -278,6 +276,7 @@ fn test_tournament_payout_distribution() {
             third: 0.20,
         },
         attempts_per_player: 3,
+        ..Default::default()
     };
 
     let result_top3 = run_tournament(config_top3);
@@ -303,7 +302,6 @@ fn test_tournament_payout_distribution() {
 #[test]
 fn test_high_stakes_update_logic() {
     let mut player = Player::new(format!("player_{}", 15), 15);
-    let hole = get_hole_by_id(4).unwrap();
 
     println!("\n=== High-Stakes Update Logic Test ===");
 
@@ -316,6 +314,7 @@ fn test_high_stakes_update_logic() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let initial_result = run_session(&mut player, normal_config);
@@ -333,6 +332,7 @@ fn test_high_stakes_update_logic() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let high_stakes_result = run_session(&mut player, high_stakes_config);
@@ -386,10 +386,12 @@ fn test_breakeven_radius() {
         hole_selection: HoleSelection::Fixed(6),
         developer_mode: Some(DeveloperMode {
             manual_miss_distance: Some(d_break_theoretical),
+            manual_miss_sequence: None,
             disable_kalman: true, // Disable Kalman to keep P_max constant
         }),
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let result = run_session(&mut player, config);
@@ -428,6 +430,7 @@ fn test_fat_tail_impact() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let result = run_session(&mut player, config);
@@ -444,3 +447,59 @@ fn test_fat_tail_impact() {
         freq, TARGET_FREQ, TOLERANCE
     );
 }
+
+/// Test 9: CLI Fairness Verdict
+///
+/// Run `validate --test fairness` with a fixed seed and check every hole
+/// passes on overlapping 95% EV intervals, with bounds shown in verbose mode
+#[test]
+fn test_cli_fairness_validation_seeded() {
+    let output = std::process::Command::new(env!("CARGO_BIN_EXE_continuum-golf-simulator"))
+        .args(["validate", "--test", "fairness", "--seed", "1", "--verbose"])
+        .env("NO_COLOR", "1")
+        .output()
+        .expect("failed to run simulator binary");
+    assert!(output.status.success());
+
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    println!("{}", stdout);
+
+    for hole in HOLE_CONFIGURATIONS.iter() {
+        let line = stdout
+            .lines()
+            .find(|l| l.contains(&format!(" H{} (", hole.id)))
+            .unwrap_or_else(|| panic!("No verdict line for hole {}", hole.id));
+        assert!(line.contains("PASS"), "Hole {} did not pass: {}", hole.id, line);
+        assert!(line.contains("95% intervals overlap"), "Unexpected verdict: {}", line);
+    }
+
+    // Verbose mode prints the interval bounds for each handicap
+    let interval_lines = stdout.lines().filter(|l| l.contains("[95% CI ")).count();
+    assert_eq!(interval_lines, HOLE_CONFIGURATIONS.len() * 7);
+    assert!(stdout.contains("All fairness tests passed!"));
+}
+
+/// Test 10: CLI Trial Count
+///
+/// `validate --trials` must be positive; a larger count runs the convergence
+/// test over several sessions
+#[test]
+fn test_cli_validation_trials() {
+    let run = |args: &[&str]| {
+        std::process::Command::new(env!("CARGO_BIN_EXE_continuum-golf-simulator"))
+            .args(args)
+            .env("NO_COLOR", "1")
+            .output()
+            .expect("failed to run simulator binary")
+    };
+
+    let rejected = run(&["validate", "--test", "rtp", "--trials", "0"]);
+    assert!(!rejected.status.success());
+    assert!(String::from_utf8_lossy(&rejected.stderr).contains("--trials"));
+
+    let output = run(&["validate", "--test", "convergence", "--trials", "3000", "--verbose"]);
+    assert!(output.status.success());
+    let stdout = String::from_utf8_lossy(&output.stdout);
+    assert!(stdout.contains("mean of 3 sessions"), "{}", stdout);
+    assert!(stdout.contains("/3 sessions"), "{}", stdout);
+}
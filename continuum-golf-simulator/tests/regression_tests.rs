@@ -0,0 +1,118 @@
+//! Economic regression guard
+//!
+//! Runs a fixed, fully seeded battery (several sessions, one venue, one
+//! tournament) and compares key outputs against `tests/data/regression_baseline.json`.
+//! Any drift beyond tolerance in the payout, integration, or Kalman code fails
+//! with a per-metric diff.
+//!
+//! When a change is intentional, regenerate the baseline and commit it:
+//!
+//! ```text
+//! UPDATE_REGRESSION_BASELINE=1 cargo test --test regression_tests
+//! ```
+
+use continuum_golf_simulator::models::player::Player;
+use continuum_golf_simulator::simulators::player_session::*;
+use continuum_golf_simulator::simulators::tournament::*;
+use continuum_golf_simulator::simulators::venue::*;
+use std::collections::BTreeMap;
+use std::fs;
+use std::path::PathBuf;
+
+/// Relative tolerance; seeded runs are deterministic, so this only absorbs
+/// last-bit differences in platform math libraries
+const RELATIVE_TOLERANCE: f64 = 1e-6;
+const ABSOLUTE_TOLERANCE: f64 = 1e-9;
+
+fn baseline_path() -> PathBuf {
+    PathBuf::from(env!("CARGO_MANIFEST_DIR")).join("tests/data/regression_baseline.json")
+}
+
+/// Run the fixed battery and collect its key outputs
+fn run_battery() -> BTreeMap<String, f64> {
+    let mut metrics = BTreeMap::new();
+
+    for (handicap, seed) in [(5, 101), (15, 102), (25, 103)] {
+        let mut player = Player::new(format!("player_{}", handicap), handicap);
+        let config = SessionConfig {
+            num_shots: 200,
+            wager_min: 5.0,
+            wager_max: 20.0,
+            seed: Some(seed),
+            ..Default::default()
+        };
+        let result = run_session(&mut player, config);
+
+        let prefix = format!("session_h{}", handicap);
+        metrics.insert(format!("{}_rtp", prefix), result.total_won / result.total_wagered);
+        metrics.insert(format!("{}_net", prefix), result.net_gain_loss);
+        metrics.insert(format!("{}_kalman_updates", prefix), result.num_kalman_updates as f64);
+    }
+
+    let venue = run_venue_simulation(VenueConfig {
+        num_bays: 4,
+        hours: 1.0,
+        shots_per_hour: 60,
+        player_archetype: PlayerArchetype::BellCurve { mean: 15, std_dev: 5.0 },
+        wager_range: (5.0, 20.0),
+        seed: Some(201),
+        ..Default::default()
+    });
+    metrics.insert("venue_total_wagered".to_string(), venue.total_wagered);
+    metrics.insert("venue_net_profit".to_string(), venue.net_profit);
+    metrics.insert("venue_hold_pct".to_string(), venue.hold_percentage * 100.0);
+
+    let tournament = run_tournament(TournamentConfig {
+        num_players: 20,
+        seed: Some(301),
+        ..Default::default()
+    });
+    metrics.insert("tournament_prize_pool".to_string(), tournament.prize_pool);
+    metrics.insert("tournament_house_rake".to_string(), tournament.house_rake);
+    metrics.insert("tournament_winning_score".to_string(), tournament.leaderboard[0].1);
+
+    metrics
+}
+
+#[test]
+fn test_economic_outputs_match_baseline() {
+    let current = run_battery();
+
+    if std::env::var_os("UPDATE_REGRESSION_BASELINE").is_some() {
+        let json = serde_json::to_string_pretty(&current).unwrap();
+        fs::write(baseline_path(), json + "\n").unwrap();
+        println!("Regression baseline written to {}", baseline_path().display());
+        return;
+    }
+
+    let contents = fs::read_to_string(baseline_path())
+        .expect("Missing baseline; run with UPDATE_REGRESSION_BASELINE=1 to create it");
+    let baseline: BTreeMap<String, f64> = serde_json::from_str(&contents).unwrap();
+
+    let mut drift = Vec::new();
+    for (metric, expected) in &baseline {
+        match current.get(metric) {
+            Some(actual) => {
+                let allowed = ABSOLUTE_TOLERANCE + RELATIVE_TOLERANCE * expected.abs();
+                if (actual - expected).abs() > allowed {
+                    drift.push(format!(
+                        "  {:<28} baseline {:>16.6}  current {:>16.6}  diff {:+.6}",
+                        metric, expected, actual, actual - expected
+                    ));
+                }
+            }
+            None => drift.push(format!("  {:<28} missing from current run", metric)),
+        }
+    }
+    for metric in current.keys().filter(|m| !baseline.contains_key(*m)) {
+        drift.push(format!("  {:<28} not in baseline", metric));
+    }
+
+    assert!(
+        drift.is_empty(),
+        "Economic outputs drifted from baseline:\n{}\n\
+         If this change is intentional, regenerate with:\n  \
+         UPDATE_REGRESSION_BASELINE=1 cargo test --test regression_tests",
+        drift.join("\n")
+    );
+}
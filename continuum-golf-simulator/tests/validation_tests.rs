@@ -1,7 +1,7 @@
-/// Validation Tests - Replicating Business Plan Claims
-///
-/// This test suite validates that the simulator matches all claims
-/// made in the business plan for the Continuum Golf wagering system.
+//! Validation Tests - Replicating Business Plan Claims
+//!
+//! This test suite validates that the simulator matches all claims
+//! made in the business plan for the Continuum Golf wagering system.
 
 use continuum_golf_simulator::math::distributions::*;
 use continuum_golf_simulator::models::hole::*;
@@ -23,24 +23,26 @@ fn validate_rtp_by_distance() {
 
     // Test Short Holes (H1, H2, H3)
     let short_holes = vec![1, 2, 3];
-    let mut short_wagered = 0.0;
-    let mut short_won = 0.0;
-
-    for hole_id in short_holes {
-        let mut player = Player::new(format!("player_{}", 15), 15);
-        let config = SessionConfig {
-            num_shots: NUM_SHOTS / 3,
-            wager_min: 10.0,
-            wager_max: 10.0,
-            hole_selection: HoleSelection::Fixed(hole_id),
-            developer_mode: None,
-            fat_tail_prob: 0.02,
-            fat_tail_mult: 3.0,
-        };
-        let result = run_session(&mut player, config);
-        short_wagered += result.total_wagered;
-        short_won += result.total_won;
-    }
+    let sessions = short_holes
+        .into_iter()
+        .map(|hole_id| {
+            let player = Player::new(format!("player_{}", 15), 15);
+            let config = SessionConfig {
+                num_shots: NUM_SHOTS / 3,
+                wager_min: 10.0,
+                wager_max: 10.0,
+                hole_selection: HoleSelection::Fixed(hole_id),
+                developer_mode: None,
+                fat_tail_prob: 0.02,
+                fat_tail_mult: 3.0,
+                ..Default::default()
+            };
+            (player, config)
+        })
+        .collect();
+    let results = run_sessions_parallel(sessions);
+    let short_wagered: f64 = results.iter().map(|r| r.total_wagered).sum();
+    let short_won: f64 = results.iter().map(|r| r.total_won).sum();
 
     let short_rtp = short_won / short_wagered;
     println!("Short Holes RTP: {:.4} (target: 0.86)", short_rtp);
@@ -52,24 +54,26 @@ fn validate_rtp_by_distance() {
 
     // Test Mid Holes (H4, H5)
     let mid_holes = vec![4, 5];
-    let mut mid_wagered = 0.0;
-    let mut mid_won = 0.0;
-
-    for hole_id in mid_holes {
-        let mut player = Player::new(format!("player_{}", 15), 15);
-        let config = SessionConfig {
-            num_shots: NUM_SHOTS / 2,
-            wager_min: 10.0,
-            wager_max: 10.0,
-            hole_selection: HoleSelection::Fixed(hole_id),
-            developer_mode: None,
-            fat_tail_prob: 0.02,
-            fat_tail_mult: 3.0,
-        };
-        let result = run_session(&mut player, config);
-        mid_wagered += result.total_wagered;
-        mid_won += result.total_won;
-    }
+    let sessions = mid_holes
+        .into_iter()
+        .map(|hole_id| {
+            let player = Player::new(format!("player_{}", 15), 15);
+            let config = SessionConfig {
+                num_shots: NUM_SHOTS / 2,
+                wager_min: 10.0,
+                wager_max: 10.0,
+                hole_selection: HoleSelection::Fixed(hole_id),
+                developer_mode: None,
+                fat_tail_prob: 0.02,
+                fat_tail_mult: 3.0,
+                ..Default::default()
+            };
+            (player, config)
+        })
+        .collect();
+    let results = run_sessions_parallel(sessions);
+    let mid_wagered: f64 = results.iter().map(|r| r.total_wagered).sum();
+    let mid_won: f64 = results.iter().map(|r| r.total_won).sum();
 
     let mid_rtp = mid_won / mid_wagered;
     println!("Mid Holes RTP: {:.4} (target: 0.88)", mid_rtp);
@@ -81,24 +85,26 @@ fn validate_rtp_by_distance() {
 
     // Test Long Holes (H6, H7, H8)
     let long_holes = vec![6, 7, 8];
-    let mut long_wagered = 0.0;
-    let mut long_won = 0.0;
-
-    for hole_id in long_holes {
-        let mut player = Player::new(format!("player_{}", 15), 15);
-        let config = SessionConfig {
-            num_shots: NUM_SHOTS / 3,
-            wager_min: 10.0,
-            wager_max: 10.0,
-            hole_selection: HoleSelection::Fixed(hole_id),
-            developer_mode: None,
-            fat_tail_prob: 0.02,
-            fat_tail_mult: 3.0,
-        };
-        let result = run_session(&mut player, config);
-        long_wagered += result.total_wagered;
-        long_won += result.total_won;
-    }
+    let sessions = long_holes
+        .into_iter()
+        .map(|hole_id| {
+            let player = Player::new(format!("player_{}", 15), 15);
+            let config = SessionConfig {
+                num_shots: NUM_SHOTS / 3,
+                wager_min: 10.0,
+                wager_max: 10.0,
+                hole_selection: HoleSelection::Fixed(hole_id),
+                developer_mode: None,
+                fat_tail_prob: 0.02,
+                fat_tail_mult: 3.0,
+                ..Default::default()
+            };
+            (player, config)
+        })
+        .collect();
+    let results = run_sessions_parallel(sessions);
+    let long_wagered: f64 = results.iter().map(|r| r.total_wagered).sum();
+    let long_won: f64 = results.iter().map(|r| r.total_won).sum();
 
     let long_rtp = long_won / long_wagered;
     println!("Long Holes RTP: {:.4} (target: 0.90)", long_rtp);
@@ -136,6 +142,7 @@ fn validate_house_edge_by_distance() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
     let result_short = run_session(&mut player, config_short);
     let edge_short = 1.0 - (result_short.total_won / result_short.total_wagered);
@@ -158,6 +165,7 @@ fn validate_house_edge_by_distance() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
     let result_mid = run_session(&mut player, config_mid);
     let edge_mid = 1.0 - (result_mid.total_won / result_mid.total_wagered);
@@ -181,6 +189,7 @@ fn validate_house_edge_by_distance() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
     let result_long = run_session(&mut player, config_long);
     let edge_long = 1.0 - (result_long.total_won / result_long.total_wagered);
@@ -205,21 +214,28 @@ fn validate_fairness_all_handicaps() {
     println!("\n=== Validation: Fairness Across All Handicaps ===");
 
     let test_hole = 5; // Mid-range hole
-    let handicaps = vec![0, 5, 10, 15, 20, 25, 30];
+    let handicaps = [0, 5, 10, 15, 20, 25, 30];
     let mut evs = Vec::new();
 
-    for handicap in &handicaps {
-        let mut player = Player::new(format!("player_{}", handicap), *handicap);
-        let config = SessionConfig {
-            num_shots: NUM_SHOTS,
-            wager_min: WAGER,
-            wager_max: WAGER,
-            hole_selection: HoleSelection::Fixed(test_hole),
-            developer_mode: None,
-            fat_tail_prob: 0.02,
-            fat_tail_mult: 3.0,
-        };
-        let result = run_session(&mut player, config);
+    let sessions = handicaps
+        .iter()
+        .map(|handicap| {
+            let player = Player::new(format!("player_{}", handicap), *handicap);
+            let config = SessionConfig {
+                num_shots: NUM_SHOTS,
+                wager_min: WAGER,
+                wager_max: WAGER,
+                hole_selection: HoleSelection::Fixed(test_hole),
+                developer_mode: None,
+                fat_tail_prob: 0.02,
+                fat_tail_mult: 3.0,
+                ..Default::default()
+            };
+            (player, config)
+        })
+        .collect();
+
+    for (handicap, result) in handicaps.iter().zip(run_sessions_parallel(sessions)) {
         let ev = result.net_gain_loss / NUM_SHOTS as f64;
         evs.push(ev);
         println!("Handicap {}: EV = ${:.4} per shot", handicap, ev);
@@ -248,7 +264,7 @@ fn validate_breakeven_radius_formula() {
     println!("\n=== Validation: Breakeven Radius Formula ===");
 
     for hole in HOLE_CONFIGURATIONS.iter() {
-        let mut player = Player::new(format!("player_{}", 15), 15);
+        let player = Player::new(format!("player_{}", 15), 15);
         let p_max = player.calculate_p_max(hole);
         let d_break = hole.calculate_breakeven_radius(p_max);
 
@@ -337,7 +353,6 @@ fn validate_high_stakes_logic() {
     println!("\n=== Validation: High-Stakes Update Logic ===");
 
     let mut player = Player::new(format!("player_{}", 15), 15);
-    let hole = get_hole_by_id(4).unwrap();
 
     // Run normal shots
     let normal_config = SessionConfig {
@@ -348,6 +363,7 @@ fn validate_high_stakes_logic() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let normal_result = run_session(&mut player, normal_config);
@@ -364,6 +380,7 @@ fn validate_high_stakes_logic() {
         developer_mode: None,
         fat_tail_prob: 0.02,
         fat_tail_mult: 3.0,
+        ..Default::default()
     };
 
     let high_stakes_result = run_session(&mut player, high_stakes_config);
@@ -407,7 +424,7 @@ fn validate_hole_configurations() {
     ];
 
     for (id, dist, d_max, rtp, k) in expected_configs {
-        let hole = get_hole_by_id(id).expect(&format!("Hole {} not found", id));
+        let hole = get_hole_by_id(id).unwrap_or_else(|| panic!("Hole {} not found", id));
 
         println!("Hole {}: {}yd, d_max={:.2}, RTP={:.2}, k={:.1}",
                  id, dist, d_max, rtp, k);
@@ -447,7 +464,6 @@ fn validate_kalman_convergence_properties() {
     let mut player = Player::new(format!("player_{}", 15), 15);
     let hole = get_hole_by_id(4).unwrap();
 
-    let mut previous_confidence = 0.0;
     let mut confidence_increased = 0;
 
     for batch_num in 0..NUM_BATCHES {
@@ -459,6 +475,7 @@ fn validate_kalman_convergence_properties() {
             developer_mode: None,
             fat_tail_prob: 0.02,
             fat_tail_mult: 3.0,
+            ..Default::default()
         };
 
         let result = run_session(&mut player, config);
@@ -538,13 +555,11 @@ fn validate_system_wide_rtp() {
 
     println!("\n=== Validation: System-Wide RTP ===");
 
-    let mut total_wagered = 0.0;
-    let mut total_won = 0.0;
-
     // Test all combinations of holes and handicaps
+    let mut sessions = Vec::new();
     for hole_id in 1..=8 {
         for handicap in [0, 10, 20, 30].iter() {
-            let mut player = Player::new(format!("player_{}", handicap), *handicap);
+            let player = Player::new(format!("player_{}", handicap), *handicap);
             let config = SessionConfig {
                 num_shots: NUM_SHOTS_PER_COMBO,
                 wager_min: 10.0,
@@ -553,14 +568,16 @@ fn validate_system_wide_rtp() {
                 developer_mode: None,
                 fat_tail_prob: 0.02,
                 fat_tail_mult: 3.0,
+                ..Default::default()
             };
-
-            let result = run_session(&mut player, config);
-            total_wagered += result.total_wagered;
-            total_won += result.total_won;
+            sessions.push((player, config));
         }
     }
 
+    let results = run_sessions_parallel(sessions);
+    let total_wagered: f64 = results.iter().map(|r| r.total_wagered).sum();
+    let total_won: f64 = results.iter().map(|r| r.total_won).sum();
+
     let system_rtp = total_won / total_wagered;
     let system_edge = 1.0 - system_rtp;
 
@@ -583,3 +600,51 @@ fn validate_system_wide_rtp() {
         system_edge
     );
 }
+
+/// Validation Test 11: RTP Accounting with a Progressive Jackpot
+///
+/// Jackpot contributions come out of the house's hold, so once the pool left
+/// over at the end is counted as owed to players, the effective RTP is the
+/// base RTP plus the contribution rate.
+#[test]
+fn validate_jackpot_rtp_accounting() {
+    const CONTRIBUTION_RATE: f64 = 0.01;
+
+    println!("\n=== Validation: Jackpot RTP Accounting ===");
+
+    for handicap in [0, 15, 30] {
+        let config = SessionConfig {
+            num_shots: 2_000,
+            wager_min: 10.0,
+            wager_max: 10.0,
+            seed: Some(267 + handicap as u64),
+            ..Default::default()
+        };
+        let base = run_session(&mut Player::new("base".to_string(), handicap), config.clone());
+        let with_jackpot = run_session(
+            &mut Player::new("jackpot".to_string(), handicap),
+            SessionConfig {
+                jackpot: JackpotState { pool: 0.0, contribution_rate: CONTRIBUTION_RATE },
+                ..config
+            },
+        );
+
+        // The jackpot draws no randomness, so the shots are identical
+        assert_eq!(base.total_wagered, with_jackpot.total_wagered);
+        let base_rtp = base.total_won / base.total_wagered;
+        let effective_rtp =
+            (with_jackpot.total_won + with_jackpot.jackpot_final_pool) / with_jackpot.total_wagered;
+
+        println!(
+            "Handicap {}: base RTP {:.4}, with jackpot {:.4} ({} jackpots)",
+            handicap, base_rtp, effective_rtp, with_jackpot.num_jackpots_hit
+        );
+        assert!(
+            (effective_rtp - (base_rtp + CONTRIBUTION_RATE)).abs() < 1e-9,
+            "Effective RTP {:.6} should be base {:.6} + {}",
+            effective_rtp,
+            base_rtp,
+            CONTRIBUTION_RATE
+        );
+    }
+}